@@ -29,6 +29,18 @@ use crate::cmof_loader::*;
 // ####################################################################################################
 
 impl CMOFAssociation {
+    /// Properties of the two member ends, in metamodel order
+    pub fn get_member_end(&self) -> Result<(Rc<CMOFProperty>, Rc<CMOFProperty>), anyhow::Error> {
+        let get_end = |end: &XMIIdReference<EnumWeakCMOF>| match get_object_as_enum(end)? {
+            EnumCMOF::CMOFProperty(c) => Ok(c),
+            _ => Err(anyhow::format_err!(
+                "Member end of \"{}\" isn't a property",
+                self.xmi_id.label()?
+            )),
+        };
+        Ok((get_end(&self.member_end.0)?, get_end(&self.member_end.1)?))
+    }
+
     pub fn get_association_relation(&self) -> Result<Relation, anyhow::Error> {
         let object_0 = get_object_as_enum(&self.member_end.0);
         let object_0: &Rc<CMOFProperty> = match object_0.as_ref().unwrap() {
@@ -50,6 +50,80 @@ impl CMOFClass {
         Ok(result)
     }
 
+    /// Get all class inheriting from the class, directly or not (key : xmi label)
+    pub fn get_all_descendant_class(
+        &self,
+    ) -> Result<BTreeMap<String, Rc<CMOFClass>>, anyhow::Error> {
+        let mut result: BTreeMap<String, Rc<CMOFClass>> = BTreeMap::new();
+        for child in self.get_reverse_super_class()? {
+            for (label, descendant) in child.get_all_descendant_class()? {
+                result.insert(label, descendant);
+            }
+            result.insert(child.get_xmi_label()?, child);
+        }
+        Ok(result)
+    }
+
+    /// Get all class inherited by the class, directly or not (key : xmi label)
+    pub fn get_all_super_class(&self) -> Result<BTreeMap<String, Rc<CMOFClass>>, anyhow::Error> {
+        let mut result: BTreeMap<String, Rc<CMOFClass>> = BTreeMap::new();
+        for (label, class) in self.get_super_class()? {
+            let class = get_object_as_class(class)?;
+            result.append(&mut class.get_all_super_class()?);
+            result.insert(label, class);
+        }
+        Ok(result)
+    }
+
+    /// Get all property of the class and of its super class, directly or not (key : xmi label)
+    pub fn get_all_property(&self) -> Result<BTreeMap<String, Rc<CMOFProperty>>, anyhow::Error> {
+        let mut result: BTreeMap<String, Rc<CMOFProperty>> = BTreeMap::new();
+        for class in self.get_super_class()?.values() {
+            result.append(&mut get_object_as_class(class)?.get_all_property()?);
+        }
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            result.insert(content.xmi_id.label()?, content.clone());
+        }
+        Ok(result)
+    }
+
+    /// Get a property by its metamodel name, in the class or in its super class (directly or not)
+    pub fn get_property_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<Rc<CMOFProperty>>, anyhow::Error> {
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            if content.get_metamodel_name() == name {
+                return Ok(Some(content.clone()));
+            }
+        }
+        for class in self.get_super_class()?.values() {
+            if let Some(content) = get_object_as_class(class)?.get_property_by_name(name)? {
+                return Ok(Some(content));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Check if the class is the other class, or one of its sub-class (directly or not)
+    pub fn is_kind_of(&self, other: &CMOFClass) -> Result<bool, anyhow::Error> {
+        let label = other.get_xmi_label()?;
+        Ok(self.get_xmi_label()? == label || self.get_all_super_class()?.contains_key(&label))
+    }
+
+    /// Get own property of the class, in declaration order (order of "ownedAttribute")
+    pub fn get_owned_attribute_in_order(&self) -> Vec<Rc<CMOFProperty>> {
+        let mut result: Vec<Rc<CMOFProperty>> = self
+            .owned_attribute
+            .values()
+            .map(|EnumOwnedAttribute::Property(content)| content.clone())
+            .collect();
+        result.sort_by_key(|content| content.declaration_order);
+        result
+    }
+
     /// Get all direct One To One relation of the class
     pub fn get_all_direct_one_to_one(
         &self,
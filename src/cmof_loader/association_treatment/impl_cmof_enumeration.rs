@@ -44,4 +44,15 @@ impl CMOFEnumeration {
 
         Ok(result)
     }
+
+    /// Get the literal matching a value, ignoring case (metamodel defaults aren't always well cased)
+    pub fn get_literal_by_designation(
+        &self,
+        value: &str,
+    ) -> Result<Option<&CMOFEnumerationLiteral>, anyhow::Error> {
+        Ok(self
+            .get_all_literal()?
+            .into_iter()
+            .find(|literal| literal.litteral_designation.eq_ignore_ascii_case(value)))
+    }
 }
@@ -61,9 +61,14 @@ pub struct CMOFClass {
     pub super_class_link: Vec<XMIIdReference<EnumWeakCMOF>>,
     /// Optional ownedAttribute object array
     #[serde(rename = "ownedAttribute")]
-    #[serde(deserialize_with = "deser_btreemap_using_name_as_key")]
+    #[serde(deserialize_with = "deser_btreemap_using_name_as_key_keeping_order")]
     #[serde(default = "default_empty_btreemap")]
     pub owned_attribute: BTreeMap<String, EnumOwnedAttribute>,
+    /// Optional ownedOperation object array
+    #[serde(rename = "ownedOperation")]
+    #[serde(deserialize_with = "deser_btreemap_using_name_as_key")]
+    #[serde(default = "default_empty_btreemap")]
+    pub owned_operation: BTreeMap<String, EnumOwnedOperation>,
     /// Optional ownedRule object
     #[serde(rename = "ownedRule")]
     #[serde(deserialize_with = "deser_btreemap_using_name_as_key")]
@@ -72,6 +77,9 @@ pub struct CMOFClass {
     /// Casing formating of "name" as technical_name
     #[serde(skip)]
     pub technical_name: String,
+    /// "lowercase_name" of the package, as module and feature of the generated crate
+    #[serde(skip)]
+    pub package_lowercase_name: String,
     /// Casing formating of "name" as table_name
     #[serde(skip)]
     pub table_name: String,
@@ -113,12 +121,19 @@ impl SetCMOFTools for CMOFClass {
             ))?
             .clone();
         let package_name_snake_case = package_name.to_case(Case::Snake);
+        let package_lowercase_name = dict_setting
+            .get("package_lowercase_name")
+            .ok_or(anyhow::format_err!(
+                "Dictionnary error in make_post_deserialize"
+            ))?
+            .clone();
         let class_upper_case = self.name.to_case(Case::UpperCamel);
         let class_snake_case = self.name.to_case(Case::Snake);
         let parent_name = self.xmi_id.get_object_id();
         // Set local values
         self.xmi_id.set_package_id_if_empty(&package_name);
         self.technical_name = format!("{}.cmof#{}", package_name, self.name);
+        self.package_lowercase_name = package_lowercase_name;
         self.table_name = format!("{}_{}", package_name_snake_case, class_snake_case);
         self.model_name = format!("{}", class_upper_case);
         self.full_name = format!("{}_class_{}", package_name_snake_case, class_snake_case);
@@ -144,6 +159,17 @@ impl SetCMOFTools for CMOFClass {
                 }
             }
         }
+        for p in self.owned_operation.values_mut() {
+            match p {
+                EnumOwnedOperation::Operation(c) => {
+                    let m = Rc::get_mut(c).unwrap();
+                    m.parent.set_package_id_if_empty(&package_name);
+                    m.parent.set_object_id(&parent_name);
+                    m.collect_object(dict_setting, dict_object)?;
+                    dict_object.insert(c.get_xmi_label()?, EnumCMOF::CMOFOperation(c.clone()));
+                }
+            }
+        }
         for (_, p) in &mut self.owned_rule {
             match p {
                 EnumOwnedRule::Constraint(c) => {
@@ -169,6 +195,11 @@ impl SetCMOFTools for CMOFClass {
                 EnumOwnedAttribute::Property(c) => c.make_post_deserialize(dict_object)?,
             }
         }
+        for p in self.owned_operation.values() {
+            match p {
+                EnumOwnedOperation::Operation(c) => c.make_post_deserialize(dict_object)?,
+            }
+        }
         for (_, p) in &self.owned_rule {
             match p {
                 EnumOwnedRule::Constraint(c) => c.make_post_deserialize(dict_object)?,
@@ -192,6 +223,11 @@ impl SetCMOFTools for CMOFClass {
 // ####################################################################################################
 
 impl CMOFClass {
+    /// Name as written in the metamodel ("BPMNShape")
+    pub fn get_metamodel_name(&self) -> String {
+        self.name.clone()
+    }
+
     pub fn generate_reverse_super_class(
         &self,
         dict_object: &BTreeMap<String, EnumCMOF>,
@@ -57,6 +57,17 @@ pub struct CMOFConstraint {
 //
 // ####################################################################################################
 
+impl CMOFConstraint {
+    /// Name of the constraint (example : "non_negative_size")
+    pub fn get_name(&self) -> &str {
+        &self._name
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
 impl SetCMOFTools for CMOFConstraint {
     fn collect_object(
         &mut self,
@@ -44,7 +44,7 @@ pub struct CMOFDataType {
     name: String,
     /// Optional ownedAttribute object array
     #[serde(rename = "ownedAttribute")]
-    #[serde(deserialize_with = "deser_btreemap_using_name_as_key")]
+    #[serde(deserialize_with = "deser_btreemap_using_name_as_key_keeping_order")]
     #[serde(default = "default_empty_btreemap")]
     pub owned_attribute: BTreeMap<String, EnumOwnedAttribute>,
     /// Optional ownedRule object
@@ -55,6 +55,9 @@ pub struct CMOFDataType {
     /// Casing formating of "name" as technical_name
     #[serde(skip)]
     pub technical_name: String,
+    /// "lowercase_name" of the package, as module and feature of the generated crate
+    #[serde(skip)]
+    pub package_lowercase_name: String,
     /// Casing formating of "name" as table_name
     #[serde(skip)]
     pub table_name: String,
@@ -84,12 +87,19 @@ impl SetCMOFTools for CMOFDataType {
             ))?
             .clone();
         let package_name_snake_case = package_name.to_case(Case::Snake);
+        let package_lowercase_name = dict_setting
+            .get("package_lowercase_name")
+            .ok_or(anyhow::format_err!(
+                "Dictionnary error in make_post_deserialize"
+            ))?
+            .clone();
         let class_upper_case = self.name.to_case(Case::UpperCamel);
         let class_snake_case = self.name.to_case(Case::Snake);
         let parent_name = self.xmi_id.get_object_id();
         // Set local values
         self.xmi_id.set_package_id_if_empty(&package_name);
         self.technical_name = format!("{}.cmof#{}", package_name, self.name);
+        self.package_lowercase_name = package_lowercase_name;
         self.table_name = format!("{}_{}", package_name_snake_case, class_snake_case);
         self.model_name = format!("{}", class_upper_case);
         self.full_name = format!("{}_datatype_{}", package_name_snake_case, class_snake_case);
@@ -146,6 +156,28 @@ impl SetCMOFTools for CMOFDataType {
 //
 // ####################################################################################################
 
+impl CMOFDataType {
+    /// Name as written in the metamodel ("Bounds")
+    pub fn get_metamodel_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Get own property of the datatype, in declaration order (order of "ownedAttribute")
+    pub fn get_owned_attribute_in_order(&self) -> Vec<Rc<CMOFProperty>> {
+        let mut result: Vec<Rc<CMOFProperty>> = self
+            .owned_attribute
+            .values()
+            .map(|EnumOwnedAttribute::Property(content)| content.clone())
+            .collect();
+        result.sort_by_key(|content| content.declaration_order);
+        result
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
 #[cfg(test)]
 mod tests {
     use super::*;
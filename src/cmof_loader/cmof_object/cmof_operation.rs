@@ -0,0 +1,143 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[derive(Clone, Debug, Deserialize, XMIIdentity)]
+#[serde(deny_unknown_fields)]
+/// RUST Struct for deserialize CMOF Operation Object
+pub struct CMOFOperation {
+    /// xmi:id attribute
+    #[serde(deserialize_with = "deser_local_xmi_id")]
+    #[serde(rename = "_xmi:id")]
+    pub xmi_id: XMIIdLocalReference,
+    /// Parent of the XMI object
+    #[serde(skip)]
+    pub parent: XMIIdReference<EnumWeakCMOF>,
+    /// name attribute
+    #[serde(rename = "_name")]
+    #[serde(deserialize_with = "deser_name")]
+    pub name: String,
+    /// visibility attribute
+    #[serde(rename = "_visibility")]
+    #[serde(default = "default_visibility")]
+    pub visibility: UMLVisibilityKind,
+    /// isQuery attribute
+    #[serde(rename = "_isQuery")]
+    #[serde(deserialize_with = "deser_boolean")]
+    #[serde(default = "default_false")]
+    pub is_query: bool,
+    /// Optional ownedParameter object array (order of the signature)
+    #[serde(rename = "ownedParameter")]
+    #[serde(deserialize_with = "deser_vec_keeping_order")]
+    #[serde(default = "default_empty_vec")]
+    pub owned_parameter: Vec<EnumOwnedParameter>,
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl SetCMOFTools for CMOFOperation {
+    fn collect_object(
+        &mut self,
+        dict_setting: &mut BTreeMap<String, String>,
+        dict_object: &mut BTreeMap<String, EnumCMOF>,
+    ) -> Result<(), anyhow::Error> {
+        // Get needed values
+        let package_name = dict_setting
+            .get("package_name")
+            .ok_or(anyhow::format_err!(
+                "Dictionnary error in make_post_deserialize"
+            ))?
+            .clone();
+        let parent_name = self.xmi_id.get_object_id();
+        // Set local values
+        self.xmi_id.set_package_id_if_empty(&package_name);
+        // Call on child
+        for p in &mut self.owned_parameter {
+            match p {
+                EnumOwnedParameter::Parameter(c) => {
+                    let m = Rc::get_mut(c).unwrap();
+                    m.parent.set_package_id_if_empty(&package_name);
+                    m.parent.set_object_id(&parent_name);
+                    m.collect_object(dict_setting, dict_object)?;
+                    dict_object.insert(c.get_xmi_label()?, EnumCMOF::CMOFParameter(c.clone()));
+                }
+            }
+        }
+        //Return
+        Ok(())
+    }
+
+    fn make_post_deserialize(
+        &self,
+        dict_object: &mut BTreeMap<String, EnumCMOF>,
+    ) -> Result<(), anyhow::Error> {
+        // Call on child
+        for p in &self.owned_parameter {
+            match p {
+                EnumOwnedParameter::Parameter(c) => c.make_post_deserialize(dict_object)?,
+            }
+        }
+        // Self
+        set_xmi_id_object(&self.parent, dict_object)?;
+        //Return
+        Ok(())
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl CMOFOperation {
+    /// Name as written in the metamodel, without raw identifier prefix ("r#type" --> "type")
+    pub fn get_metamodel_name(&self) -> String {
+        self.name.trim_start_matches("r#").to_string()
+    }
+
+    /// Parameters of the signature ("in", "inout" and "out" direction), in order
+    pub fn get_signature_parameter(&self) -> Vec<Rc<CMOFParameter>> {
+        self.owned_parameter
+            .iter()
+            .map(|EnumOwnedParameter::Parameter(c)| c.clone())
+            .filter(|c| c.direction != UMLParameterDirectionKind::Return)
+            .collect()
+    }
+
+    /// Parameter with "return" direction, if any
+    pub fn get_return_parameter(&self) -> Option<Rc<CMOFParameter>> {
+        self.owned_parameter
+            .iter()
+            .map(|EnumOwnedParameter::Parameter(c)| c.clone())
+            .find(|c| c.direction == UMLParameterDirectionKind::Return)
+    }
+}
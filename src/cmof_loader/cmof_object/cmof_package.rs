@@ -78,6 +78,10 @@ impl SetCMOFTools for CMOFPackage {
         // Set local values
         self.xmi_id.set_package_id_if_empty(&package_name);
         self.lowercase_name = String::from(package_name_snake_case);
+        dict_setting.insert(
+            String::from("package_lowercase_name"),
+            self.lowercase_name.clone(),
+        );
         // Call on child
         for (_, p) in &mut self.package_import {
             match p {
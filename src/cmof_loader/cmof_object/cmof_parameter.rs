@@ -0,0 +1,155 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[derive(Clone, Debug, Deserialize, XMIIdentity)]
+#[serde(deny_unknown_fields)]
+/// RUST Struct for deserialize CMOF Parameter Object
+pub struct CMOFParameter {
+    /// xmi:id attribute
+    #[serde(deserialize_with = "deser_local_xmi_id")]
+    #[serde(rename = "_xmi:id")]
+    pub xmi_id: XMIIdLocalReference,
+    /// Parent of the XMI object
+    #[serde(skip)]
+    pub parent: XMIIdReference<EnumWeakCMOF>,
+    /// Optional name attribute (empty for return parameter)
+    #[serde(rename = "_name")]
+    #[serde(deserialize_with = "deser_name")]
+    #[serde(default = "default_empty_string")]
+    pub name: String,
+    /// Optional direction attribute
+    #[serde(rename = "_direction")]
+    #[serde(default = "default_direction")]
+    pub direction: UMLParameterDirectionKind,
+    /// Optional type attribute (simple type)
+    #[serde(deserialize_with = "deser_option_xmi_id")]
+    #[serde(default = "default_option")]
+    #[serde(rename = "_type")]
+    pub simple_type: Option<XMIIdReference<EnumWeakCMOF>>,
+    /// Optional type object (complex type)
+    #[serde(rename = "type")]
+    pub complex_type: Option<EnumType>,
+    /// Optional lower attribute
+    #[serde(rename = "_lower")]
+    #[serde(deserialize_with = "deser_lower_bound")]
+    #[serde(default = "default_lower")]
+    pub lower: i32,
+    /// Optional upper attribute
+    #[serde(rename = "_upper")]
+    #[serde(deserialize_with = "deser_upper_bound")]
+    #[serde(default = "default_upper")]
+    pub upper: UnlimitedNatural<i32>,
+    /// Optional default attribute
+    #[serde(rename = "_default")]
+    pub default: Option<String>,
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl SetCMOFTools for CMOFParameter {
+    fn collect_object(
+        &mut self,
+        dict_setting: &mut BTreeMap<String, String>,
+        dict_object: &mut BTreeMap<String, EnumCMOF>,
+    ) -> Result<(), anyhow::Error> {
+        // Get needed values
+        let package_name = dict_setting
+            .get("package_name")
+            .ok_or(anyhow::format_err!(
+                "Dictionnary error in make_post_deserialize"
+            ))?
+            .clone();
+        // Set local values
+        self.xmi_id.set_package_id_if_empty(&package_name);
+        if let Some(simple_type) = self.simple_type.as_mut() {
+            simple_type.set_package_id_if_empty(&package_name);
+        }
+        // Call on child
+        if let Some(complex_type) = self.complex_type.as_mut() {
+            complex_type.collect_object(dict_setting, dict_object)?;
+        }
+        //Return
+        Ok(())
+    }
+
+    fn make_post_deserialize(
+        &self,
+        dict_object: &mut BTreeMap<String, EnumCMOF>,
+    ) -> Result<(), anyhow::Error> {
+        // Call on child
+        if let Some(complex_type) = self.complex_type.as_ref() {
+            complex_type.make_post_deserialize(dict_object)?;
+        }
+        if let Some(simple_type) = self.simple_type.as_ref() {
+            set_xmi_id_object(simple_type, dict_object)?;
+        }
+        // Self
+        set_xmi_id_object(&self.parent, dict_object)?;
+        //Return
+        Ok(())
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl CMOFParameter {
+    /// Type of the parameter, as for CMOFProperty
+    pub fn get_type(&self) -> Result<EnumWeakCMOF, anyhow::Error> {
+        // For parameter simple
+        if let Some(simple_type) = self.simple_type.as_ref() {
+            simple_type.get_object()
+        } else {
+            match self.complex_type.as_ref() {
+                Some(EnumType::HRefPrimitiveType(link)) => {
+                    // Foreign type
+                    Ok(EnumWeakCMOF::CMOFPrimitiveType(link.href.get_object()?))
+                }
+                Some(EnumType::HRefClass(link)) => {
+                    // Foreign type
+                    Ok(EnumWeakCMOF::CMOFClass(link.href.get_object()?))
+                }
+                Some(EnumType::HRefDataType(link)) => {
+                    // Foreign type
+                    Ok(EnumWeakCMOF::CMOFDataType(link.href.get_object()?))
+                }
+                None => Err(anyhow::format_err!(
+                    "Parameter \"{}\" without type",
+                    self.xmi_id.label()?
+                )),
+            }
+        }
+    }
+}
@@ -47,6 +47,9 @@ pub struct CMOFPrimitiveType {
     /// Casing formating of "name" as technical_name
     #[serde(skip)]
     pub technical_name: String,
+    /// "lowercase_name" of the package, as module and feature of the generated crate
+    #[serde(skip)]
+    pub package_lowercase_name: String,
     /// Casing formating of "name" as table_name
     #[serde(skip)]
     pub table_name: String,
@@ -77,11 +80,18 @@ impl SetCMOFTools for CMOFPrimitiveType {
             .clone();
         let parent_name = self.xmi_id.get_object_id();
         let package_name_snake_case = package_name.to_case(Case::Snake);
+        let package_lowercase_name = dict_setting
+            .get("package_lowercase_name")
+            .ok_or(anyhow::format_err!(
+                "Dictionnary error in make_post_deserialize"
+            ))?
+            .clone();
         let class_upper_case = self.name.to_case(Case::UpperCamel);
         let class_snake_case = self.name.to_case(Case::Snake);
         // Set local values
         self.xmi_id.set_package_id_if_empty(&package_name);
         self.technical_name = format!("{}.cmof#{}", package_name, self.name);
+        self.package_lowercase_name = package_lowercase_name;
         self.table_name = format!("{}_{}", package_name_snake_case, class_snake_case);
         self.model_name = format!("{}", class_upper_case);
         self.full_name = format!("{}_primitive_{}", package_name_snake_case, class_snake_case);
@@ -123,6 +123,9 @@ pub struct CMOFProperty {
     /// Optional SubsettedProperty object
     #[serde(rename = "subsettedProperty")]
     pub subsetted_property_link: Option<EnumSubsettedProperty>,
+    /// Position in the "ownedAttribute" array of the owner (declaration order)
+    #[serde(skip)]
+    pub declaration_order: usize,
 }
 
 // ####################################################################################################
@@ -222,6 +225,57 @@ impl SetCMOFTools for CMOFProperty {
 // ####################################################################################################
 
 impl CMOFProperty {
+    /// Name as written in the metamodel, without raw identifier prefix ("r#type" --> "type")
+    pub fn get_metamodel_name(&self) -> String {
+        self.name.trim_start_matches("r#").to_string()
+    }
+
+    /// Multiplicity as in UML ("1", "0..1", "0..*", "1..*")
+    pub fn get_multiplicity(&self) -> String {
+        let upper = match self.upper {
+            infinitable::Finite(upper) => upper.to_string(),
+            _ => String::from("*"),
+        };
+        if upper == self.lower.to_string() {
+            upper
+        } else {
+            format!("{}..{}", self.lower, upper)
+        }
+    }
+
+    /// Class used as type of the property (error if the type isn't a class)
+    pub fn get_class_type(&self) -> Result<Rc<CMOFClass>, anyhow::Error> {
+        match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => Ok(c),
+            _ => Err(anyhow::format_err!(
+                "Type of \"{}\" isn't a class",
+                self.xmi_id.label()?
+            )),
+        }
+    }
+
+    /// Other member end of the association of the property (None without association)
+    pub fn get_opposite(&self) -> Result<Option<Rc<CMOFProperty>>, anyhow::Error> {
+        let association = match &self.association {
+            Some(association) => association,
+            None => return Ok(None),
+        };
+        match get_object_as_enum(association)? {
+            EnumCMOF::CMOFAssociation(c) => {
+                let (end_0, end_1) = c.get_member_end()?;
+                if end_0.xmi_id.label()? == self.xmi_id.label()? {
+                    Ok(Some(end_1))
+                } else {
+                    Ok(Some(end_0))
+                }
+            }
+            _ => Err(anyhow::format_err!(
+                "Association of \"{}\" isn't an association",
+                self.xmi_id.label()?
+            )),
+        }
+    }
+
     pub fn get_type(&self) -> Result<EnumWeakCMOF, anyhow::Error> {
         // For field simple
         if self.simple_type.is_some() {
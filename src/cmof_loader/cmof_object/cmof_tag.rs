@@ -56,6 +56,17 @@ pub struct CMOFTag {
 //
 // ####################################################################################################
 
+impl CMOFTag {
+    /// Name of the tag (example : "org.omg.xmi.nsURI")
+    pub fn get_name(&self) -> &str {
+        &self._name
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
 impl SetCMOFTools for CMOFTag {
     fn collect_object(
         &mut self,
@@ -47,10 +47,14 @@ pub enum EnumCMOF {
     CMOFEnumerationLiteral(Rc<CMOFEnumerationLiteral>),
     /// CMOFOpaqueExpression
     CMOFOpaqueExpression(Rc<CMOFOpaqueExpression>),
+    /// CMOFOperation
+    CMOFOperation(Rc<CMOFOperation>),
     /// CMOFPackage
     CMOFPackage(Rc<CMOFPackage>),
     /// CMOFPackageImport
     CMOFPackageImport(Rc<CMOFPackageImport>),
+    /// CMOFParameter
+    CMOFParameter(Rc<CMOFParameter>),
     /// CMOFPrimitiveType
     CMOFPrimitiveType(Rc<CMOFPrimitiveType>),
     /// CMOFProperty
@@ -70,8 +74,10 @@ impl EnumCMOF {
             EnumCMOF::CMOFEnumeration(c) => c.xmi_id.label(),
             EnumCMOF::CMOFEnumerationLiteral(c) => c.xmi_id.label(),
             EnumCMOF::CMOFOpaqueExpression(c) => c.xmi_id.label(),
+            EnumCMOF::CMOFOperation(c) => c.xmi_id.label(),
             EnumCMOF::CMOFPackage(c) => c.xmi_id.label(),
             EnumCMOF::CMOFPackageImport(c) => c.xmi_id.label(),
+            EnumCMOF::CMOFParameter(c) => c.xmi_id.label(),
             EnumCMOF::CMOFPrimitiveType(c) => c.xmi_id.label(),
             EnumCMOF::CMOFProperty(c) => c.xmi_id.label(),
             EnumCMOF::CMOFTag(c) => c.xmi_id.label(),
@@ -100,10 +106,14 @@ pub enum EnumWeakCMOF {
     CMOFEnumerationLiteral(Weak<CMOFEnumerationLiteral>),
     /// CMOFOpaqueExpression
     CMOFOpaqueExpression(Weak<CMOFOpaqueExpression>),
+    /// CMOFOperation
+    CMOFOperation(Weak<CMOFOperation>),
     /// CMOFPackage
     CMOFPackage(Weak<CMOFPackage>),
     /// CMOFPackageImport
     CMOFPackageImport(Weak<CMOFPackageImport>),
+    /// CMOFParameter
+    CMOFParameter(Weak<CMOFParameter>),
     /// CMOFPrimitiveType
     CMOFPrimitiveType(Weak<CMOFPrimitiveType>),
     /// CMOFProperty
@@ -126,8 +136,10 @@ impl EnumWeakCMOF {
             EnumCMOF::CMOFOpaqueExpression(c) => {
                 EnumWeakCMOF::CMOFOpaqueExpression(Rc::downgrade(&c))
             }
+            EnumCMOF::CMOFOperation(c) => EnumWeakCMOF::CMOFOperation(Rc::downgrade(c)),
             EnumCMOF::CMOFPackage(c) => EnumWeakCMOF::CMOFPackage(Rc::downgrade(&c)),
             EnumCMOF::CMOFPackageImport(c) => EnumWeakCMOF::CMOFPackageImport(Rc::downgrade(&c)),
+            EnumCMOF::CMOFParameter(c) => EnumWeakCMOF::CMOFParameter(Rc::downgrade(c)),
             EnumCMOF::CMOFPrimitiveType(c) => EnumWeakCMOF::CMOFPrimitiveType(Rc::downgrade(&c)),
             EnumCMOF::CMOFProperty(c) => EnumWeakCMOF::CMOFProperty(Rc::downgrade(&c)),
             EnumCMOF::CMOFTag(c) => EnumWeakCMOF::CMOFTag(Rc::downgrade(&c)),
@@ -185,6 +197,13 @@ impl EnumWeakCMOF {
                     None => Err(anyhow::format_err!("Upgrade result 'None' : {:?}", self)),
                 }
             }
+            EnumWeakCMOF::CMOFOperation(c) => {
+                let r = c.upgrade();
+                match r {
+                    Some(content) => Ok(EnumCMOF::CMOFOperation(content)),
+                    None => Err(anyhow::format_err!("Upgrade result 'None' : {:?}", self)),
+                }
+            }
             EnumWeakCMOF::CMOFPackage(c) => {
                 let r = c.upgrade();
                 match r {
@@ -199,6 +218,13 @@ impl EnumWeakCMOF {
                     None => Err(anyhow::format_err!("Upgrade result 'None' : {:?}", self)),
                 }
             }
+            EnumWeakCMOF::CMOFParameter(c) => {
+                let r = c.upgrade();
+                match r {
+                    Some(content) => Ok(EnumCMOF::CMOFParameter(content)),
+                    None => Err(anyhow::format_err!("Upgrade result 'None' : {:?}", self)),
+                }
+            }
             EnumWeakCMOF::CMOFPrimitiveType(c) => {
                 let r = c.upgrade();
                 match r {
@@ -266,12 +292,18 @@ pub fn set_xmi_id_object(
             EnumCMOF::CMOFOpaqueExpression(c) => {
                 object.set_object(EnumWeakCMOF::CMOFOpaqueExpression(Rc::downgrade(c)));
             }
+            EnumCMOF::CMOFOperation(c) => {
+                object.set_object(EnumWeakCMOF::CMOFOperation(Rc::downgrade(c)));
+            }
             EnumCMOF::CMOFPackage(c) => {
                 object.set_object(EnumWeakCMOF::CMOFPackage(Rc::downgrade(c)));
             }
             EnumCMOF::CMOFPackageImport(c) => {
                 object.set_object(EnumWeakCMOF::CMOFPackageImport(Rc::downgrade(c)));
             }
+            EnumCMOF::CMOFParameter(c) => {
+                object.set_object(EnumWeakCMOF::CMOFParameter(Rc::downgrade(c)));
+            }
             EnumCMOF::CMOFPrimitiveType(c) => {
                 object.set_object(EnumWeakCMOF::CMOFPrimitiveType(Rc::downgrade(c)));
             }
@@ -302,6 +334,18 @@ pub enum EnumOwnedAttribute {
     Property(Rc<CMOFProperty>),
 }
 
+impl DeclarationOrder for EnumOwnedAttribute {
+    fn set_declaration_order(&mut self, order: usize) {
+        match self {
+            EnumOwnedAttribute::Property(c) => {
+                if let Some(m) = Rc::get_mut(c) {
+                    m.declaration_order = order;
+                }
+            }
+        }
+    }
+}
+
 // ####################################################################################################
 //
 // ####################################################################################################
@@ -373,6 +417,15 @@ impl EnumOwnedMember {
             EnumOwnedMember::PrimitiveType(content) => content.technical_name.clone(),
         }
     }
+    pub fn get_package_lowercase_name(&self) -> String {
+        match self {
+            EnumOwnedMember::Association(content) => content.package_lowercase_name.clone(),
+            EnumOwnedMember::Class(content) => content.package_lowercase_name.clone(),
+            EnumOwnedMember::DataType(content) => content.package_lowercase_name.clone(),
+            EnumOwnedMember::Enumeration(content) => content.package_lowercase_name.clone(),
+            EnumOwnedMember::PrimitiveType(content) => content.package_lowercase_name.clone(),
+        }
+    }
     pub fn get_table_name(&self) -> String {
         match self {
             EnumOwnedMember::Association(content) => content.table_name.clone(),
@@ -406,6 +459,36 @@ impl EnumOwnedMember {
 //
 // ####################################################################################################
 
+#[derive(Clone, Debug, Deserialize, XMIIdentity)]
+#[serde(tag = "_xmi:type")]
+#[serde(deny_unknown_fields)]
+/// RUST Struct for representing OwnedOperation Tag
+pub enum EnumOwnedOperation {
+    /// OwnedOperation with cmof:Operation type
+    #[serde(deserialize_with = "deser_rc")]
+    #[serde(rename = "cmof:Operation")]
+    Operation(Rc<CMOFOperation>),
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[derive(Clone, Debug, Deserialize, XMIIdentity)]
+#[serde(tag = "_xmi:type")]
+#[serde(deny_unknown_fields)]
+/// RUST Struct for representing OwnedParameter Tag
+pub enum EnumOwnedParameter {
+    /// OwnedParameter with cmof:Parameter type
+    #[serde(deserialize_with = "deser_rc")]
+    #[serde(rename = "cmof:Parameter")]
+    Parameter(Rc<CMOFParameter>),
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
 #[derive(Clone, Debug, Deserialize, XMIIdentity)]
 #[serde(tag = "_xmi:type")]
 #[serde(deny_unknown_fields)]
@@ -28,8 +28,10 @@ mod cmof_datatype;
 mod cmof_enumeration;
 mod cmof_enumeration_literal;
 mod cmof_opaque_expression;
+mod cmof_operation;
 mod cmof_package;
 mod cmof_package_import;
+mod cmof_parameter;
 mod cmof_primitive_type;
 mod cmof_property;
 mod cmof_tag;
@@ -37,6 +39,7 @@ mod enum_cmof_object;
 mod enum_href_object;
 mod file_cmof;
 mod set_xmi_id_object;
+mod uml_parameter_direction_kind;
 mod uml_visibility_kind;
 pub use cmof_association::*;
 pub use cmof_class::*;
@@ -45,8 +48,10 @@ pub use cmof_datatype::*;
 pub use cmof_enumeration::*;
 pub use cmof_enumeration_literal::*;
 pub use cmof_opaque_expression::*;
+pub use cmof_operation::*;
 pub use cmof_package::*;
 pub use cmof_package_import::*;
+pub use cmof_parameter::*;
 pub use cmof_primitive_type::*;
 pub use cmof_property::*;
 pub use cmof_tag::*;
@@ -54,4 +59,5 @@ pub use enum_cmof_object::*;
 pub use enum_href_object::*;
 pub use file_cmof::*;
 pub use set_xmi_id_object::*;
+pub use uml_parameter_direction_kind::*;
 pub use uml_visibility_kind::*;
@@ -86,6 +86,15 @@ pub fn default_visibility() -> UMLVisibilityKind {
 //
 // ####################################################################################################
 
+/// Default ParameterDirectionKind, as default value for serde_default
+pub fn default_direction() -> UMLParameterDirectionKind {
+    UMLParameterDirectionKind::In
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
 /// Default VisibilityKind, as default value for serde_default
 pub fn default_option<T>() -> Option<T> {
     None
@@ -86,6 +86,79 @@ where
 //
 // ####################################################################################################
 
+/// Deserialising to __Vec__, from array or single object, keeping the order of the source
+/// Not 'Option' tolerant, use 'default' for this
+pub fn deser_vec_keeping_order<'de: 'te, 'te: 'de, D, V>(
+    deserializer: D,
+) -> Result<Vec<V>, D::Error>
+where
+    D: de::Deserializer<'de>,
+    V: de::Deserialize<'te>,
+{
+    struct OneOrVec<V>(PhantomData<Vec<V>>);
+
+    impl<'de: 'te, 'te: 'de, V: de::Deserialize<'te>> de::Visitor<'de> for OneOrVec<V> {
+        type Value = Vec<V>;
+
+        // Requested type description, returned in error case
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("object or array of object")
+        }
+
+        // Result for Object
+        fn visit_map<E>(self, map: E) -> Result<Self::Value, E::Error>
+        where
+            E: de::MapAccess<'de>,
+        {
+            let v: V = de::Deserialize::deserialize(de::value::MapAccessDeserializer::new(map))?;
+            Ok(vec![v])
+        }
+
+        // Result for Array
+        fn visit_seq<S>(self, visitor: S) -> Result<Self::Value, S::Error>
+        where
+            S: de::SeqAccess<'de>,
+        {
+            de::Deserialize::deserialize(de::value::SeqAccessDeserializer::new(visitor))
+        }
+    }
+
+    deserializer.deserialize_any(OneOrVec(PhantomData))
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Object knowing its position in the source array
+pub trait DeclarationOrder {
+    /// Save the position of the object in the source array
+    fn set_declaration_order(&mut self, order: usize);
+}
+
+/// Deserialising to __BTreeMap__, from array or single object, saving the position of each object
+/// Not 'Option' tolerant, use 'default' for this
+pub fn deser_btreemap_using_name_as_key_keeping_order<'de: 'te, 'te: 'de, D, V>(
+    deserializer: D,
+) -> Result<BTreeMap<String, V>, D::Error>
+where
+    D: de::Deserializer<'de>,
+    V: de::Deserialize<'te>,
+    V: XMIIdentity + DeclarationOrder,
+{
+    let mut r: BTreeMap<String, V> = BTreeMap::new();
+    let values: Vec<V> = deser_vec_keeping_order(deserializer)?;
+    for (order, mut v) in values.into_iter().enumerate() {
+        v.set_declaration_order(order);
+        r.insert(v.get_xmi_id().get_object_id(), v);
+    }
+    Ok(r)
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,4 +231,54 @@ mod tests {
         let error_target = "invalid type: boolean `true`, expected object";
         check_deser_make_error::<RandomStruct>(input_str, error_target);
     }
+
+    #[derive(Clone, Debug, PartialEq, Deserialize)]
+    struct RandomVecStruct {
+        #[serde(deserialize_with = "deser_vec_keeping_order")]
+        value: Vec<String>,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Deserialize)]
+    struct RandomOrderStruct {
+        #[serde(deserialize_with = "deser_btreemap_using_name_as_key_keeping_order")]
+        value: BTreeMap<String, ThirdRandomStruct>,
+    }
+
+    #[derive(Clone, Debug, Deserialize, XMIIdentity)]
+    struct ThirdRandomStruct {
+        #[serde(deserialize_with = "deser_local_xmi_id")]
+        #[serde(rename = "_xmi:id")]
+        xmi_id: XMIIdLocalReference,
+        #[serde(skip)]
+        order: usize,
+    }
+
+    impl DeclarationOrder for ThirdRandomStruct {
+        fn set_declaration_order(&mut self, order: usize) {
+            self.order = order;
+        }
+    }
+
+    #[test]
+    fn deser_btreemap_using_name_as_key_keeping_order_01_creation() {
+        initialize_log_for_test();
+
+        let input_str = r#"{"value": [{"_xmi:id": "key_2"}, {"_xmi:id": "key_1"}]}"#;
+        let r: Result<RandomOrderStruct, serde_json::Error> = serde_json::from_str(input_str);
+        assert!(r.is_ok());
+        let value = r.unwrap().value;
+        assert_eq!(value.get("key_1").map(|v| v.order), Some(1));
+        assert_eq!(value.get("key_2").map(|v| v.order), Some(0));
+    }
+
+    #[test]
+    fn deser_vec_keeping_order_01_creation() {
+        initialize_log_for_test();
+
+        let input_str = r#"{"value": ["key_2", "key_1"]}"#;
+        let value_target = RandomVecStruct {
+            value: vec!["key_2".to_string(), "key_1".to_string()],
+        };
+        check_deser_make_no_error(input_str, &value_target);
+    }
 }
@@ -56,6 +56,8 @@ pub struct LoadingPackage {
     state: LoadingState,
     /// Sorted oned_member
     sorted_owned_member: BTreeMap<String, EnumOwnedMember>,
+    /// Tags of the package file ("cmof:Tag")
+    tags: Vec<CMOFTag>,
 }
 
 impl LoadingPackage {
@@ -67,6 +69,7 @@ impl LoadingPackage {
             cmof_object: None,
             state: LoadingState::Empty,
             sorted_owned_member: BTreeMap::new(),
+            tags: Vec::new(),
         }
     }
 
@@ -105,6 +108,27 @@ impl LoadingPackage {
         self.state = LoadingState::Loaded;
     }
 
+    /// Save tags of the package file
+    pub fn set_tags(&mut self, tags: Vec<CMOFTag>) {
+        self.tags = tags;
+    }
+
+    /// Value of a tag applied on the package itself (example : "org.omg.xmi.nsPrefix")
+    pub fn get_package_tag(&self, name: &str) -> Option<String> {
+        self.tags
+            .iter()
+            .find(|tag| tag.get_name() == name && tag.element == self.id)
+            .map(|tag| tag.value.clone())
+    }
+
+    /// Value of a tag applied on an element of the package, using its object id (example : "Process-name")
+    pub fn get_element_tag(&self, element: &str, name: &str) -> Option<String> {
+        self.tags
+            .iter()
+            .find(|tag| tag.get_name() == name && tag.element == element)
+            .map(|tag| tag.value.clone())
+    }
+
     /// Delete Element and change state
     pub fn make_finished(&mut self) -> Result<(), anyhow::Error> {
         self.cmof_object = None;
@@ -61,6 +61,14 @@ impl LoadingTracker {
         self.file_env.get_output_folder()
     }
 
+    /// Name of the input folder, for the head of the output files (same for each run, unlike output folder)
+    pub fn get_input_folder_name(&self) -> String {
+        self.get_input_folder()
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
     /// Shortcut function of file_env output folder
     pub fn get_order_len(&self) -> usize {
         self.importing_order.len()
@@ -100,13 +108,6 @@ impl LoadingTracker {
         self.file_env.delete_if_empty()?;
         Ok(())
     }
-
-    /// Recall for copy output to result
-    pub fn export_result(&mut self) -> Result<(), anyhow::Error> {
-        // Copy output to result
-        self.file_env.export_result()?;
-        Ok(())
-    }
 }
 
 // Algorithm
@@ -213,6 +214,7 @@ impl LoadingTracker {
         let package_object = self.loaded_package.get_mut(&label).unwrap();
         // package_object.make_loaded_element(package_element);
         package_object.make_loaded(cmof_package);
+        package_object.set_tags(cmof_result.tags);
 
         // Define treatment order
         let max = self.get_order_len();
@@ -24,6 +24,7 @@ If not, see <https://www.gnu.org/licenses/>.
 pub mod cmof_loader;
 pub mod custom_file_tools;
 pub mod custom_log_tools;
+pub mod reflective_api;
 
 // pub mod writing_entity;
 pub mod output_writing;
@@ -31,6 +32,7 @@ pub mod output_writing;
 // For "main" use only
 mod output_cargo_checker;
 mod output_result_manager;
+mod output_user_code;
 
 use log::info;
 
@@ -45,27 +47,76 @@ fn main() -> Result<(), anyhow::Error> {
     let logger_configuration = "config_log.yml"; // File for configuring logger
     let input_folder = "metamodel_file/"; // Folder where input file can be find
     let main_output_folder = "../Imbriqua_Output_file/"; // Folder dedicased to store output folders and files
-    let result_folder = "../Imbriqua_Structure_Result/entities/src"; // Folder dedicased to store output folders and files
+    let result_folder = "../Imbriqua_Structure_Result/entities"; // Folder dedicased to store output folders and files
+    let output_crate_name = "entities"; // Name of the output crate (Cargo.toml package)
     let main_package_file = "BPMNDI.json"; // File of the main package to explore
     let main_package_id = "_0"; // Package ID of main file to explore
+    let plantuml_neighbourhood = [("BPMN20-Process", 1)]; // Classes (and depth) for PlantUML neighbourhood diagram
+    let generators = [
+        "sea_orm",
+        "builder",
+        "domain_model",
+        "execution",
+        "bpmn_xml",
+        "diesel",
+        "json_schema",
+        "xsd",
+        "graphql",
+        "protobuf",
+        "typescript",
+        "doc_site",
+        "graphviz",
+        "plantuml",
+        "plantuml_neighbourhood",
+    ]; // Generators to run, in order
+    let args: Vec<String> = std::env::args().collect(); // Command line options
+    let template_folder = match args.iter().position(|arg| arg == "--template-folder") {
+        Some(index) => match args.get(index + 1) {
+            Some(folder) => Some(std::path::PathBuf::from(folder)),
+            None => {
+                return Err(anyhow::format_err!(
+                    "Missing folder after \"--template-folder\""
+                ))
+            }
+        },
+        None => None,
+    }; // Folder of "*.jinja" files overriding the Sea-ORM templates, the only backend with templates ("--template-folder <path>")
+    let dry_run = args.iter().any(|arg| arg == "--dry-run"); // Compare with the result folder instead of exporting (error if differences)
 
     // Initialise global logger, file environment and loading environment
     let _handle = custom_log_tools::open_logger(logger_configuration)?;
     let file_env =
         output_result_manager::open_env(input_folder, main_output_folder, result_folder)?;
-    let mut loading_env = cmof_loader::open_loader(file_env)?;
+    let mut loading_env = cmof_loader::open_loader(file_env.clone())?;
     // Load ordered packages list
     loading_env.make_prepare(main_package_file, main_package_id, "root")?;
 
-    // Makin lib.rs file
-    loading_env.write_lib_file()?;
-    // Makin all mod_x.rs file
-    loading_env.write_mod_object()?;
+    // Run the generators
+    let mut registry =
+        output_writing::GeneratorRegistry::with_default_generators(template_folder.as_deref())?;
+    registry.register(Box::new(
+        output_writing::writing_plantuml::PlantumlNeighbourhoodGenerator::new(
+            &plantuml_neighbourhood,
+        ),
+    ))?;
+    let crate_generator = output_writing::writing_crate::CrateGenerator::new(
+        output_crate_name,
+        &registry.get_selection(&generators)?,
+    )?;
+    registry.register(Box::new(crate_generator))?;
+    let sink = output_writing::OutputSink::new(&file_env.get_output_folder(), dry_run);
+    registry.run(&[&generators[..], &["crate"]].concat(), &loading_env, &sink)?;
 
     // Cleaning
     loading_env.close()?;
-    // Export the result
-    loading_env.export_result()?;
+    // Keep the hand-written user regions of the result (orphaned regions are kept in a side file)
+    let _orphaned_regions = file_env.restore_user_code()?;
+    // Export the result, with the manifest of the generation (or only compare it, for dry-run)
+    if dry_run {
+        file_env.check_result()?;
+    } else {
+        file_env.export_result(&sink.get_generation_sources(&file_env.get_input_folder())?)?;
+    }
 
     // // Make doc for loader
     // let cargo_loader_package = "Cargo.toml"; // Location of loader environment package Cargo.toml file
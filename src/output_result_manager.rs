@@ -24,9 +24,14 @@ If not, see <https://www.gnu.org/licenses/>.
 // Package section
 use crate::custom_file_tools::*;
 use crate::custom_log_tools::*;
+use crate::output_user_code::clear_user_code_regions;
 
 // Dependencies section
 use chrono::Local;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use similar::TextDiff;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Provide management of a input folder and a output folder (created with time name)
 #[derive(Clone, PartialEq, Debug)]
@@ -108,14 +113,342 @@ impl ResultEnv {
         self.output_folder.delete_folder(true)
     }
 
-    /// Copy output to result
-    pub fn export_result(&self) -> Result<(), anyhow::Error> {
-        // Purge folder
-        self.result_folder.purge_folder()?;
-        // Copy content of output_folder to result_folder
-        self.output_folder.copy_folder(&self.result_folder)?;
+    /// Copy output to result, only for changed files, and delete the files of the previous export missing in output (other files of result are kept)
+    ///
+    /// Files of the previous export edited by hand are reported before being overwritten, then the manifest of the export is written
+    ///
+    /// Error (before any change) if a file of result at a path of output wasn't written by a previous export, and has other content
+    pub fn export_result(&self, sources: &GenerationSources) -> Result<(), anyhow::Error> {
+        let diff = self.diff_result()?;
+        if !diff.untracked.is_empty() {
+            return Err(anyhow::format_err!(
+                "Files {:?} of {:?} weren't written by a previous export : move or delete them before the export",
+                diff.untracked,
+                self.result_folder
+            ));
+        }
+        for path in diff.get_overwritten_edits() {
+            warn!(
+                "File {:?} of {:?} was edited by hand since the last export : overwritten",
+                path, self.result_folder
+            );
+        }
+        // Write added and changed files only (unchanged files keep their modification time)
+        for path in diff.added.iter().chain(diff.changed.iter()) {
+            let to = self.result_folder.join(path);
+            if let Some(parent) = to.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::copy(self.output_folder.join(path), &to)?;
+        }
+        // Delete files of the previous export only, then their empty folders
+        for path in &diff.removed {
+            let file = self.result_folder.join(path);
+            file.delete_file()?;
+            let mut folder = file.parent();
+            while let Some(current) = folder {
+                if current == self.result_folder || current.read_dir()?.next().is_some() {
+                    break;
+                }
+                std::fs::remove_dir(current)?;
+                folder = current.parent();
+            }
+        }
+        // Files of this export, for the next one
+        let manifest = self.get_manifest(sources)?;
+        if manifest != ResultManifest::read(&self.result_folder)? {
+            manifest.write(&self.result_folder)?;
+        }
+        info!(
+            "Export to {:?} : {}",
+            self.result_folder,
+            diff.get_summary()
+        );
         Ok(())
     }
+
+    /// Manifest of the files of output, with their hash and the elements written in them
+    pub fn get_manifest(
+        &self,
+        sources: &GenerationSources,
+    ) -> Result<ResultManifest, anyhow::Error> {
+        let files = read_folder_files(&self.output_folder)?
+            .into_iter()
+            .map(|(path, content)| {
+                let file = ManifestFile {
+                    hash: get_generated_hash(&content),
+                    sources: sources.file_sources.get(&path).cloned().unwrap_or_default(),
+                };
+                (path, file)
+            })
+            .collect();
+        Ok(ResultManifest {
+            generator_version: String::from(env!("CARGO_PKG_VERSION")),
+            inputs: sources.inputs.clone(),
+            files,
+        })
+    }
+
+    /// Compare output to result, without changing result (dry-run of __export_result()__)
+    ///
+    /// Only the files of the previous export and the files at a path of output are read in result (not build or repository folders)
+    pub fn diff_result(&self) -> Result<ResultDiff, anyhow::Error> {
+        let output_files = read_folder_files(&self.output_folder)?;
+        let manifest = ResultManifest::read(&self.result_folder)?;
+        let result_files = read_files(
+            &self.result_folder,
+            output_files.keys().chain(manifest.files.keys()),
+        )?;
+        let mut diff = ResultDiff::default();
+        for (path, content) in &output_files {
+            match result_files.get(path) {
+                None => {
+                    diff.unified_diffs
+                        .insert(path.clone(), get_unified_diff(path, None, Some(content)));
+                    diff.added.push(path.clone());
+                }
+                Some(old_content) if old_content != content => {
+                    diff.unified_diffs.insert(
+                        path.clone(),
+                        get_unified_diff(path, Some(old_content), Some(content)),
+                    );
+                    if manifest.files.contains_key(path) {
+                        diff.changed.push(path.clone());
+                    } else {
+                        diff.untracked.push(path.clone());
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+        for (path, old_content) in &result_files {
+            // Only files of the previous export, other files of result aren't generated
+            let Some(manifest_file) = manifest.files.get(path) else {
+                continue;
+            };
+            if get_generated_hash(old_content) != manifest_file.hash {
+                diff.edited.push(path.clone());
+            }
+            if !output_files.contains_key(path) {
+                diff.unified_diffs.insert(
+                    path.clone(),
+                    get_unified_diff(path, Some(old_content), None),
+                );
+                diff.removed.push(path.clone());
+            }
+        }
+        Ok(diff)
+    }
+
+    /// Dry-run of __export_result()__ : print the differences between output and result, then delete output (error if differences)
+    pub fn check_result(&self) -> Result<(), anyhow::Error> {
+        let diff = self.diff_result()?;
+        for unified_diff in diff.unified_diffs.values() {
+            print!("{}", unified_diff);
+        }
+        for path in diff.get_overwritten_edits() {
+            warn!(
+                "File {:?} of {:?} was edited by hand since the last export : export would overwrite it",
+                path, self.result_folder
+            );
+        }
+        for path in &diff.untracked {
+            warn!(
+                "File {:?} of {:?} wasn't written by a previous export : export would refuse to overwrite it",
+                path, self.result_folder
+            );
+        }
+        info!(
+            "Dry-run against {:?} : {}",
+            self.result_folder,
+            diff.get_summary()
+        );
+        // Output folder is only written for the comparison
+        self.output_folder.purge_folder()?;
+        self.output_folder.delete_folder(false)?;
+        if diff.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::format_err!(
+                "Result folder {:?} isn't up to date ({})",
+                self.result_folder,
+                diff.get_summary()
+            ))
+        }
+    }
+}
+
+/// Differences between the output folder and the result folder, see __ResultEnv::diff_result()__
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ResultDiff {
+    /// Files of output missing in result (path relative to the folders)
+    pub added: Vec<PathBuf>,
+    /// Files of the previous export missing in output (path relative to the folders)
+    pub removed: Vec<PathBuf>,
+    /// Files with other content in output (path relative to the folders)
+    pub changed: Vec<PathBuf>,
+    /// Files of the previous export edited by hand since it (hash other than in the manifest)
+    pub edited: Vec<PathBuf>,
+    /// Files of result at a path of output with other content, not written by a previous export (not in the manifest)
+    pub untracked: Vec<PathBuf>,
+    /// Unified diff of each added, removed, changed or untracked file, from result to output
+    pub unified_diffs: BTreeMap<PathBuf, String>,
+}
+
+impl ResultDiff {
+    /// No difference between output and result
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.untracked.is_empty()
+    }
+
+    /// Files edited by hand, removed or changed by the export
+    pub fn get_overwritten_edits(&self) -> Vec<&PathBuf> {
+        self.edited
+            .iter()
+            .filter(|path| self.removed.contains(path) || self.changed.contains(path))
+            .collect()
+    }
+
+    /// Count of added, removed, changed and untracked files ("2 added, 0 removed, 1 changed (1 edited by hand), 0 untracked")
+    pub fn get_summary(&self) -> String {
+        format!(
+            "{} added, {} removed, {} changed ({} edited by hand), {} untracked",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len(),
+            self.get_overwritten_edits().len(),
+            self.untracked.len()
+        )
+    }
+}
+
+/// Inputs of a generation and sources of its files, for the manifest of the export
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct GenerationSources {
+    /// Hash of each input file (metamodel and extension files), by path ("metamodel_file/DC.json")
+    pub inputs: BTreeMap<PathBuf, String>,
+    /// Labels of the elements written in each file of output, by path relative to output ("DC.cmof#Font")
+    pub file_sources: BTreeMap<PathBuf, BTreeSet<String>>,
+}
+
+/// Exported file, in [`ResultManifest`]
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct ManifestFile {
+    /// Hash of the content with empty user regions (SHA-256, hexadecimal, see [`get_generated_hash`])
+    pub hash: String,
+    /// Labels of the elements written in the file (empty for a file made from all the model)
+    pub sources: BTreeSet<String>,
+}
+
+/// File of result listing the files of the last export, see __ResultEnv::export_result()__
+pub const RESULT_MANIFEST_FILE: &str = "imbriqua_manifest.json";
+
+/// Last export in result folder, stored in [`RESULT_MANIFEST_FILE`]
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct ResultManifest {
+    /// Version of Imbriqua Structure making the export
+    pub generator_version: String,
+    /// Hash of each input file, see [`GenerationSources`]
+    pub inputs: BTreeMap<PathBuf, String>,
+    /// Exported files, by path relative to the folder
+    pub files: BTreeMap<PathBuf, ManifestFile>,
+}
+
+impl ResultManifest {
+    /// Read the manifest of a result folder (empty if the folder has no manifest)
+    pub fn read(folder: &Path) -> Result<Self, anyhow::Error> {
+        let file = folder.join(RESULT_MANIFEST_FILE);
+        if !file.exists() {
+            return Ok(ResultManifest::default());
+        }
+        match serde_json::from_str(&file.get_file_content()?) {
+            Ok(manifest) => Ok(manifest),
+            Err(error) => {
+                // Manifest of an other version : no file of the previous export is known
+                warn!("Manifest {:?} isn't readable, ignored ({})", file, error);
+                Ok(ResultManifest::default())
+            }
+        }
+    }
+
+    /// Write the manifest in a result folder
+    pub fn write(&self, folder: &Path) -> Result<(), anyhow::Error> {
+        let content = serde_json::to_string_pretty(self)? + "\n";
+        std::fs::write(folder.join(RESULT_MANIFEST_FILE), content)?;
+        Ok(())
+    }
+}
+
+/// Hash of a file content (SHA-256, hexadecimal)
+pub fn get_content_hash(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+/// Hash of the generated code of a file content : user regions are emptied, so hand-written code in them isn't an edit (SHA-256, hexadecimal)
+pub fn get_generated_hash(content: &[u8]) -> String {
+    match std::str::from_utf8(content) {
+        Ok(text) => get_content_hash(clear_user_code_regions(text).as_bytes()),
+        Err(_) => get_content_hash(content),
+    }
+}
+
+/// Content of all files of a folder (and of its sub-folders, without manifest), by path relative to the folder
+pub fn read_folder_files(folder: &Path) -> Result<BTreeMap<PathBuf, Vec<u8>>, anyhow::Error> {
+    let mut result = BTreeMap::new();
+    let mut folders = vec![folder.to_path_buf()];
+    while let Some(current) = folders.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                folders.push(path);
+            } else {
+                let relative_path = path.strip_prefix(folder)?.to_path_buf();
+                if relative_path != Path::new(RESULT_MANIFEST_FILE) {
+                    result.insert(relative_path, std::fs::read(&path)?);
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Content of the existing files of a folder among the given paths (relative to the folder)
+fn read_files<'a>(
+    folder: &Path,
+    paths: impl Iterator<Item = &'a PathBuf>,
+) -> Result<BTreeMap<PathBuf, Vec<u8>>, anyhow::Error> {
+    let mut result = BTreeMap::new();
+    for path in paths {
+        let file = folder.join(path);
+        if file.is_file() {
+            result.insert(path.clone(), std::fs::read(&file)?);
+        }
+    }
+    Ok(result)
+}
+
+/// Unified diff of a file, from result content to output content (None for missing file)
+fn get_unified_diff(path: &Path, old: Option<&Vec<u8>>, new: Option<&Vec<u8>>) -> String {
+    let old_header = match old {
+        Some(_) => format!("result/{}", path.display()),
+        None => String::from("/dev/null"),
+    };
+    let new_header = match new {
+        Some(_) => format!("output/{}", path.display()),
+        None => String::from("/dev/null"),
+    };
+    let old_text = String::from_utf8(old.cloned().unwrap_or_default());
+    let new_text = String::from_utf8(new.cloned().unwrap_or_default());
+    match (old_text, new_text) {
+        (Ok(old_text), Ok(new_text)) => TextDiff::from_lines(&old_text, &new_text)
+            .unified_diff()
+            .header(&old_header, &new_header)
+            .to_string(),
+        _ => format!("Binary files {} and {} differ\n", old_header, new_header),
+    }
 }
 
 /// Shorcut of __ResultEnv::new()__, creating ResultEnv instance and creating output folder with time name
@@ -191,20 +524,26 @@ mod tests {
         // Setting
         let step_1 = "tests/output_result_manager/output_result_manager_04_export_result/step_1";
         let step_2 = "tests/output_result_manager/output_result_manager_04_export_result/step_2";
-        let input_path = "tests/output_result_manager/output_result_manager_04_export_result/input";
+        let input_path = step_1;
         let main_output_path =
             "tests/output_result_manager/output_result_manager_04_export_result/main_output";
         let result_path =
             "tests/output_result_manager/output_result_manager_04_export_result/result";
         // Preparing
         Path::new(main_output_path).purge_folder();
+        Path::new(result_path).purge_folder();
         // Test
         let file_env = open_env(input_path, main_output_path, result_path).unwrap();
         // Clone input in output
         Path::new(step_1).copy_folder(&file_env.get_output_folder());
         Path::new(step_2).copy_folder(&file_env.get_result_folder());
         // Export output in result
-        file_env.export_result();
+        let mut sources = GenerationSources::default();
+        sources.file_sources.insert(
+            PathBuf::from("f1.txt"),
+            BTreeSet::from([String::from("DC.cmof#Font")]),
+        );
+        file_env.export_result(&sources).unwrap();
         // Check if all files are in result
         let path_test_1 = Path::new(
             "tests/output_result_manager/output_result_manager_04_export_result/result/f1.txt",
@@ -224,12 +563,161 @@ mod tests {
         let path_test_2 = Path::new(
             "tests/output_result_manager/output_result_manager_04_export_result/result/f4",
         );
+        assert!(!path_test_1.exists());
+        assert!(!path_test_2.exists());
+        // Check if files out of the previous export are kept
         let path_test_3 = Path::new(
             "tests/output_result_manager/output_result_manager_04_export_result/result/f5/f6.txt",
         );
-        assert!(!path_test_1.exists());
-        assert!(!path_test_2.exists());
-        assert!(!path_test_3.exists());
+        assert!(path_test_3.exists());
+        // Check the manifest of the export
+        let manifest = ResultManifest::read(&file_env.get_result_folder()).unwrap();
+        let files: Vec<&Path> = manifest.files.keys().map(|p| p.as_path()).collect();
+        assert_eq!(files, vec![Path::new("f1.txt"), Path::new("f2/f3.txt")]);
+        let file = manifest.files.get(Path::new("f1.txt")).unwrap();
+        assert_eq!(
+            file.hash,
+            get_generated_hash(&std::fs::read(Path::new(result_path).join("f1.txt")).unwrap())
+        );
+        assert_eq!(file.sources, BTreeSet::from([String::from("DC.cmof#Font")]));
+        assert_eq!(manifest.generator_version, env!("CARGO_PKG_VERSION"));
+        // Ending
+        Path::new(main_output_path).purge_folder();
+        Path::new(result_path).purge_folder();
+    }
+
+    #[test]
+    fn output_result_manager_05_diff_result() {
+        // Logs
+        initialize_log_for_test();
+        // Setting
+        let step_1 = "tests/output_result_manager/output_result_manager_05_diff_result/step_1";
+        let step_2 = "tests/output_result_manager/output_result_manager_05_diff_result/step_2";
+        let input_path = step_1;
+        let main_output_path =
+            "tests/output_result_manager/output_result_manager_05_diff_result/main_output";
+        let result_path = "tests/output_result_manager/output_result_manager_05_diff_result/result";
+        // Preparing
+        Path::new(main_output_path).purge_folder();
+        Path::new(result_path).purge_folder();
+        // Test
+        let file_env = open_env(input_path, main_output_path, result_path).unwrap();
+        Path::new(step_1).copy_folder(&file_env.get_output_folder());
+        Path::new(step_2).copy_folder(&file_env.get_result_folder());
+        let diff = file_env.diff_result().unwrap();
+        assert_eq!(diff.added, vec![PathBuf::from("f2/f3.txt")]);
+        assert_eq!(diff.removed, vec![PathBuf::from("f4.txt")]);
+        assert_eq!(diff.changed, vec![PathBuf::from("f1.txt")]);
+        // "f1.txt" of result isn't the exported one
+        assert_eq!(diff.edited, vec![PathBuf::from("f1.txt")]);
+        assert_eq!(diff.get_overwritten_edits(), vec![&PathBuf::from("f1.txt")]);
+        assert_eq!(
+            diff.get_summary(),
+            "1 added, 1 removed, 1 changed (1 edited by hand), 0 untracked"
+        );
+        assert_eq!(
+            diff.unified_diffs.get(Path::new("f1.txt")).unwrap(),
+            "--- result/f1.txt\n+++ output/f1.txt\n@@ -1,2 +1,2 @@\n same\n-old\n+new\n"
+        );
+        assert!(!diff.unified_diffs.contains_key(Path::new("f5.txt")));
+        // Dry-run : result unchanged, output deleted
+        assert!(file_env.check_result().is_err());
+        assert!(Path::new(result_path).join("f4.txt").exists());
+        assert!(!file_env.get_output_folder().exists());
+        // Ending
+        Path::new(main_output_path).purge_folder();
+        Path::new(result_path).purge_folder();
+    }
+
+    #[test]
+    fn output_result_manager_06_untracked_file() {
+        // Logs
+        initialize_log_for_test();
+        // Setting
+        let step_1 = "tests/output_result_manager/output_result_manager_06_untracked_file/step_1";
+        let step_2 = "tests/output_result_manager/output_result_manager_06_untracked_file/step_2";
+        let input_path = step_1;
+        let main_output_path =
+            "tests/output_result_manager/output_result_manager_06_untracked_file/main_output";
+        let result_path =
+            "tests/output_result_manager/output_result_manager_06_untracked_file/result";
+        // Preparing
+        Path::new(main_output_path).purge_folder();
+        Path::new(result_path).purge_folder();
+        // Test
+        let file_env = open_env(input_path, main_output_path, result_path).unwrap();
+        Path::new(step_1).copy_folder(&file_env.get_output_folder());
+        Path::new(step_2).copy_folder(&file_env.get_result_folder());
+        // "f1.txt" of result isn't from an export, "f2.txt" has the same content, "notes/" isn't read
+        let diff = file_env.diff_result().unwrap();
+        assert_eq!(diff.untracked, vec![PathBuf::from("f1.txt")]);
+        assert!(diff.added.is_empty() && diff.changed.is_empty() && diff.removed.is_empty());
+        assert_eq!(
+            diff.get_summary(),
+            "0 added, 0 removed, 0 changed (0 edited by hand), 1 untracked"
+        );
+        // Export refused, result unchanged
+        let sources = GenerationSources::default();
+        assert!(file_env.export_result(&sources).is_err());
+        let file = Path::new(result_path).join("f1.txt");
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "hand\n");
+        assert!(!Path::new(result_path).join(RESULT_MANIFEST_FILE).exists());
+        // Export once the file is moved
+        file.delete_file().unwrap();
+        file_env.export_result(&sources).unwrap();
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "new\n");
+        let manifest = ResultManifest::read(&file_env.get_result_folder()).unwrap();
+        let files: Vec<&Path> = manifest.files.keys().map(|p| p.as_path()).collect();
+        assert_eq!(files, vec![Path::new("f1.txt"), Path::new("f2.txt")]);
+        assert!(Path::new(result_path).join("notes/f3.txt").exists());
+        // Ending
+        Path::new(main_output_path).purge_folder();
+        Path::new(result_path).purge_folder();
+    }
+
+    #[test]
+    fn output_result_manager_07_edited_user_region() {
+        // Logs
+        initialize_log_for_test();
+        // Setting
+        let step_1 =
+            "tests/output_result_manager/output_result_manager_07_edited_user_region/step_1";
+        let step_2 =
+            "tests/output_result_manager/output_result_manager_07_edited_user_region/step_2";
+        let input_path = step_1;
+        let main_output_path =
+            "tests/output_result_manager/output_result_manager_07_edited_user_region/main_output";
+        let result_path =
+            "tests/output_result_manager/output_result_manager_07_edited_user_region/result";
+        // Preparing
+        Path::new(main_output_path).purge_folder();
+        Path::new(result_path).purge_folder();
+        // Test
+        let file_env = open_env(input_path, main_output_path, result_path).unwrap();
+        Path::new(step_1).copy_folder(&file_env.get_output_folder());
+        Path::new(step_2).copy_folder(&file_env.get_result_folder());
+        // Hash of the generated code only
+        assert_eq!(
+            get_generated_hash(b"a\n// <user-code name=\"r\">\nhand\n// </user-code>\n"),
+            get_content_hash(b"a\n// <user-code name=\"r\">\n// </user-code>\n")
+        );
+        // "a.rs" edited in its user region only, "b.rs" edited out of user regions
+        file_env.restore_user_code().unwrap();
+        let diff = file_env.diff_result().unwrap();
+        assert_eq!(diff.changed, vec![PathBuf::from("b.rs")]);
+        assert_eq!(diff.edited, vec![PathBuf::from("b.rs")]);
+        assert_eq!(diff.get_overwritten_edits(), vec![&PathBuf::from("b.rs")]);
+        // Manifest of the export : same hash for "a.rs"
+        let manifest = file_env
+            .get_manifest(&GenerationSources::default())
+            .unwrap();
+        assert_eq!(
+            manifest.files.get(Path::new("a.rs")),
+            ResultManifest::read(&file_env.get_result_folder())
+                .unwrap()
+                .files
+                .get(Path::new("a.rs"))
+        );
         // Ending
         Path::new(main_output_path).purge_folder();
         Path::new(result_path).purge_folder();
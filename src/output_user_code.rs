@@ -0,0 +1,337 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../doc/output_user_code.md")]
+
+// Package section
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_result_manager::*;
+
+// Dependencies section
+use std::collections::BTreeMap;
+
+/// Start line of a user region, followed by the name of the region and by [`USER_CODE_START_END`]
+pub const USER_CODE_START: &str = "// <user-code name=\"";
+
+/// End of the start line of a user region
+pub const USER_CODE_START_END: &str = "\">";
+
+/// End line of a user region
+pub const USER_CODE_END: &str = "// </user-code>";
+
+/// File of output keeping the content of the orphaned regions (and of the orphaned regions of the previous exports)
+pub const ORPHANED_USER_CODE_FILE: &str = "imbriqua_orphaned_user_code.txt";
+
+/// First line of [`ORPHANED_USER_CODE_FILE`]
+const ORPHANED_USER_CODE_HEADER: &str = "Hand-written code of user regions without place in the generated files : copy it back, then delete it from this file\n";
+
+/// User region of a file of the previous output, without place in the new output
+#[derive(Clone, PartialEq, Debug)]
+pub struct OrphanedRegion {
+    /// File of the region (path relative to the result folder)
+    pub file: PathBuf,
+    /// Name of the region
+    pub name: String,
+    /// Hand-written content of the region
+    pub content: String,
+}
+
+/// Contents of the user regions of a file, by name of region
+pub fn get_user_code_regions(content: &str) -> Result<BTreeMap<String, String>, anyhow::Error> {
+    let mut result = BTreeMap::new();
+    let mut current: Option<(String, String)> = None;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if let Some(name) = get_region_name(trimmed) {
+            if let Some((other, _)) = current {
+                return Err(anyhow::format_err!(
+                    "User region \"{}\" starts inside user region \"{}\"",
+                    name,
+                    other
+                ));
+            }
+            current = Some((name, String::new()));
+        } else if trimmed == USER_CODE_END {
+            let (name, region_content) = current
+                .take()
+                .ok_or(anyhow::format_err!("User region end without start"))?;
+            if result.insert(name.clone(), region_content).is_some() {
+                return Err(anyhow::format_err!(
+                    "User region \"{}\" is defined twice",
+                    name
+                ));
+            }
+        } else if let Some((_, ref mut region_content)) = current {
+            region_content.push_str(line);
+        }
+    }
+    match current {
+        Some((name, _)) => Err(anyhow::format_err!("User region \"{}\" without end", name)),
+        None => Ok(result),
+    }
+}
+
+/// Replace the contents of the user regions of a file (regions without new content are kept)
+pub fn set_user_code_regions(
+    content: &str,
+    regions: &BTreeMap<String, String>,
+) -> Result<String, anyhow::Error> {
+    // Checking regions of the file
+    get_user_code_regions(content)?;
+    let mut result = String::new();
+    let mut in_replaced_region = false;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if let Some(name) = get_region_name(trimmed) {
+            result.push_str(line);
+            if let Some(region_content) = regions.get(&name) {
+                result.push_str(region_content);
+                in_replaced_region = true;
+            }
+        } else if trimmed == USER_CODE_END {
+            result.push_str(line);
+            in_replaced_region = false;
+        } else if !in_replaced_region {
+            result.push_str(line);
+        }
+    }
+    Ok(result)
+}
+
+/// Content of a file with empty user regions (only the generated code), or the same content if its regions are invalid
+pub fn clear_user_code_regions(content: &str) -> String {
+    match get_user_code_regions(content) {
+        Ok(regions) => {
+            let empty_regions = regions
+                .into_keys()
+                .map(|name| (name, String::new()))
+                .collect();
+            set_user_code_regions(content, &empty_regions).unwrap_or_else(|_| content.to_string())
+        }
+        Err(_) => content.to_string(),
+    }
+}
+
+/// Name of the region of a start line ("// <user-code name="behavior">" --> "behavior")
+fn get_region_name(trimmed_line: &str) -> Option<String> {
+    trimmed_line
+        .strip_prefix(USER_CODE_START)?
+        .strip_suffix(USER_CODE_START_END)
+        .map(|name| name.to_string())
+}
+
+impl ResultEnv {
+    /// Re-insert the user regions of the files of result in the files of output, returning the regions without place in output
+    ///
+    /// Only the files of the previous export (see [`ResultManifest`]) are read. The orphaned regions are written in [`ORPHANED_USER_CODE_FILE`] of output
+    pub fn restore_user_code(&self) -> Result<Vec<OrphanedRegion>, anyhow::Error> {
+        let output_folder = self.get_output_folder();
+        let result_folder = self.get_result_folder();
+        let output_files = read_folder_files(&output_folder)?;
+        let manifest = ResultManifest::read(&result_folder)?;
+        let mut orphaned_regions = Vec::new();
+        let mut orphaned_content = String::from(ORPHANED_USER_CODE_HEADER);
+        for path in manifest.files.keys() {
+            let Ok(old_content) = std::fs::read_to_string(result_folder.join(path)) else {
+                continue;
+            };
+            if path == Path::new(ORPHANED_USER_CODE_FILE) {
+                // Orphaned regions of the previous exports are kept
+                orphaned_content = old_content;
+                continue;
+            }
+            let old_regions = get_user_code_regions(&old_content)
+                .map_err(|error| anyhow::format_err!("{:?} : {}", path, error))?;
+            if old_regions.is_empty() {
+                continue;
+            }
+            // Regions of a file without new version, or without region of the same name in the new version
+            let new_content = match output_files.get(path) {
+                Some(content) => String::from_utf8(content.clone())?,
+                None => String::new(),
+            };
+            let new_regions = get_user_code_regions(&new_content)
+                .map_err(|error| anyhow::format_err!("{:?} : {}", path, error))?;
+            for (name, content) in &old_regions {
+                if !new_regions.contains_key(name) {
+                    warn!(
+                        "Orphaned user region \"{}\" of {:?}, kept in {:?}",
+                        name, path, ORPHANED_USER_CODE_FILE
+                    );
+                    orphaned_regions.push(OrphanedRegion {
+                        file: path.clone(),
+                        name: name.clone(),
+                        content: content.clone(),
+                    });
+                }
+            }
+            // Writing the new version with the user regions
+            if output_files.contains_key(path) {
+                let restored = set_user_code_regions(&new_content, &old_regions)?;
+                if restored != new_content {
+                    std::fs::write(output_folder.join(path), restored)?;
+                    debug!("User regions of {:?} restored", path);
+                }
+            }
+        }
+        // Orphaned regions, after the ones of the previous exports
+        for region in &orphaned_regions {
+            orphaned_content.push_str(&format!(
+                "\n// Orphaned user region \"{}\" of {:?}\n{}",
+                region.name, region.file, region.content
+            ));
+        }
+        if orphaned_content != ORPHANED_USER_CODE_HEADER {
+            std::fs::write(
+                output_folder.join(ORPHANED_USER_CODE_FILE),
+                orphaned_content,
+            )?;
+        }
+        info!(
+            "User regions restored from {:?} ({} orphaned)",
+            result_folder,
+            orphaned_regions.len()
+        );
+        Ok(orphaned_regions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+
+    #[test]
+    fn output_user_code_01_get_user_code_regions() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let content = "use a;\n// <user-code name=\"behavior\">\nimpl B for A {}\n// </user-code>\n\n    // <user-code name=\"impl\">\n    // </user-code>\n";
+            let regions = get_user_code_regions(content)?;
+            assert_eq!(regions.len(), 2);
+            assert_eq!(regions.get("behavior").unwrap(), "impl B for A {}\n");
+            assert_eq!(regions.get("impl").unwrap(), "");
+
+            // Errors
+            assert!(get_user_code_regions("// <user-code name=\"a\">\n").is_err());
+            assert!(get_user_code_regions("// </user-code>\n").is_err());
+            assert!(get_user_code_regions(
+                "// <user-code name=\"a\">\n// <user-code name=\"b\">\n// </user-code>\n// </user-code>\n"
+            )
+            .is_err());
+            assert!(get_user_code_regions(
+                "// <user-code name=\"a\">\n// </user-code>\n// <user-code name=\"a\">\n// </user-code>\n"
+            )
+            .is_err());
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn output_user_code_02_set_user_code_regions() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let content = "use a;\n// <user-code name=\"behavior\">\nimpl B for A {}\n// </user-code>\n// <user-code name=\"impl\">\nimpl A {}\n// </user-code>\n";
+            let mut regions = BTreeMap::new();
+            regions.insert(
+                String::from("behavior"),
+                String::from("impl B for A {\n    fn b() {}\n}\n"),
+            );
+            regions.insert(String::from("other"), String::from("fn other() {}\n"));
+            assert_eq!(
+                set_user_code_regions(content, &regions)?,
+                "use a;\n// <user-code name=\"behavior\">\nimpl B for A {\n    fn b() {}\n}\n// </user-code>\n// <user-code name=\"impl\">\nimpl A {}\n// </user-code>\n"
+            );
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn output_user_code_03_restore_user_code() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let test_folder = "tests/output_user_code/output_user_code_03_restore_user_code/";
+            let main_output_path = format!("{test_folder}main_output");
+            let result_path = format!("{test_folder}result");
+            let file_env = open_env(
+                &format!("{test_folder}step_1"),
+                &main_output_path,
+                &result_path,
+            )?;
+            Path::new(&format!("{test_folder}step_1"))
+                .copy_folder(&file_env.get_output_folder())?;
+            Path::new(&format!("{test_folder}step_2"))
+                .copy_folder(&file_env.get_result_folder())?;
+
+            // "a.rs" restored, region of "b.rs" (removed) orphaned, "c.rs" isn't exported (not read)
+            let orphaned_regions = file_env.restore_user_code()?;
+            let output_folder = file_env.get_output_folder();
+            assert_eq!(
+                std::fs::read_to_string(output_folder.join("a.rs"))?,
+                "use a;\n// <user-code name=\"behavior\">\nimpl B for A {\n    fn b() {}\n}\n// </user-code>\n"
+            );
+            assert_eq!(orphaned_regions.len(), 1);
+            assert_eq!(orphaned_regions[0].file, PathBuf::from("b.rs"));
+            assert_eq!(orphaned_regions[0].name, "impl");
+            let orphaned_content =
+                std::fs::read_to_string(output_folder.join(ORPHANED_USER_CODE_FILE))?;
+            assert_eq!(
+                orphaned_content,
+                format!("{ORPHANED_USER_CODE_HEADER}\n// Orphaned user region \"impl\" of \"b.rs\"\nimpl B {{\n    fn hand_written() {{}}\n}}\n")
+            );
+
+            // Orphaned regions are kept by the next export
+            file_env.export_result(&GenerationSources::default())?;
+            Path::new(&main_output_path).purge_folder()?;
+            let file_env = open_env(
+                &format!("{test_folder}step_1"),
+                &main_output_path,
+                &result_path,
+            )?;
+            Path::new(&format!("{test_folder}step_1"))
+                .copy_folder(&file_env.get_output_folder())?;
+            assert!(file_env.restore_user_code()?.is_empty());
+            assert_eq!(
+                std::fs::read_to_string(
+                    file_env.get_output_folder().join(ORPHANED_USER_CODE_FILE)
+                )?,
+                orphaned_content
+            );
+
+            Path::new(&main_output_path).purge_folder()?;
+            Path::new(&result_path).purge_folder()?;
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+}
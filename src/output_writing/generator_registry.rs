@@ -0,0 +1,337 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../doc/generator_registry.md")]
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::writing_bpmn_xml::BpmnXmlGenerator;
+use crate::output_writing::writing_builder::BuilderGenerator;
+use crate::output_writing::writing_diesel::DieselGenerator;
+use crate::output_writing::writing_doc_site::DocSiteGenerator;
+use crate::output_writing::writing_domain_model::DomainModelGenerator;
+use crate::output_writing::writing_entity::SeaOrmGenerator;
+use crate::output_writing::writing_execution::ExecutionGenerator;
+use crate::output_writing::writing_graphql::GraphqlGenerator;
+use crate::output_writing::writing_graphviz::GraphvizGenerator;
+use crate::output_writing::writing_json_schema::JsonSchemaGenerator;
+use crate::output_writing::writing_plantuml::PlantumlGenerator;
+use crate::output_writing::writing_protobuf::ProtobufGenerator;
+use crate::output_writing::writing_typescript::TypescriptGenerator;
+use crate::output_writing::writing_xsd::XsdGenerator;
+use crate::output_writing::*;
+
+// Dependencies section
+use std::path::{Path, PathBuf};
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Rust module of the output crate, written by a generator (see [`CrateGenerator`](crate::output_writing::writing_crate::CrateGenerator))
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateModule {
+    /// Name of the module (sub-folder of the source folder, "builder")
+    pub name: &'static str,
+    /// Description of the module, for __lib.rs__ and __README.md__
+    pub description: &'static str,
+    /// Crates used by the module : (name, specification in __Cargo.toml__)
+    pub dependencies: &'static [(&'static str, &'static str)],
+    /// Other modules of the crate used by the module ("entities")
+    pub required_modules: &'static [&'static str],
+    /// Items of the module re-exported by the prelude ("*", "read_bpmn_xml")
+    pub prelude_items: &'static [&'static str],
+    /// Attributes needed in the crate root (r#"recursion_limit = "1024""#)
+    pub crate_attributes: &'static [&'static str],
+}
+
+/// Code generator backend, writing its output files from the loaded model
+pub trait Generator {
+    /// Name used to select the backend in [`GeneratorRegistry`] ("sea_orm")
+    fn get_name(&self) -> &str;
+
+    /// Write the output files, from the packages of the model (after [`LoadingTracker::make_prepare`]), in the sink
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error>;
+
+    /// Rust module of the output crate written by the backend, None if it writes no Rust code
+    fn get_crate_module(&self) -> Option<CrateModule> {
+        None
+    }
+
+    /// Check if the backend reads the template folder (see [`TemplateSet`]), false for backends with compiled-in code
+    fn use_template_folder(&self) -> bool {
+        false
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Generators available for a run, selected by name
+#[derive(Default)]
+pub struct GeneratorRegistry {
+    /// Generators, in registration order
+    generators: Vec<Box<dyn Generator>>,
+    /// Template folder given to the backends with templates, if any
+    template_folder: Option<PathBuf>,
+}
+
+impl GeneratorRegistry {
+    /// Empty registry
+    pub fn new() -> Self {
+        GeneratorRegistry::default()
+    }
+
+    /// Registry with all built-in backends, the Sea-ORM one using the template folder (see [`TemplateSet`])
+    pub fn with_default_generators(template_folder: Option<&Path>) -> Result<Self, anyhow::Error> {
+        let mut registry = GeneratorRegistry::new();
+        registry.template_folder = template_folder.map(Path::to_path_buf);
+        registry.register(Box::new(SeaOrmGenerator::new(template_folder)))?;
+        registry.register(Box::new(BuilderGenerator))?;
+        registry.register(Box::new(DomainModelGenerator))?;
+        registry.register(Box::new(ExecutionGenerator))?;
+        registry.register(Box::new(BpmnXmlGenerator))?;
+        registry.register(Box::new(DieselGenerator))?;
+        registry.register(Box::new(JsonSchemaGenerator))?;
+        registry.register(Box::new(XsdGenerator))?;
+        registry.register(Box::new(GraphqlGenerator))?;
+        registry.register(Box::new(ProtobufGenerator))?;
+        registry.register(Box::new(TypescriptGenerator))?;
+        registry.register(Box::new(DocSiteGenerator))?;
+        registry.register(Box::new(GraphvizGenerator))?;
+        registry.register(Box::new(PlantumlGenerator))?;
+        Ok(registry)
+    }
+
+    /// Add a generator, error if the name is already used
+    pub fn register(&mut self, generator: Box<dyn Generator>) -> Result<(), anyhow::Error> {
+        if self.get_generator(generator.get_name()).is_some() {
+            return Err(anyhow::format_err!(
+                "Generator \"{}\" already registered",
+                generator.get_name()
+            ));
+        }
+        self.generators.push(generator);
+        Ok(())
+    }
+
+    /// Names of all generators, in registration order
+    pub fn get_names(&self) -> Vec<&str> {
+        self.generators
+            .iter()
+            .map(|generator| generator.get_name())
+            .collect()
+    }
+
+    /// Generator using its name
+    pub fn get_generator(&self, name: &str) -> Option<&dyn Generator> {
+        self.generators
+            .iter()
+            .find(|generator| generator.get_name() == name)
+            .map(|generator| generator.as_ref())
+    }
+
+    /// Generators of a selection, in selection order (error if a name is unknown)
+    pub fn get_selection(&self, names: &[&str]) -> Result<Vec<&dyn Generator>, anyhow::Error> {
+        let mut result = Vec::new();
+        for name in names {
+            match self.get_generator(name) {
+                Some(generator) => result.push(generator),
+                None => {
+                    return Err(anyhow::format_err!(
+                        "Unknown generator \"{}\" (available : {})",
+                        name,
+                        self.get_names().join(", ")
+                    ))
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Check that a selected generator reads the template folder, if any (the other selected generators are logged : they don't read it)
+    pub fn check_template_folder(&self, names: &[&str]) -> Result<(), anyhow::Error> {
+        let Some(template_folder) = &self.template_folder else {
+            return Ok(());
+        };
+        let (with_templates, without_templates): (Vec<&dyn Generator>, Vec<&dyn Generator>) = self
+            .get_selection(names)?
+            .into_iter()
+            .partition(|generator| generator.use_template_folder());
+        if with_templates.is_empty() {
+            let names: Vec<&str> = self
+                .generators
+                .iter()
+                .filter(|generator| generator.use_template_folder())
+                .map(|generator| generator.get_name())
+                .collect();
+            return Err(anyhow::format_err!(
+                "Template folder {:?} not used : no selected generator has templates (only {})",
+                template_folder,
+                names.join(", ")
+            ));
+        }
+        if !without_templates.is_empty() {
+            let names: Vec<&str> = without_templates
+                .iter()
+                .map(|generator| generator.get_name())
+                .collect();
+            warn!(
+                "Template folder {:?} not used by generators without templates : {}",
+                template_folder,
+                names.join(", ")
+            );
+        }
+        Ok(())
+    }
+
+    /// Run the selected generators, in selection order (nothing is written if a name is unknown, or if the template folder isn't used)
+    pub fn run(
+        &self,
+        names: &[&str],
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        self.check_template_folder(names)?;
+        for generator in self.get_selection(names)? {
+            debug!("Generator \"{}\" : START", generator.get_name());
+            generator.generate(model, sink)?;
+            info!("Generator \"{}\" : Finished", generator.get_name());
+        }
+        // Generated Rust files must be valid, even if written as text
+        let count = check_rust_files(&sink.get_output_folder())?;
+        info!("{count} generated Rust files checked");
+        Ok(())
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+
+    /// Generator writing nothing, with a name
+    struct NamedGenerator(&'static str);
+
+    impl Generator for NamedGenerator {
+        fn get_name(&self) -> &str {
+            self.0
+        }
+
+        fn generate(
+            &self,
+            _model: &LoadingTracker,
+            _sink: &dyn WrittingPath,
+        ) -> Result<(), anyhow::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn generator_registry_01_default_generators() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let registry = GeneratorRegistry::with_default_generators(None)?;
+            assert_eq!(registry.get_names().first(), Some(&"sea_orm"));
+            assert!(registry.get_generator("xsd").is_some());
+            assert!(registry.get_generator("plantuml_neighbourhood").is_none());
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn generator_registry_02_register_and_select() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let mut registry = GeneratorRegistry::new();
+            registry.register(Box::new(NamedGenerator("first")))?;
+            registry.register(Box::new(NamedGenerator("second")))?;
+            assert!(registry
+                .register(Box::new(NamedGenerator("first")))
+                .is_err());
+            assert_eq!(registry.get_names(), vec!["first", "second"]);
+
+            // Selection order, not registration order
+            let names: Vec<&str> = registry
+                .get_selection(&["second", "first"])?
+                .iter()
+                .map(|generator| generator.get_name())
+                .collect();
+            assert_eq!(names, vec!["second", "first"]);
+
+            let r = registry.get_selection(&["first", "unknown"]);
+            assert_eq!(
+                r.err().map(|error| error.to_string()),
+                Some(String::from(
+                    "Unknown generator \"unknown\" (available : first, second)"
+                ))
+            );
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn generator_registry_03_template_folder() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            // Without template folder, any selection
+            let registry = GeneratorRegistry::with_default_generators(None)?;
+            registry.check_template_folder(&["xsd"])?;
+
+            // With template folder, only if Sea-ORM is selected
+            let registry =
+                GeneratorRegistry::with_default_generators(Some(Path::new("templates")))?;
+            registry.check_template_folder(&["sea_orm", "xsd"])?;
+            let r = registry.check_template_folder(&["xsd", "builder"]);
+            assert_eq!(
+                r.err().map(|error| error.to_string()),
+                Some(String::from(
+                    "Template folder \"templates\" not used : no selected generator has templates (only sea_orm)"
+                ))
+            );
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+}
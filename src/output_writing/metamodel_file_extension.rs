@@ -20,6 +20,7 @@ If not, see <https://www.gnu.org/licenses/>.
 #![warn(missing_docs)]
 #![doc = include_str!("../../doc/writing_entity.md")]
 
+use serde::de::DeserializeOwned;
 use std::collections::BTreeMap;
 
 // Package section
@@ -39,30 +40,83 @@ struct SimpleValue {
     pub value: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct SqlTypeValue {
+    pub key: String,
+    pub sql_type: String,
+    pub rust_type: String,
+}
+
+/// Entry of a metamodel extension file, as key and value
+trait MetamodelFileEntry: DeserializeOwned {
+    type Value: std::fmt::Debug;
+
+    fn into_entry(self) -> (String, Self::Value);
+}
+
+impl MetamodelFileEntry for SimpleValue {
+    type Value = String;
+
+    fn into_entry(self) -> (String, Self::Value) {
+        (self.key, self.value)
+    }
+}
+
+impl MetamodelFileEntry for SqlTypeValue {
+    type Value = (String, String);
+
+    fn into_entry(self) -> (String, Self::Value) {
+        (self.key, (self.sql_type, self.rust_type))
+    }
+}
+
 // // ####################################################################################################
 // //
 // // ####################################################################################################
 
+/// Folder of the metamodel extension files
+pub const METAMODEL_EXTENSION_FOLDER: &str = "metamodel_file_extension";
+
 /// Storage content of "metamodel_file_extension/enumeration_default_value.json" file
 pub type EnumerationDefaultValues = BTreeMap<String, String>;
 
 /// Storage content of "metamodel_file_extension/primitive_type_conversion.json" file
 pub type PrimitiveTypeConversion = BTreeMap<String, String>;
 
+/// Storage content of "metamodel_file_extension/diesel_type_conversion.json" file (SQL type, Rust type)
+pub type DieselTypeConversion = BTreeMap<String, (String, String)>;
+
+/// Storage content of "metamodel_file_extension/json_schema_type_conversion.json" file
+pub type JsonSchemaTypeConversion = BTreeMap<String, String>;
+
+/// Storage content of "metamodel_file_extension/xsd_type_conversion.json" file
+pub type XsdTypeConversion = BTreeMap<String, String>;
+
+/// Storage content of "metamodel_file_extension/graphql_type_conversion.json" file
+pub type GraphqlTypeConversion = BTreeMap<String, String>;
+
+/// Storage content of "metamodel_file_extension/protobuf_type_conversion.json" file
+pub type ProtobufTypeConversion = BTreeMap<String, String>;
+
+/// Storage content of "metamodel_file_extension/typescript_type_conversion.json" file
+pub type TypescriptTypeConversion = BTreeMap<String, String>;
+
+/// Storage content of "metamodel_file_extension/bpmn_xml_name.json" file
+pub type BpmnXmlNames = BTreeMap<String, String>;
+
 // // ####################################################################################################
 // //
 // // ####################################################################################################
 
-/// Provide content of "metamodel_file_extension/enumeration_default_value.json" file
-pub fn read_enumeration_default_values() -> Result<EnumerationDefaultValues, anyhow::Error> {
-    let reader_path = Path::new("metamodel_file_extension/enumeration_default_value.json");
+/// Provide content of a file of "metamodel_file_extension" folder, by key
+fn read_metamodel_file_extension<T: MetamodelFileEntry>(
+    file_name: &str,
+) -> Result<BTreeMap<String, T::Value>, anyhow::Error> {
+    let reader_path = Path::new(METAMODEL_EXTENSION_FOLDER).join(file_name);
     let reader = reader_path.get_file_content()?;
-    let values: Vec<SimpleValue> = serde_json::from_str(&reader)?;
-    let values: EnumerationDefaultValues = values
-        .iter()
-        .map(|x| (x.key.clone(), x.value.clone()))
-        .collect();
-    trace!("Read Enumeration Default Values : {:#?}", &values);
+    let values: Vec<T> = serde_json::from_str(&reader)?;
+    let values: BTreeMap<String, T::Value> = values.into_iter().map(T::into_entry).collect();
+    trace!("Read \"{}\" : {:#?}", file_name, &values);
     Ok(values)
 }
 
@@ -70,15 +124,79 @@ pub fn read_enumeration_default_values() -> Result<EnumerationDefaultValues, any
 // //
 // // ####################################################################################################
 
+/// Provide content of "metamodel_file_extension/enumeration_default_value.json" file
+pub fn read_enumeration_default_values() -> Result<EnumerationDefaultValues, anyhow::Error> {
+    read_metamodel_file_extension::<SimpleValue>("enumeration_default_value.json")
+}
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
 /// Provide content of "metamodel_file_extension/primitive_type_conversion.json" file
-pub fn read_primitive_type_conversion() -> Result<BTreeMap<String, String>, anyhow::Error> {
-    let reader_path = Path::new("metamodel_file_extension/primitive_type_conversion.json");
-    let reader = reader_path.get_file_content()?;
-    let values: Vec<SimpleValue> = serde_json::from_str(&reader)?;
-    let values: BTreeMap<String, String> = values
-        .iter()
-        .map(|x| (x.key.clone(), x.value.clone()))
-        .collect();
-    trace!("Read Prititive Type Conversion : {:#?}", &values);
-    Ok(values)
+pub fn read_primitive_type_conversion() -> Result<PrimitiveTypeConversion, anyhow::Error> {
+    read_metamodel_file_extension::<SimpleValue>("primitive_type_conversion.json")
+}
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+/// Provide content of "metamodel_file_extension/diesel_type_conversion.json" file
+pub fn read_diesel_type_conversion() -> Result<DieselTypeConversion, anyhow::Error> {
+    read_metamodel_file_extension::<SqlTypeValue>("diesel_type_conversion.json")
+}
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+/// Provide content of "metamodel_file_extension/json_schema_type_conversion.json" file
+pub fn read_json_schema_type_conversion() -> Result<JsonSchemaTypeConversion, anyhow::Error> {
+    read_metamodel_file_extension::<SimpleValue>("json_schema_type_conversion.json")
+}
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+/// Provide content of "metamodel_file_extension/xsd_type_conversion.json" file
+pub fn read_xsd_type_conversion() -> Result<XsdTypeConversion, anyhow::Error> {
+    read_metamodel_file_extension::<SimpleValue>("xsd_type_conversion.json")
+}
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+/// Provide content of "metamodel_file_extension/graphql_type_conversion.json" file
+pub fn read_graphql_type_conversion() -> Result<GraphqlTypeConversion, anyhow::Error> {
+    read_metamodel_file_extension::<SimpleValue>("graphql_type_conversion.json")
+}
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+/// Provide content of "metamodel_file_extension/protobuf_type_conversion.json" file
+pub fn read_protobuf_type_conversion() -> Result<ProtobufTypeConversion, anyhow::Error> {
+    read_metamodel_file_extension::<SimpleValue>("protobuf_type_conversion.json")
+}
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+/// Provide content of "metamodel_file_extension/typescript_type_conversion.json" file
+pub fn read_typescript_type_conversion() -> Result<TypescriptTypeConversion, anyhow::Error> {
+    read_metamodel_file_extension::<SimpleValue>("typescript_type_conversion.json")
+}
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+/// Provide content of "metamodel_file_extension/bpmn_xml_name.json" file
+pub fn read_bpmn_xml_names() -> Result<BpmnXmlNames, anyhow::Error> {
+    read_metamodel_file_extension::<SimpleValue>("bpmn_xml_name.json")
 }
@@ -20,9 +20,29 @@ If not, see <https://www.gnu.org/licenses/>.
 #![warn(missing_docs)]
 
 // Mod section
+pub mod generator_registry;
 pub mod metamodel_file_extension;
+pub mod rust_code;
+pub mod template_set;
+pub mod writing_bpmn_xml;
+pub mod writing_builder;
+pub mod writing_crate;
+pub mod writing_diesel;
+pub mod writing_doc_site;
+pub mod writing_domain_model;
 pub mod writing_entity;
+pub mod writing_execution;
+pub mod writing_graphql;
+pub mod writing_graphviz;
+pub mod writing_json_schema;
 pub mod writing_lib_file;
 pub mod writing_manager;
+pub mod writing_plantuml;
+pub mod writing_protobuf;
+pub mod writing_typescript;
+pub mod writing_xsd;
+pub use generator_registry::*;
 pub use metamodel_file_extension::*;
+pub use rust_code::*;
+pub use template_set::*;
 pub use writing_manager::*;
@@ -0,0 +1,248 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../doc/rust_code.md")]
+
+// Package section
+use crate::output_result_manager::read_folder_files;
+
+// Dependencies section
+use proc_macro2::{Ident, TokenStream};
+use quote::ToTokens;
+use std::ffi::OsStr;
+use std::fmt;
+use std::path::Path;
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Rust type of generated code, checked by the Rust parser
+#[derive(Clone)]
+pub struct RustType {
+    /// Syntax tree of the type
+    content: syn::Type,
+}
+
+impl RustType {
+    /// Parse a Rust type (`std::primitive::u64`, `UnlimitedNatural<usize>`)
+    pub fn parse(text: &str) -> Result<Self, anyhow::Error> {
+        match syn::parse_str::<syn::Type>(text) {
+            Ok(content) => Ok(RustType { content }),
+            Err(e) => Err(anyhow::format_err!("Invalid Rust type \"{text}\" : {e}")),
+        }
+    }
+
+    /// Generic type with one argument (`Option<i64>`, `Vec<FlowNode>`, `Ref<Process>`)
+    pub fn wrap(wrapper: &str, argument: &RustType) -> Result<Self, anyhow::Error> {
+        let wrapper = rust_ident(wrapper)?;
+        Ok(RustType {
+            content: syn::parse_quote!(#wrapper<#argument>),
+        })
+    }
+}
+
+impl fmt::Display for RustType {
+    /// Compact text of the type (`Option<UnlimitedNatural<usize>>`)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", compact_tokens(self.content.to_token_stream()))
+    }
+}
+
+impl fmt::Debug for RustType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RustType({self})")
+    }
+}
+
+impl ToTokens for RustType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.content.to_tokens(tokens)
+    }
+}
+
+/// Text of tokens without the spaces added by the tokenizer (kept between two words, and after ",")
+fn compact_tokens(tokens: TokenStream) -> String {
+    let text = tokens.to_string();
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ' ' {
+            let previous = result.chars().last();
+            if (is_word(previous) && is_word(chars.peek().copied())) || previous == Some(',') {
+                result.push(' ');
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Rust identifier (`flow_node`, raw identifier `r#type`, or raw identifier for a keyword)
+pub fn rust_ident(name: &str) -> Result<Ident, anyhow::Error> {
+    if let Ok(ident) = syn::parse_str::<Ident>(name) {
+        return Ok(ident);
+    }
+    match syn::parse_str::<Ident>(&format!("r#{name}")) {
+        Ok(ident) => Ok(ident),
+        Err(_) => Err(anyhow::format_err!("Invalid Rust identifier \"{name}\"")),
+    }
+}
+
+/// Rust expression from the text of a value (`1_u64`, `-1_i64`, `true`), checked by the Rust parser
+pub fn rust_expr(text: &str) -> Result<TokenStream, anyhow::Error> {
+    match syn::parse_str::<syn::Expr>(text) {
+        Ok(expr) => Ok(expr.to_token_stream()),
+        Err(e) => Err(anyhow::format_err!(
+            "Invalid Rust expression \"{text}\" : {e}"
+        )),
+    }
+}
+
+/// Pretty-print the tokens of a Rust file (error if they aren't a valid Rust file)
+pub fn rust_file_to_string(tokens: TokenStream) -> Result<String, anyhow::Error> {
+    match syn::parse2::<syn::File>(tokens) {
+        Ok(file) => Ok(prettyplease::unparse(&file)),
+        Err(e) => Err(anyhow::format_err!("Invalid generated Rust file : {e}")),
+    }
+}
+
+/// Check the Rust files (".rs") of a folder and of its sub-folders, returning their count (error if a file isn't a valid Rust file)
+pub fn check_rust_files(folder: &Path) -> Result<usize, anyhow::Error> {
+    let mut count = 0;
+    for (path, content) in read_folder_files(folder)? {
+        if path.extension() != Some(OsStr::new("rs")) {
+            continue;
+        }
+        let content = String::from_utf8(content)?;
+        if let Err(e) = syn::parse_file(&content) {
+            return Err(anyhow::format_err!(
+                "Invalid generated Rust file {:?} : {e}",
+                path
+            ));
+        }
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Text of a doc comment attribute ("/// {text}" or "//! {text}"), with the leading space
+pub fn rust_doc(text: &str) -> String {
+    format!(" {text}")
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+    use quote::quote;
+
+    #[test]
+    fn rust_code_01_type() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let content = RustType::parse("UnlimitedNatural<usize>")?;
+            let option = RustType::wrap("Option", &content)?;
+            assert_eq!(option.to_string(), "Option<UnlimitedNatural<usize>>");
+            assert_eq!(
+                RustType::parse("std::primitive::u64")?.to_string(),
+                "std::primitive::u64"
+            );
+            assert_eq!(
+                RustType::parse("(i64, dyn Any)")?.to_string(),
+                "(i64, dyn Any)"
+            );
+            // Missing or malformed type
+            assert!(RustType::parse("").is_err());
+            assert!(RustType::parse("Option<").is_err());
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn rust_code_02_ident_and_file() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            assert_eq!(rust_ident("flow_node")?.to_string(), "flow_node");
+            assert_eq!(rust_ident("type")?.to_string(), "r#type");
+            assert_eq!(rust_ident("r#type")?.to_string(), "r#type");
+            assert!(rust_ident("flow node").is_err());
+            assert_eq!(rust_expr("-1_i64")?.to_string(), "- 1_i64");
+            assert!(rust_expr("1 +").is_err());
+
+            let name = rust_ident("FlowNode")?;
+            let field_type = RustType::wrap("Option", &RustType::parse("i64")?)?;
+            let doc = rust_doc("FIELD : BPMN20-FlowNode-id");
+            let tokens = quote! {
+                pub struct #name {
+                    #[doc = #doc]
+                    pub id: #field_type,
+                }
+            };
+            assert_eq!(
+                rust_file_to_string(tokens)?,
+                "pub struct FlowNode {\n    /// FIELD : BPMN20-FlowNode-id\n    pub id: Option<i64>,\n}\n"
+            );
+            // Tokens which aren't items
+            assert!(rust_file_to_string(quote!(i64)).is_err());
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn rust_code_03_check_rust_files() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            // Only ".rs" files are checked
+            let folder = Path::new("tests/rust_code/rust_code_03_check_rust_files");
+            assert_eq!(check_rust_files(&folder.join("valid"))?, 2);
+            let r = check_rust_files(&folder.join("invalid"));
+            assert!(r.is_err_and(|e| e
+                .to_string()
+                .starts_with("Invalid generated Rust file \"lib.rs\"")));
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+}
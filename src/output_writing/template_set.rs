@@ -0,0 +1,201 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../doc/template_set.md")]
+
+// Package section
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+
+// Dependencies section
+use minijinja::{Environment, UndefinedBehavior};
+use serde::Serialize;
+
+/// Extension of the template files of an override folder
+pub const TEMPLATE_EXTENSION: &str = "jinja";
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Templates of a backend, built-in or overridden by the files of a folder
+pub struct TemplateSet {
+    /// Template environment, with all templates
+    environment: Environment<'static>,
+}
+
+impl TemplateSet {
+    /// Load the built-in templates (name, content), then the "*.jinja" files of the override folder
+    ///
+    /// A file with the name of a built-in template replaces it, other files can be used with "include" or "import"
+    pub fn new(
+        builtin: &[(&'static str, &'static str)],
+        override_folder: Option<&Path>,
+    ) -> Result<Self, anyhow::Error> {
+        let mut environment = Environment::new();
+        environment.set_trim_blocks(true);
+        environment.set_lstrip_blocks(true);
+        environment.set_undefined_behavior(UndefinedBehavior::Strict);
+        environment.add_filter("snake_case", |value: String| value.to_case(Case::Snake));
+        environment.add_filter("pascal_case", |value: String| value.to_case(Case::Pascal));
+
+        for (name, content) in builtin {
+            environment.add_template(name, content)?;
+        }
+
+        if let Some(folder) = override_folder {
+            for path in folder.get_folder_content()? {
+                if path.extension().and_then(|extension| extension.to_str())
+                    != Some(TEMPLATE_EXTENSION)
+                {
+                    continue;
+                }
+                let name = match path.file_name().and_then(|name| name.to_str()) {
+                    Some(name) => name.to_string(),
+                    None => continue,
+                };
+                if builtin
+                    .iter()
+                    .any(|(builtin_name, _)| *builtin_name == name)
+                {
+                    info!("Template \"{name}\" overridden by {path:?}");
+                } else {
+                    info!("Template \"{name}\" added from {path:?}");
+                }
+                environment.add_template_owned(name, path.get_file_content()?)?;
+            }
+        }
+
+        Ok(TemplateSet { environment })
+    }
+
+    /// Render a template with a context object
+    pub fn render<S: Serialize>(&self, name: &str, context: S) -> Result<String, anyhow::Error> {
+        let template = self.environment.get_template(name)?;
+        Ok(template.render(context)?)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+    use crate::output_writing::writing_entity::{
+        LibContext, MemberContext, PackageContext, SEA_ORM_TEMPLATES,
+    };
+
+    #[derive(Serialize)]
+    struct Context {
+        names: Vec<&'static str>,
+    }
+
+    const BUILTIN: [(&str, &str); 1] = [(
+        "list.jinja",
+        "{% for name in names %}\n{{ name | snake_case }}\n{% endfor %}\n",
+    )];
+
+    #[test]
+    fn template_set_01_builtin() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let templates = TemplateSet::new(&BUILTIN, None)?;
+            let context = Context {
+                names: vec!["FlowNode", "SequenceFlow"],
+            };
+            assert_eq!(
+                templates.render("list.jinja", &context)?,
+                "flow_node\nsequence_flow\n"
+            );
+            // Unknown variables are errors
+            let r = templates.render("list.jinja", ());
+            assert!(r.is_err());
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn template_set_02_override() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let folder = Path::new("tests/template_set/template_set_02_override");
+            let templates = TemplateSet::new(&BUILTIN, Some(folder))?;
+            let context = Context {
+                names: vec!["FlowNode", "SequenceFlow"],
+            };
+            assert_eq!(
+                templates.render("list.jinja", &context)?,
+                "- FlowNode\n- SequenceFlow\n"
+            );
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn template_set_03_sea_orm_templates() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let templates = TemplateSet::new(&SEA_ORM_TEMPLATES, None)?;
+            let package = PackageContext {
+                folder_name: String::from("output/"),
+                name: String::from("bpmn_20"),
+                uri: String::from("http://www.omg.org/spec/BPMN/20100524/MODEL-XMI"),
+                members: vec![MemberContext::new(
+                    "Enumeration",
+                    "bpmn_20",
+                    "ProcessType",
+                    "bpmn_20_process_type",
+                    "bpmn_20",
+                )],
+            };
+            assert_eq!(
+                templates.render("entity_package_mod.jinja", &package)?,
+                "//! Sea-ORM entities of the package \"http://www.omg.org/spec/BPMN/20100524/MODEL-XMI\"\n//! Imported from \"output/\"\n\n/// Enumeration : ProcessType\npub mod process_type;\npub use process_type::ProcessType as ProcessType;\n"
+            );
+            let context = LibContext {
+                folder_name: String::from("output/"),
+                packages: vec![package],
+            };
+            assert_eq!(
+                templates.render("entity_mod.jinja", &context)?,
+                "//! Sea-ORM entities (one module by package, one module by member of the packages)\n//! Imported from \"output/\"\n\n/// Package \"http://www.omg.org/spec/BPMN/20100524/MODEL-XMI\"\n#[cfg(feature = \"bpmn_20\")]\npub mod bpmn_20;\n#[cfg(feature = \"bpmn_20\")]\npub use bpmn_20::*;\n"
+            );
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+}
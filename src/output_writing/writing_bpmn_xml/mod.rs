@@ -0,0 +1,805 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_bpmn_xml.md")]
+
+// Mod section
+mod writing_bpmn_xml_for_class;
+mod writing_bpmn_xml_for_datatype;
+mod writing_bpmn_xml_for_enumeration;
+mod writing_bpmn_xml_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::writing_domain_model::DOMAIN_MODEL_FOLDER;
+use crate::output_writing::writing_xsd::XsdNamespace;
+use crate::output_writing::*;
+
+// Dependencies section
+use std::collections::BTreeSet;
+
+/// Sub-folder of the source folder (module of the output crate) used by the BPMN XML writing
+pub const BPMN_XML_FOLDER: &str = "bpmn_xml";
+
+/// Module of the output crate written by the BPMN XML reading and writing (see [`CrateModule`])
+pub const BPMN_XML_CRATE_MODULE: CrateModule = CrateModule {
+    name: BPMN_XML_FOLDER,
+    description: "BPMN 2.0 XML interchange (\".bpmn\" files), on the plain domain model",
+    dependencies: &[("roxmltree", r#""0.20""#)],
+    required_modules: &[DOMAIN_MODEL_FOLDER],
+    prelude_items: &[
+        "read_bpmn_xml",
+        "write_bpmn_xml",
+        "BpmnXmlError",
+        "FromBpmnXml",
+        "ToBpmnXml",
+    ],
+    crate_attributes: &[],
+};
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Place of a property value in the XML element of its owner
+#[derive(Clone, Debug, PartialEq)]
+pub enum BpmnXmlPlacement {
+    /// Attribute, with its name
+    Attribute(String),
+    /// Child elements, with their qualified name ("bpmn:incoming")
+    Element(String),
+    /// Child elements named by the class of each value ("bpmn:task")
+    ClassElement,
+    /// Text of the element
+    Text,
+}
+
+/// Shared content during the writing of the BPMN XML files
+pub struct BpmnXmlContext {
+    /// Packages without namespace tags (key : package id), their classes aren't in BPMN XML files
+    pub external_packages: BTreeSet<String>,
+    /// Namespace prefix of the packages with namespace tags (key : package id)
+    pub prefixes: BTreeMap<String, String>,
+    /// XML names which aren't following the default rules (key : property label)
+    pub bpmn_xml_names: BpmnXmlNames,
+}
+
+impl BpmnXmlContext {
+    /// Check if a class is in a package with namespace tags
+    pub fn is_bpmn_xml_class(&self, class: &CMOFClass) -> bool {
+        !self
+            .external_packages
+            .contains(&class.xmi_id.get_package_id())
+    }
+
+    /// Qualified name of an element of a package ("bpmn:task"), without prefix for packages without namespace tags
+    pub fn get_qualified_name(&self, package_id: &str, name: &str) -> String {
+        match self.prefixes.get(package_id) {
+            Some(prefix) => format!("{}:{}", prefix, name),
+            None => name.to_string(),
+        }
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// BPMN XML backend (reading and writing of BPMN XML files, in "bpmn_xml" module)
+pub struct BpmnXmlGenerator;
+
+impl Generator for BpmnXmlGenerator {
+    fn get_name(&self) -> &str {
+        "bpmn_xml"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_bpmn_xml(sink)
+    }
+
+    fn get_crate_module(&self) -> Option<CrateModule> {
+        Some(BPMN_XML_CRATE_MODULE)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Shared content of the BPMN XML writing : namespace prefixes (nsPrefix tags) and names of "bpmn_xml_name.json"
+    pub fn get_bpmn_xml_context(&self) -> Result<BpmnXmlContext, anyhow::Error> {
+        let mut context = BpmnXmlContext {
+            external_packages: BTreeSet::new(),
+            prefixes: BTreeMap::new(),
+            bpmn_xml_names: read_bpmn_xml_names()?,
+        };
+        for pckg in self.get_package_in_order().values() {
+            let package_id = pckg.get_json().xmi_id.get_package_id();
+            match XsdNamespace::from_tags(pckg) {
+                Some(namespace) => {
+                    context.prefixes.insert(package_id, namespace.prefix);
+                }
+                None => {
+                    warn!(
+                        "No namespace tags for \"{}\", not in BPMN XML files",
+                        pckg.get_label()
+                    );
+                    context.external_packages.insert(package_id);
+                }
+            }
+        }
+        Ok(context)
+    }
+
+    /// Make BPMN XML reading and writing files (one by class, datatype and enumeration), in "bpmn_xml" sub-folder
+    pub fn write_bpmn_xml(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let context = self.get_bpmn_xml_context()?;
+
+        // Namespaces of the packages, from nsURI and nsPrefix tags
+        let mut namespaces = String::new();
+        for pckg in self.get_package_in_order().values() {
+            if let Some(namespace) = XsdNamespace::from_tags(pckg) {
+                namespaces.push_str(&format!(
+                    "    ({:?}, {:?}),\n",
+                    namespace.uri, namespace.prefix
+                ));
+            }
+        }
+
+        // Super classes of all classes, for the check of the references
+        let mut super_classes = String::new();
+        for pckg in self.get_package_in_order().values() {
+            for entity in pckg.get_json().owned_member.values() {
+                if let EnumOwnedMember::Class(content) = entity {
+                    let all_super_class = content.get_all_super_class()?;
+                    if all_super_class.is_empty() || !context.is_bpmn_xml_class(content) {
+                        continue;
+                    }
+                    let names: Vec<String> = all_super_class
+                        .values()
+                        .map(|class| format!("{:?}", class.get_metamodel_name()))
+                        .collect();
+                    super_classes.push_str(&format!(
+                        "        {:?} => &[{}],\n",
+                        content.get_metamodel_name(),
+                        names.join(", ")
+                    ));
+                }
+            }
+        }
+
+        // Head of mod.rs, using template
+        let (_, mut mod_writer) = sink.get_module_file(BPMN_XML_FOLDER, "mod.rs");
+        let _ = writeln!(
+            mod_writer,
+            include_str!("template/bpmn_xml_mod_common.tmpl"),
+            folder_name = self.get_input_folder_name(),
+            namespaces = namespaces,
+            super_classes = super_classes,
+        );
+
+        // Common part of the reading and of the writing, without metamodel content
+        let (_, mut wrt) = sink.get_module_file(BPMN_XML_FOLDER, "reader.rs");
+        let _ = wrt.write_all(include_str!("template/bpmn_xml_reader.rs").as_bytes());
+        let (_, mut wrt) = sink.get_module_file(BPMN_XML_FOLDER, "writer.rs");
+        let _ = wrt.write_all(include_str!("template/bpmn_xml_writer.rs").as_bytes());
+
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating BPMN XML for \"{label}\" : START");
+            let mut objects = Vec::new();
+            for entity in pckg.get_json().owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Class(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(BPMN_XML_FOLDER, entity);
+                        let r = content.write_bpmn_xml(&mut wrt, &context);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::DataType(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(BPMN_XML_FOLDER, entity);
+                        let r = content.write_bpmn_xml(&mut wrt, &context);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::Enumeration(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(BPMN_XML_FOLDER, entity);
+                        let r = content.write_bpmn_xml(&mut wrt);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::Association(_) | EnumOwnedMember::PrimitiveType(_) => {
+                        // Associations are read as fields of their member ends, primitive types as values
+                        continue;
+                    }
+                }
+                objects.push(entity);
+            }
+            // Module of the package (gated by its feature in mod.rs), declaring the modules of its objects
+            if !objects.is_empty() {
+                let package = pckg.get_json();
+                let (_, mut package_writer) =
+                    sink.get_package_module_file(BPMN_XML_FOLDER, &package.lowercase_name);
+                let _ = write!(
+                    package_writer,
+                    include_str!("template/bpmn_xml_package_mod_common.tmpl"),
+                    uri = package.uri,
+                    folder_name = self.get_input_folder_name(),
+                );
+                for entity in objects {
+                    let (_, module_name) = entity.get_entity_module();
+                    let _ = write!(
+                        package_writer,
+                        include_str!("template/bpmn_xml_mod_part_2_object.tmpl"),
+                        model_name = entity.get_model_name(),
+                        module_name = module_name,
+                    );
+                }
+                let _ = writeln!(
+                    mod_writer,
+                    include_str!("template/bpmn_xml_mod_part_1_package.tmpl"),
+                    uri = package.uri,
+                    package_name = package.lowercase_name,
+                );
+            }
+            info!("Generating BPMN XML for \"{label}\" : Finished");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+    use crate::output_result_manager::open_env;
+    use crate::output_writing::writing_crate::CrateGenerator;
+    use std::process::Command;
+
+    /// Camunda Modeler file, read and written by the tests
+    const SAMPLE: &str = "tests/writing_bpmn_xml/writing_bpmn_xml_01_round_trip/sample.bpmn";
+
+    /// XML Schema instance namespace (for "xsi:type")
+    const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+    /// Values of the properties of an element (key : property label), as read with the mapping of the generator
+    type MappedFields = BTreeMap<String, Vec<MappedValue>>;
+
+    /// Value of a property, as read with the mapping of the generator
+    #[derive(Clone, Debug, PartialEq)]
+    enum MappedValue {
+        /// Enumeration, primitive type, or id of a reference
+        Value(String),
+        /// Owned value of a class (label of the concrete class)
+        Class(String, MappedFields),
+        /// Owned value of a datatype
+        DataType(MappedFields),
+    }
+
+    /// XML element during the writing
+    #[derive(Default)]
+    struct MappedElement {
+        name: String,
+        attributes: Vec<(String, String)>,
+        text: Option<String>,
+        children: Vec<MappedElement>,
+        classes: Vec<String>,
+    }
+
+    /// Reading and writing of XML files using the placements and the names of the generator, on the metamodel (without generated code)
+    ///
+    /// Same rules as the generated "bpmn_xml" module : values are kept as text, so the round trip checks the placements and the names of each property
+    struct BpmnXmlMapping {
+        context: BpmnXmlContext,
+        namespaces: Vec<XsdNamespace>,
+        classes: BTreeMap<String, Rc<CMOFClass>>,
+    }
+
+    impl BpmnXmlMapping {
+        fn new(loading_env: &LoadingTracker) -> Result<Self, anyhow::Error> {
+            let mut namespaces = Vec::new();
+            let mut classes = BTreeMap::new();
+            for pckg in loading_env.get_package_in_order().values() {
+                namespaces.extend(XsdNamespace::from_tags(pckg));
+                for entity in pckg.get_json().owned_member.values() {
+                    if let EnumOwnedMember::Class(content) = entity {
+                        classes.insert(content.xmi_id.label()?, content.clone());
+                    }
+                }
+            }
+            Ok(BpmnXmlMapping {
+                context: loading_env.get_bpmn_xml_context()?,
+                namespaces,
+                classes,
+            })
+        }
+
+        /// Read a file, with a root element of the class
+        fn read(&self, content: &str, label: &str) -> Result<MappedValue, anyhow::Error> {
+            let document = roxmltree::Document::parse(content)?;
+            let class = self.classes.get(label).unwrap().clone();
+            self.read_owned(document.root_element(), &EnumCMOF::CMOFClass(class))
+        }
+
+        /// Write a file, with the namespace declarations
+        fn write(&self, value: &MappedValue, label: &str) -> Result<String, anyhow::Error> {
+            let class = self.classes.get(label).unwrap().clone();
+            let mut root = self.write_owned(value, None, &EnumCMOF::CMOFClass(class))?;
+            for namespace in &self.namespaces {
+                let name = format!("xmlns:{}", namespace.prefix);
+                root.attributes.push((name, namespace.uri.clone()));
+            }
+            root.attributes
+                .push((String::from("xmlns:xsi"), String::from(XSI_NAMESPACE)));
+            let mut result = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+            write_element(&root, &mut result);
+            Ok(result)
+        }
+
+        /// Child elements of the model namespaces, with one of the names (ignoring the case of the first letter)
+        fn get_elements<'a, 'input>(
+            &self,
+            node: roxmltree::Node<'a, 'input>,
+            names: &[String],
+        ) -> Vec<roxmltree::Node<'a, 'input>> {
+            node.children()
+                .filter(|child| child.is_element())
+                .filter(|child| {
+                    let namespace = child.tag_name().namespace().unwrap_or_default();
+                    self.namespaces
+                        .iter()
+                        .any(|x| namespace.trim_end_matches("-XMI") == x.uri)
+                })
+                .filter(|child| {
+                    let name = child.tag_name().name();
+                    names.iter().any(|x| {
+                        x[1..] == name[1.min(name.len())..]
+                            && x[..1].eq_ignore_ascii_case(&name[..1.min(name.len())])
+                    })
+                })
+                .collect()
+        }
+
+        /// Texts of the values : attribute (first name, separated by spaces if "is_many"), texts of the child elements, or text of the element
+        fn get_values(
+            &self,
+            node: roxmltree::Node<'_, '_>,
+            names: &[String],
+            is_many: bool,
+            is_text: bool,
+        ) -> Vec<String> {
+            let mut result: Vec<String> = Vec::new();
+            if let Some(value) = names.first().and_then(|name| node.attribute(name.as_str())) {
+                if !is_many {
+                    return vec![value.to_string()];
+                }
+                result.extend(value.split_whitespace().map(String::from));
+            }
+            for child in self.get_elements(node, names) {
+                result.push(child.text().unwrap_or_default().trim().to_string());
+                if !is_many {
+                    return result;
+                }
+            }
+            if is_text && result.is_empty() {
+                let text: String = node
+                    .children()
+                    .filter(|child| child.is_text())
+                    .filter_map(|child| child.text())
+                    .collect();
+                if !text.trim().is_empty() {
+                    result.push(text.trim().to_string());
+                }
+            }
+            result
+        }
+
+        /// Read an owned value (class or datatype) from an element
+        fn read_owned(
+            &self,
+            node: roxmltree::Node<'_, '_>,
+            owned_type: &EnumCMOF,
+        ) -> Result<MappedValue, anyhow::Error> {
+            let mut fields = MappedFields::new();
+            match owned_type {
+                EnumCMOF::CMOFClass(class) => {
+                    let class = self.get_concrete_class(node, class)?;
+                    self.read_class(node, &class, &mut fields)?;
+                    Ok(MappedValue::Class(class.xmi_id.label()?, fields))
+                }
+                EnumCMOF::CMOFDataType(datatype) => {
+                    let properties = datatype.get_owned_attribute_in_order();
+                    let mut hierarchy_names = Vec::new();
+                    for property in &properties {
+                        hierarchy_names.extend(property.get_bpmn_xml_own_names());
+                    }
+                    for property in &properties {
+                        self.read_property(node, property, &hierarchy_names, &mut fields)?;
+                    }
+                    Ok(MappedValue::DataType(fields))
+                }
+                _ => Err(anyhow::format_err!("Unexpected owned type")),
+            }
+        }
+
+        /// Concrete class of an element : "xsi:type" (without prefix and "t") or element name, the class itself if not found
+        fn get_concrete_class(
+            &self,
+            node: roxmltree::Node<'_, '_>,
+            class: &Rc<CMOFClass>,
+        ) -> Result<Rc<CMOFClass>, anyhow::Error> {
+            if !class.has_domain_kind()? {
+                return Ok(class.clone());
+            }
+            let type_name = match node.attribute((XSI_NAMESPACE, "type")) {
+                Some(xsi_type) => {
+                    let name = xsi_type.rsplit(':').next().unwrap_or(xsi_type);
+                    match name.strip_prefix('t') {
+                        Some(rest) if rest.starts_with(|c: char| c.is_ascii_uppercase()) => rest,
+                        _ => name,
+                    }
+                }
+                None => node.tag_name().name(),
+            };
+            for descendant in class.get_all_descendant_class()?.values() {
+                let name = descendant.get_metamodel_name();
+                if !descendant.is_abstract && name[1..] == type_name[1.min(type_name.len())..] {
+                    return Ok(descendant.clone());
+                }
+            }
+            if class.is_abstract {
+                return Err(anyhow::format_err!("Unexpected element \"{type_name}\""));
+            }
+            Ok(class.clone())
+        }
+
+        /// Read the properties of a class, and of its super classes, from the same element
+        fn read_class(
+            &self,
+            node: roxmltree::Node<'_, '_>,
+            class: &CMOFClass,
+            fields: &mut MappedFields,
+        ) -> Result<(), anyhow::Error> {
+            for super_class in class.get_super_class()?.values() {
+                self.read_class(node, &*get_object_as_class(super_class)?, fields)?;
+            }
+            let hierarchy_names = class.get_bpmn_xml_hierarchy_names()?;
+            for property in class.get_owned_attribute_in_order() {
+                self.read_property(node, &property, &hierarchy_names, fields)?;
+            }
+            Ok(())
+        }
+
+        /// Read the values of a property (not derived), using its placement and its names
+        fn read_property(
+            &self,
+            node: roxmltree::Node<'_, '_>,
+            property: &CMOFProperty,
+            hierarchy_names: &[String],
+            fields: &mut MappedFields,
+        ) -> Result<(), anyhow::Error> {
+            if !property.is_bpmn_xml_read()? {
+                return Ok(());
+            }
+            let is_many = property.upper > infinitable::Finite(1);
+            let is_text = property.get_bpmn_xml_placement(&self.context)? == BpmnXmlPlacement::Text;
+            let names = property.get_bpmn_xml_names(&self.context, hierarchy_names)?;
+            let property_type = property.get_type()?.upgrade()?;
+            let mut values = Vec::new();
+            match &property_type {
+                EnumCMOF::CMOFClass(c) if !property.is_composite => {
+                    for value in self.get_values(node, &names, is_many, is_text) {
+                        // IDREF can be written as QName ("tns:Task_1")
+                        let id = if self.context.is_bpmn_xml_class(c) {
+                            value.rsplit(':').next().unwrap_or(&value).trim()
+                        } else {
+                            value.trim()
+                        };
+                        if !id.is_empty() {
+                            values.push(MappedValue::Value(id.to_string()));
+                        }
+                    }
+                }
+                EnumCMOF::CMOFClass(_) | EnumCMOF::CMOFDataType(_) => {
+                    let mut children = self.get_elements(node, &names);
+                    if !is_many {
+                        children.truncate(1);
+                    }
+                    if children.is_empty() && property.lower >= 1 {
+                        return Err(anyhow::format_err!("Missing element {:?}", names));
+                    }
+                    for child in children {
+                        values.push(self.read_owned(child, &property_type)?);
+                    }
+                }
+                _ => {
+                    let mut texts = self.get_values(node, &names, is_many, is_text);
+                    if texts.is_empty() && property.lower >= 1 {
+                        texts.extend(property.default.clone());
+                    }
+                    values.extend(texts.into_iter().map(MappedValue::Value));
+                }
+            }
+            fields.insert(property.xmi_id.label()?, values);
+            Ok(())
+        }
+
+        /// Write an owned value (class or datatype), named by the property (or by the class if None)
+        fn write_owned(
+            &self,
+            value: &MappedValue,
+            name: Option<&str>,
+            owned_type: &EnumCMOF,
+        ) -> Result<MappedElement, anyhow::Error> {
+            let mut element = MappedElement::default();
+            match (value, owned_type) {
+                (MappedValue::Class(label, fields), EnumCMOF::CMOFClass(class)) => {
+                    let concrete = self.classes.get(label).unwrap();
+                    element.name = match name {
+                        Some(name) => name.to_string(),
+                        None => concrete.get_bpmn_xml_element_name(&self.context),
+                    };
+                    // Concrete class of a value named by the property
+                    if name.is_some() && concrete.xmi_id.label()? != class.xmi_id.label()? {
+                        let type_name = format!("t{}", concrete.get_metamodel_name());
+                        let xsi_type = self
+                            .context
+                            .get_qualified_name(&concrete.xmi_id.get_package_id(), &type_name);
+                        element
+                            .attributes
+                            .push((String::from("xsi:type"), xsi_type));
+                    }
+                    self.write_class(&mut element, concrete, fields)?;
+                }
+                (MappedValue::DataType(fields), EnumCMOF::CMOFDataType(datatype)) => {
+                    element.name = match name {
+                        Some(name) => name.to_string(),
+                        None => self.context.get_qualified_name(
+                            &datatype.xmi_id.get_package_id(),
+                            &datatype.get_metamodel_name(),
+                        ),
+                    };
+                    for property in datatype.get_owned_attribute_in_order() {
+                        self.write_property(&mut element, &property, fields)?;
+                    }
+                }
+                _ => return Err(anyhow::format_err!("Unexpected owned value {:?}", value)),
+            }
+            Ok(element)
+        }
+
+        /// Write the properties of a class, after the ones of its super classes (once by class)
+        fn write_class(
+            &self,
+            element: &mut MappedElement,
+            class: &CMOFClass,
+            fields: &MappedFields,
+        ) -> Result<(), anyhow::Error> {
+            let label = class.xmi_id.label()?;
+            if element.classes.contains(&label) {
+                return Ok(());
+            }
+            element.classes.push(label);
+            for super_class in class.get_super_class()?.values() {
+                self.write_class(element, &*get_object_as_class(super_class)?, fields)?;
+            }
+            for property in class.get_owned_attribute_in_order() {
+                self.write_property(element, &property, fields)?;
+            }
+            Ok(())
+        }
+
+        /// Write the values of a property (not derived), using its placement (not the default value of a mandatory value)
+        fn write_property(
+            &self,
+            element: &mut MappedElement,
+            property: &CMOFProperty,
+            fields: &MappedFields,
+        ) -> Result<(), anyhow::Error> {
+            if property.is_derived || property.is_derived_union {
+                return Ok(());
+            }
+            let mut values = fields
+                .get(&property.xmi_id.label()?)
+                .cloned()
+                .unwrap_or_default();
+            let is_many = property.upper > infinitable::Finite(1);
+            if !is_many
+                && property.lower >= 1
+                && values == Vec::from_iter(property.default.clone().map(MappedValue::Value))
+            {
+                values.clear();
+            }
+            let property_type = property.get_type()?.upgrade()?;
+            let texts: Vec<String> = values
+                .iter()
+                .filter_map(|value| match value {
+                    MappedValue::Value(text) => Some(text.clone()),
+                    _ => None,
+                })
+                .collect();
+            match property.get_bpmn_xml_placement(&self.context)? {
+                BpmnXmlPlacement::Element(name) if texts.is_empty() => {
+                    for value in &values {
+                        let child = self.write_owned(value, Some(&name), &property_type)?;
+                        element.children.push(child);
+                    }
+                }
+                BpmnXmlPlacement::ClassElement => {
+                    for value in &values {
+                        let child = self.write_owned(value, None, &property_type)?;
+                        element.children.push(child);
+                    }
+                }
+                BpmnXmlPlacement::Attribute(name) => {
+                    if !texts.is_empty() {
+                        element.attributes.push((name, texts.join(" ")));
+                    }
+                }
+                BpmnXmlPlacement::Element(name) => {
+                    for text in texts {
+                        element.children.push(MappedElement {
+                            name: name.clone(),
+                            text: Some(text),
+                            ..Default::default()
+                        });
+                    }
+                }
+                BpmnXmlPlacement::Text => element.text = texts.into_iter().next(),
+            }
+            Ok(())
+        }
+    }
+
+    /// Write an element as XML
+    fn write_element(element: &MappedElement, result: &mut String) {
+        let escape = |value: &str| {
+            value
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;")
+        };
+        result.push_str(&format!("<{}", element.name));
+        for (name, value) in &element.attributes {
+            result.push_str(&format!(" {}=\"{}\"", name, escape(value)));
+        }
+        result.push('>');
+        if let Some(text) = &element.text {
+            result.push_str(&escape(text));
+        }
+        for child in &element.children {
+            write_element(child, result);
+        }
+        result.push_str(&format!("</{}>\n", element.name));
+    }
+
+    #[test]
+    #[ignore = "builds the generated crate with cargo (slow, needs its dependencies in the cargo registry)"]
+    fn writing_bpmn_xml_01_round_trip() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let test_folder = "tests/writing_bpmn_xml/writing_bpmn_xml_01_round_trip/";
+            let file_env = open_env(
+                "metamodel_file",
+                &format!("{test_folder}output"),
+                &format!("{test_folder}result"),
+            )?;
+            let mut loading_env = open_loader(file_env)?;
+            loading_env.make_prepare("BPMNDI.json", "_0", "root")?;
+
+            // Output crate with the domain model and the BPMN XML reading and writing only
+            let generators = [DOMAIN_MODEL_FOLDER, BPMN_XML_FOLDER];
+            let mut registry = GeneratorRegistry::with_default_generators(None)?;
+            let crate_generator =
+                CrateGenerator::new("entities", &registry.get_selection(&generators)?)?;
+            registry.register(Box::new(crate_generator))?;
+            let sink = OutputSink::new(&loading_env.get_output_folder(), false);
+            registry.run(&[&generators[..], &["crate"]].concat(), &loading_env, &sink)?;
+
+            // "round_trip.rs" reads "sample.bpmn", writes it, and reads the written file
+            let crate_folder = sink.get_output_folder();
+            let tests_folder = crate_folder.join("tests");
+            tests_folder.create_folder()?;
+            for file_name in ["round_trip.rs", "sample.bpmn"] {
+                Path::new(&format!("{test_folder}{file_name}"))
+                    .copy_file(&tests_folder.join(file_name))?;
+            }
+            let output = Command::new("cargo")
+                .args(["test", "--offline", "--manifest-path"])
+                .arg(crate_folder.join("Cargo.toml"))
+                .env(
+                    "CARGO_TARGET_DIR",
+                    std::env::temp_dir().join("imbriqua_bpmn_xml_round_trip"),
+                )
+                .output()?;
+            assert!(
+                output.status.success(),
+                "{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            );
+
+            Path::new(&format!("{test_folder}output")).purge_folder()?;
+            Path::new(&format!("{test_folder}result")).purge_folder()?;
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn writing_bpmn_xml_02_mapping_round_trip() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let test_folder = "tests/writing_bpmn_xml/writing_bpmn_xml_02_mapping_round_trip/";
+            let file_env = open_env(
+                "metamodel_file",
+                &format!("{test_folder}output"),
+                &format!("{test_folder}result"),
+            )?;
+            let mut loading_env = open_loader(file_env)?;
+            loading_env.make_prepare("BPMNDI.json", "_0", "root")?;
+            let mapping = BpmnXmlMapping::new(&loading_env)?;
+
+            // Reading of the sample
+            let definitions =
+                mapping.read(&Path::new(SAMPLE).get_file_content()?, "BPMN20-Definitions")?;
+            let MappedValue::Class(_, fields) = &definitions else {
+                panic!("Definitions expected");
+            };
+            assert_eq!(
+                fields["BPMN20-Definitions-exporter"],
+                [MappedValue::Value(String::from("Camunda Modeler"))]
+            );
+            assert_eq!(fields["BPMN20-Definitions-diagrams"].len(), 1);
+            let [MappedValue::Class(label, process)] =
+                &fields["BPMN20-Definitions-rootElements"][..]
+            else {
+                panic!("One process expected");
+            };
+            assert_eq!(label, "BPMN20-Process");
+            assert_eq!(
+                process["BPMN20-FlowElementsContainer-flowElements"].len(),
+                9
+            );
+            assert_eq!(process["BPMN20-Process-artifacts"].len(), 2);
+
+            // Writing, and reading of the written file
+            let written = mapping.write(&definitions, "BPMN20-Definitions")?;
+            assert!(written.contains("xsi:type=\"bpmn:tFormalExpression\""));
+            assert_eq!(mapping.read(&written, "BPMN20-Definitions")?, definitions);
+
+            loading_env.close()?;
+            Path::new(&format!("{test_folder}output")).purge_folder()?;
+            Path::new(&format!("{test_folder}result")).purge_folder()?;
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+}
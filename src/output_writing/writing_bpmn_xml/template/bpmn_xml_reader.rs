@@ -0,0 +1,334 @@
+//! Reading of BPMN 2.0 XML interchange files (".bpmn"), common part
+
+use super::*;
+use crate::domain_model::Ref;
+use roxmltree::Node;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// XML Schema instance namespace (for "xsi:type")
+pub const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Error during the reading of a BPMN XML file
+#[derive(Clone, Debug, PartialEq)]
+pub enum BpmnXmlError {
+    /// Not well-formed XML
+    Xml(String),
+    /// Element not expected at this place (or concrete class not found)
+    UnexpectedElement(String),
+    /// Mandatory element not found
+    MissingElement(String),
+    /// Value not convertible to the expected type
+    InvalidValue(String, String),
+    /// Reference to an unknown id
+    UnresolvedReference(String),
+    /// Reference to an element of an unexpected class
+    WrongReference(String, String),
+}
+
+impl fmt::Display for BpmnXmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BpmnXmlError::Xml(message) => write!(f, "XML error : {message}"),
+            BpmnXmlError::UnexpectedElement(name) => write!(f, "Unexpected element \"{name}\""),
+            BpmnXmlError::MissingElement(name) => write!(f, "Missing element \"{name}\""),
+            BpmnXmlError::InvalidValue(value, expected) => {
+                write!(f, "Invalid value \"{value}\", expected {expected}")
+            }
+            BpmnXmlError::UnresolvedReference(id) => write!(f, "Unresolved reference \"{id}\""),
+            BpmnXmlError::WrongReference(id, expected) => {
+                write!(f, "Reference \"{id}\" isn't a {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BpmnXmlError {}
+
+/// Object read from an XML element
+pub trait FromBpmnXml: Sized {
+    /// Read the object from an XML element
+    fn from_bpmn_xml(node: Node<'_, '_>, reader: &mut BpmnXmlReader) -> Result<Self, BpmnXmlError>;
+}
+
+impl<T: FromBpmnXml> FromBpmnXml for Box<T> {
+    fn from_bpmn_xml(node: Node<'_, '_>, reader: &mut BpmnXmlReader) -> Result<Self, BpmnXmlError> {
+        Ok(Box::new(T::from_bpmn_xml(node, reader)?))
+    }
+}
+
+/// Value read from an XML attribute or an XML text
+pub trait FromBpmnXmlValue: Sized {
+    /// Read the value from a string
+    fn from_bpmn_xml_value(value: &str) -> Result<Self, BpmnXmlError>;
+}
+
+impl<T: FromStr> FromBpmnXmlValue for T {
+    fn from_bpmn_xml_value(value: &str) -> Result<Self, BpmnXmlError> {
+        value.trim().parse().map_err(|_| {
+            BpmnXmlError::InvalidValue(value.to_string(), std::any::type_name::<T>().to_string())
+        })
+    }
+}
+
+/// Read a BPMN XML file content (example : `read_bpmn_xml::<Definitions>(&content)`)
+pub fn read_bpmn_xml<T: FromBpmnXml>(content: &str) -> Result<T, BpmnXmlError> {
+    let document =
+        roxmltree::Document::parse(content).map_err(|e| BpmnXmlError::Xml(e.to_string()))?;
+    let mut reader = BpmnXmlReader::default();
+    let result = T::from_bpmn_xml(document.root_element(), &mut reader)?;
+    reader.resolve()?;
+    Ok(result)
+}
+
+/// Check if an XML namespace is the namespace of a package (with or without "-XMI" suffix)
+pub fn is_bpmn_xml_namespace(namespace: Option<&str>) -> bool {
+    match namespace {
+        Some(namespace) => BPMN_XML_NAMESPACES
+            .iter()
+            .any(|(uri, _)| namespace.trim_end_matches("-XMI") == *uri),
+        None => false,
+    }
+}
+
+/// Compare a metamodel name with an XML name, ignoring the case of the first letter ("process" and "Process")
+pub fn is_same_bpmn_xml_name(metamodel_name: &str, xml_name: &str) -> bool {
+    let mut a = metamodel_name.chars();
+    let mut b = xml_name.chars();
+    match (a.next(), b.next()) {
+        (Some(x), Some(y)) => x.eq_ignore_ascii_case(&y) && a.as_str() == b.as_str(),
+        _ => false,
+    }
+}
+
+/// Check if a class (metamodel name) is the expected class, or one of its sub-classes
+pub fn is_bpmn_xml_kind_of(class: &str, expected: &str) -> bool {
+    class == expected || get_bpmn_xml_super_classes(class).contains(&expected)
+}
+
+/// State of the reading : ids of the elements, and references to check
+#[derive(Clone, Debug, Default)]
+pub struct BpmnXmlReader {
+    /// Class (metamodel name) of each element with id
+    pub ids: BTreeMap<String, &'static str>,
+    /// References to check at the end of the reading : (id, expected class)
+    pub references: Vec<(String, &'static str)>,
+}
+
+impl BpmnXmlReader {
+    /// Save the id of an element, with its concrete class
+    pub fn register(&mut self, node: Node<'_, '_>, class: &'static str) {
+        if let Some(id) = node.attribute("id") {
+            self.ids.insert(id.to_string(), class);
+        }
+    }
+
+    /// Name of the concrete class of an element : "xsi:type" (without prefix and "t"), or element name
+    pub fn get_type_name<'a>(&self, node: Node<'a, '_>) -> &'a str {
+        match node.attribute((XSI_NAMESPACE, "type")) {
+            Some(xsi_type) => {
+                let name = xsi_type.rsplit(':').next().unwrap_or(xsi_type);
+                match name.strip_prefix('t') {
+                    Some(rest) if rest.starts_with(|c: char| c.is_ascii_uppercase()) => rest,
+                    _ => name,
+                }
+            }
+            None => node.tag_name().name(),
+        }
+    }
+
+    /// Child elements of the model namespaces, with one of the names
+    pub fn get_elements<'a, 'input>(
+        &self,
+        node: Node<'a, 'input>,
+        names: &[&str],
+    ) -> Vec<Node<'a, 'input>> {
+        node.children()
+            .filter(|child| child.is_element())
+            .filter(|child| is_bpmn_xml_namespace(child.tag_name().namespace()))
+            .filter(|child| {
+                names
+                    .iter()
+                    .any(|name| is_same_bpmn_xml_name(name, child.tag_name().name()))
+            })
+            .collect()
+    }
+
+    /// Single value : attribute (first name), or text of a child element, or text of the element (if "is_text")
+    pub fn get_value(&self, node: Node<'_, '_>, names: &[&str], is_text: bool) -> Option<String> {
+        if let Some(value) = names.first().and_then(|name| node.attribute(*name)) {
+            return Some(value.to_string());
+        }
+        if let Some(child) = self.get_elements(node, names).first() {
+            return Some(child.text().unwrap_or_default().to_string());
+        }
+        if is_text {
+            let text: String = node
+                .children()
+                .filter(|child| child.is_text())
+                .filter_map(|child| child.text())
+                .collect();
+            if !text.trim().is_empty() {
+                return Some(text.trim().to_string());
+            }
+        }
+        None
+    }
+
+    /// Many values : attribute (first name, separated by spaces), and texts of the child elements
+    pub fn get_values(&self, node: Node<'_, '_>, names: &[&str]) -> Vec<String> {
+        let mut result: Vec<String> = names
+            .first()
+            .and_then(|name| node.attribute(*name))
+            .map(|value| value.split_whitespace().map(String::from).collect())
+            .unwrap_or_default();
+        for child in self.get_elements(node, names) {
+            result.push(child.text().unwrap_or_default().trim().to_string());
+        }
+        result
+    }
+
+    /// Mandatory value, using the default value of the metamodel (or of the type) if missing
+    pub fn read_value<T: FromBpmnXmlValue + Default>(
+        &mut self,
+        node: Node<'_, '_>,
+        names: &[&str],
+        is_text: bool,
+        default: Option<&str>,
+    ) -> Result<T, BpmnXmlError> {
+        match self.get_value(node, names, is_text).as_deref().or(default) {
+            Some(value) => T::from_bpmn_xml_value(value),
+            None => Ok(T::default()),
+        }
+    }
+
+    /// Optional value
+    pub fn read_optional_value<T: FromBpmnXmlValue>(
+        &mut self,
+        node: Node<'_, '_>,
+        names: &[&str],
+        is_text: bool,
+    ) -> Result<Option<T>, BpmnXmlError> {
+        self.get_value(node, names, is_text)
+            .map(|value| T::from_bpmn_xml_value(&value))
+            .transpose()
+    }
+
+    /// Many values
+    pub fn read_values<T: FromBpmnXmlValue>(
+        &mut self,
+        node: Node<'_, '_>,
+        names: &[&str],
+    ) -> Result<Vec<T>, BpmnXmlError> {
+        self.get_values(node, names)
+            .iter()
+            .map(|value| T::from_bpmn_xml_value(value))
+            .collect()
+    }
+
+    /// Reference to an element, checked at the end of the reading (not checked for external class)
+    pub fn make_reference<T>(&mut self, value: &str, expected: Option<&'static str>) -> Ref<T> {
+        match expected {
+            Some(expected) => {
+                // IDREF can be written as QName ("tns:Task_1")
+                let id = value.rsplit(':').next().unwrap_or(value).trim();
+                if !id.is_empty() {
+                    self.references.push((id.to_string(), expected));
+                }
+                Ref::new(id)
+            }
+            None => Ref::new(value.trim()),
+        }
+    }
+
+    /// Mandatory reference (empty reference if missing)
+    pub fn read_reference<T>(
+        &mut self,
+        node: Node<'_, '_>,
+        names: &[&str],
+        is_text: bool,
+        expected: Option<&'static str>,
+    ) -> Ref<T> {
+        let value = self.get_value(node, names, is_text).unwrap_or_default();
+        self.make_reference(&value, expected)
+    }
+
+    /// Optional reference
+    pub fn read_optional_reference<T>(
+        &mut self,
+        node: Node<'_, '_>,
+        names: &[&str],
+        is_text: bool,
+        expected: Option<&'static str>,
+    ) -> Option<Ref<T>> {
+        let value = self.get_value(node, names, is_text)?;
+        Some(self.make_reference(&value, expected))
+    }
+
+    /// Many references
+    pub fn read_references<T>(
+        &mut self,
+        node: Node<'_, '_>,
+        names: &[&str],
+        expected: Option<&'static str>,
+    ) -> Vec<Ref<T>> {
+        self.get_values(node, names)
+            .iter()
+            .map(|value| self.make_reference(value, expected))
+            .collect()
+    }
+
+    /// Mandatory child element
+    pub fn read_element<T: FromBpmnXml>(
+        &mut self,
+        node: Node<'_, '_>,
+        names: &[&str],
+    ) -> Result<T, BpmnXmlError> {
+        match self.get_elements(node, names).first() {
+            Some(child) => T::from_bpmn_xml(*child, self),
+            None => Err(BpmnXmlError::MissingElement(names.join(" | "))),
+        }
+    }
+
+    /// Optional child element
+    pub fn read_optional_element<T: FromBpmnXml>(
+        &mut self,
+        node: Node<'_, '_>,
+        names: &[&str],
+    ) -> Result<Option<T>, BpmnXmlError> {
+        match self.get_elements(node, names).first() {
+            Some(child) => Ok(Some(T::from_bpmn_xml(*child, self)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Many child elements
+    pub fn read_elements<T: FromBpmnXml>(
+        &mut self,
+        node: Node<'_, '_>,
+        names: &[&str],
+    ) -> Result<Vec<T>, BpmnXmlError> {
+        self.get_elements(node, names)
+            .into_iter()
+            .map(|child| T::from_bpmn_xml(child, self))
+            .collect()
+    }
+
+    /// Check all the references : known id, and element of the expected class
+    pub fn resolve(&self) -> Result<(), BpmnXmlError> {
+        for (id, expected) in &self.references {
+            match self.ids.get(id) {
+                None => return Err(BpmnXmlError::UnresolvedReference(id.clone())),
+                Some(class) if !is_bpmn_xml_kind_of(class, expected) => {
+                    return Err(BpmnXmlError::WrongReference(
+                        id.clone(),
+                        expected.to_string(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
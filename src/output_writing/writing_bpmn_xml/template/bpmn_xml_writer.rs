@@ -0,0 +1,240 @@
+//! Writing of BPMN 2.0 XML interchange files (".bpmn"), common part
+
+use super::*;
+use crate::domain_model::Ref;
+use std::fmt::Display;
+
+/// Object written as an XML element
+pub trait ToBpmnXml {
+    /// Write the object as an XML element, named by the property (or by the class if None)
+    fn to_bpmn_xml(&self, name: Option<&str>) -> BpmnXmlElement;
+}
+
+impl<T: ToBpmnXml> ToBpmnXml for Box<T> {
+    fn to_bpmn_xml(&self, name: Option<&str>) -> BpmnXmlElement {
+        self.as_ref().to_bpmn_xml(name)
+    }
+}
+
+/// Value written as an XML attribute or an XML text
+pub trait ToBpmnXmlValue {
+    /// Write the value as a string
+    fn to_bpmn_xml_value(&self) -> String;
+
+    /// Check if the value is the value read when missing (default value of the metamodel, or of the type)
+    fn is_bpmn_xml_default(&self, default: Option<&str>) -> bool;
+}
+
+impl<T: Display + FromBpmnXmlValue + Default + PartialEq> ToBpmnXmlValue for T {
+    fn to_bpmn_xml_value(&self) -> String {
+        self.to_string()
+    }
+
+    fn is_bpmn_xml_default(&self, default: Option<&str>) -> bool {
+        match default {
+            Some(default) => T::from_bpmn_xml_value(default).is_ok_and(|value| value == *self),
+            None => *self == T::default(),
+        }
+    }
+}
+
+impl<T> ToBpmnXmlValue for Ref<T> {
+    fn to_bpmn_xml_value(&self) -> String {
+        self.id.clone()
+    }
+
+    fn is_bpmn_xml_default(&self, _default: Option<&str>) -> bool {
+        self.id.is_empty()
+    }
+}
+
+/// Write a BPMN XML file content, with the namespace declarations (example : `write_bpmn_xml(&definitions)`)
+pub fn write_bpmn_xml<T: ToBpmnXml>(value: &T) -> String {
+    let mut root = value.to_bpmn_xml(None);
+    for (uri, prefix) in BPMN_XML_NAMESPACES {
+        root.set_attribute(&format!("xmlns:{prefix}"), uri);
+    }
+    root.set_attribute("xmlns:xsi", XSI_NAMESPACE);
+    let mut result = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    root.write_to(&mut result, 0);
+    result
+}
+
+/// Escape the XML special characters of a text or of an attribute value
+pub fn escape_bpmn_xml(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            '\n' => result.push_str("&#10;"),
+            '\r' => result.push_str("&#13;"),
+            '\t' => result.push_str("&#9;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// XML element during the writing
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BpmnXmlElement {
+    /// Qualified name ("bpmn:task")
+    pub name: String,
+    /// Attributes, in writing order
+    pub attributes: Vec<(String, String)>,
+    /// Text content
+    pub text: Option<String>,
+    /// Child elements, in writing order
+    pub children: Vec<BpmnXmlElement>,
+    /// Classes (metamodel name) already written in the element, for the super classes shared by many sub-classes
+    pub classes: Vec<&'static str>,
+}
+
+impl BpmnXmlElement {
+    /// Empty element
+    pub fn new(name: &str) -> Self {
+        BpmnXmlElement {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Start the writing of the fields of a class, false if the class is already written
+    pub fn start_class(&mut self, class: &'static str) -> bool {
+        if self.classes.contains(&class) {
+            return false;
+        }
+        self.classes.push(class);
+        true
+    }
+
+    /// Set an attribute, replacing the previous value
+    pub fn set_attribute(&mut self, name: &str, value: &str) {
+        match self.attributes.iter_mut().find(|(key, _)| key == name) {
+            Some((_, old_value)) => *old_value = value.to_string(),
+            None => self.attributes.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    /// Set "xsi:type" (concrete class of a value written with the name of the property)
+    pub fn with_xsi_type(mut self, name: Option<&str>, xsi_type: &str) -> Self {
+        if name.is_some() {
+            self.set_attribute("xsi:type", xsi_type);
+        }
+        self
+    }
+
+    /// Mandatory value as attribute (not written if it's the default value)
+    pub fn write_attribute<T: ToBpmnXmlValue>(&mut self, name: &str, value: &T, default: Option<&str>) {
+        if !value.is_bpmn_xml_default(default) {
+            self.set_attribute(name, &value.to_bpmn_xml_value());
+        }
+    }
+
+    /// Optional value as attribute
+    pub fn write_optional_attribute<T: ToBpmnXmlValue>(&mut self, name: &str, value: &Option<T>) {
+        if let Some(value) = value {
+            self.set_attribute(name, &value.to_bpmn_xml_value());
+        }
+    }
+
+    /// Many values as attribute, separated by spaces
+    pub fn write_attributes<T: ToBpmnXmlValue>(&mut self, name: &str, values: &[T]) {
+        if !values.is_empty() {
+            let values: Vec<String> = values.iter().map(|value| value.to_bpmn_xml_value()).collect();
+            self.set_attribute(name, &values.join(" "));
+        }
+    }
+
+    /// Mandatory value as text of a child element (not written if it's the default value)
+    pub fn write_value_element<T: ToBpmnXmlValue>(&mut self, name: &str, value: &T, default: Option<&str>) {
+        if !value.is_bpmn_xml_default(default) {
+            self.push_value_element(name, value);
+        }
+    }
+
+    /// Optional value as text of a child element
+    pub fn write_optional_value_element<T: ToBpmnXmlValue>(&mut self, name: &str, value: &Option<T>) {
+        if let Some(value) = value {
+            self.push_value_element(name, value);
+        }
+    }
+
+    /// Many values as texts of child elements
+    pub fn write_value_elements<T: ToBpmnXmlValue>(&mut self, name: &str, values: &[T]) {
+        for value in values {
+            self.push_value_element(name, value);
+        }
+    }
+
+    /// Add a child element with a value as text
+    fn push_value_element<T: ToBpmnXmlValue>(&mut self, name: &str, value: &T) {
+        let mut child = BpmnXmlElement::new(name);
+        child.text = Some(value.to_bpmn_xml_value());
+        self.children.push(child);
+    }
+
+    /// Mandatory value as text of the element (not written if it's the default value)
+    pub fn write_text<T: ToBpmnXmlValue>(&mut self, value: &T, default: Option<&str>) {
+        if !value.is_bpmn_xml_default(default) {
+            self.text = Some(value.to_bpmn_xml_value());
+        }
+    }
+
+    /// Optional value as text of the element
+    pub fn write_optional_text<T: ToBpmnXmlValue>(&mut self, value: &Option<T>) {
+        if let Some(value) = value {
+            self.text = Some(value.to_bpmn_xml_value());
+        }
+    }
+
+    /// Mandatory owned value as child element (named by the property, or by the class if None)
+    pub fn write_element<T: ToBpmnXml>(&mut self, name: Option<&str>, value: &T) {
+        self.children.push(value.to_bpmn_xml(name));
+    }
+
+    /// Optional owned value as child element
+    pub fn write_optional_element<T: ToBpmnXml>(&mut self, name: Option<&str>, value: &Option<T>) {
+        if let Some(value) = value {
+            self.children.push(value.to_bpmn_xml(name));
+        }
+    }
+
+    /// Many owned values as child elements
+    pub fn write_elements<T: ToBpmnXml>(&mut self, name: Option<&str>, values: &[T]) {
+        for value in values {
+            self.children.push(value.to_bpmn_xml(name));
+        }
+    }
+
+    /// Write the element as indented XML
+    pub fn write_to(&self, result: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        result.push_str(&indent);
+        result.push('<');
+        result.push_str(&self.name);
+        for (name, value) in &self.attributes {
+            result.push_str(&format!(" {}=\"{}\"", name, escape_bpmn_xml(value)));
+        }
+        match (&self.text, self.children.is_empty()) {
+            (None, true) => result.push_str(" />\n"),
+            (Some(text), true) => {
+                result.push_str(&format!(">{}</{}>\n", escape_bpmn_xml(text), self.name));
+            }
+            (text, false) => {
+                result.push_str(">\n");
+                if let Some(text) = text {
+                    result.push_str(&format!("{}  {}\n", indent, escape_bpmn_xml(text)));
+                }
+                for child in &self.children {
+                    child.write_to(result, depth + 1);
+                }
+                result.push_str(&format!("{}</{}>\n", indent, self.name));
+            }
+        }
+    }
+}
@@ -0,0 +1,323 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_bpmn_xml::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::writing_entity::get_entity_module;
+use crate::output_writing::*;
+
+// Dependencies section
+use proc_macro2::TokenStream;
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// Write BPMN XML reading and writing content to output file, from "CMOFClass" object
+    pub fn write_bpmn_xml(
+        &self,
+        wrt: &mut File,
+        context: &BpmnXmlContext,
+    ) -> Result<(), anyhow::Error> {
+        let fields = self.get_bpmn_xml_fields(context)?;
+        let writes = self.get_bpmn_xml_writes(context)?;
+        let element = if writes.is_empty() {
+            rust_ident("_element")?
+        } else {
+            rust_ident("element")?
+        };
+        let (node, reader) = if !self.is_bpmn_xml_read()? {
+            (rust_ident("_node")?, rust_ident("_reader")?)
+        } else {
+            (rust_ident("node")?, rust_ident("reader")?)
+        };
+
+        let header = rust_doc(&format!("class : {}", self.full_name));
+        let read_doc = rust_doc(&format!(
+            "Read the fields of {} (and of its super classes) from an XML element",
+            self.model_name
+        ));
+        let write_doc = rust_doc(&format!(
+            "Write the fields of {} (and of its super classes) in an XML element",
+            self.model_name
+        ));
+        let name = rust_ident(&self.model_name)?;
+        let metamodel_name = self.get_metamodel_name();
+        let element_name = self.get_bpmn_xml_element_name(context);
+        let kind = self.get_bpmn_xml_kind(context)?;
+
+        let tokens = quote! {
+            #![doc = #header]
+            use crate::bpmn_xml::*;
+            use crate::domain_model::*;
+
+            impl #name {
+                #[doc = #read_doc]
+                pub fn read_bpmn_xml(#node: roxmltree::Node<'_, '_>, #reader: &mut BpmnXmlReader) -> Result<Self, BpmnXmlError> {
+                    Ok(#name {
+                        #(#fields)*
+                    })
+                }
+
+                #[doc = #write_doc]
+                pub fn write_bpmn_xml(&self, #element: &mut BpmnXmlElement) {
+                    #(#writes)*
+                }
+            }
+
+            impl FromBpmnXml for #name {
+                fn from_bpmn_xml(node: roxmltree::Node<'_, '_>, reader: &mut BpmnXmlReader) -> Result<Self, BpmnXmlError> {
+                    reader.register(node, #metamodel_name);
+                    Self::read_bpmn_xml(node, reader)
+                }
+            }
+
+            impl ToBpmnXml for #name {
+                fn to_bpmn_xml(&self, name: Option<&str>) -> BpmnXmlElement {
+                    let mut element = BpmnXmlElement::new(name.unwrap_or(#element_name));
+                    self.write_bpmn_xml(&mut element);
+                    element
+                }
+            }
+
+            #kind
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+
+    /// Check if the XML element is used (super class or property to read)
+    fn is_bpmn_xml_read(&self) -> Result<bool, anyhow::Error> {
+        if !self.get_super_class()?.is_empty() {
+            return Ok(true);
+        }
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            if content.is_bpmn_xml_read()? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Names of the properties of the class, of its super class and of its sub-class (can't be element of other type)
+    pub fn get_bpmn_xml_hierarchy_names(&self) -> Result<Vec<String>, anyhow::Error> {
+        let mut classes: Vec<Rc<CMOFClass>> = Vec::new();
+        classes.extend(self.get_all_super_class()?.into_values());
+        classes.extend(self.get_all_descendant_class()?.into_values());
+        let mut result = Vec::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            result.extend(content.get_bpmn_xml_own_names());
+        }
+        for class in classes {
+            for property in class.owned_attribute.values() {
+                let EnumOwnedAttribute::Property(content) = property;
+                result.extend(content.get_bpmn_xml_own_names());
+            }
+        }
+        Ok(result)
+    }
+
+    /// Fields of the reading : super classes (read from the same element), then properties
+    fn get_bpmn_xml_fields(
+        &self,
+        context: &BpmnXmlContext,
+    ) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+
+        // For super class, read from the same element
+        for (_, class) in self.get_super_class()? {
+            let super_class = get_object_as_class(class)?;
+            let field_name = rust_ident(&super_class.super_field_name)?;
+            let model_name = rust_ident(&super_class.model_name)?;
+            result.push(quote!(#field_name: #model_name::read_bpmn_xml(node, reader)?,));
+        }
+
+        // For all property
+        let hierarchy_names = self.get_bpmn_xml_hierarchy_names()?;
+        let (package_name, _) = get_entity_module(&self.package_lowercase_name, &self.table_name);
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            result.push(content.get_bpmn_xml_field(context, &hierarchy_names, &package_name)?);
+        }
+
+        Ok(result)
+    }
+
+    /// Statements of the writing, properties in declaration order of the metamodel
+    ///
+    /// The class is written once, even if many super classes have it as super class
+    fn get_bpmn_xml_writes(
+        &self,
+        context: &BpmnXmlContext,
+    ) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+
+        // For super class, written in the same element
+        for (_, class) in self.get_super_class()? {
+            let super_class = get_object_as_class(class)?;
+            let field_name = rust_ident(&super_class.super_field_name)?;
+            result.push(quote!(self.#field_name.write_bpmn_xml(element);));
+        }
+
+        // For all property
+        let (package_name, _) = get_entity_module(&self.package_lowercase_name, &self.table_name);
+        for property in self.get_owned_attribute_in_order() {
+            result.extend(property.get_bpmn_xml_write(context, &package_name)?);
+        }
+
+        if result.is_empty() {
+            return Ok(result);
+        }
+        let metamodel_name = self.get_metamodel_name();
+        result.insert(
+            0,
+            quote! {
+                if !element.start_class(#metamodel_name) {
+                    return;
+                }
+            },
+        );
+        Ok(result)
+    }
+
+    /// Element name of the class, metamodel name with lowercase first letter ("bpmn:task", but "bpmndi:BPMNShape")
+    pub fn get_bpmn_xml_element_name(&self, context: &BpmnXmlContext) -> String {
+        context.get_qualified_name(
+            &self.xmi_id.get_package_id(),
+            &self.get_bpmn_xml_local_name(),
+        )
+    }
+
+    /// Element name of the class without prefix ("task", but "BPMNShape"), also used by the XSD global elements
+    pub fn get_bpmn_xml_local_name(&self) -> String {
+        let name = self.get_metamodel_name();
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (Some(first), Some(second)) if !second.is_ascii_uppercase() => {
+                first.to_ascii_lowercase().to_string() + &name[first.len_utf8()..]
+            }
+            _ => name.clone(),
+        }
+    }
+
+    /// Reading and writing of the polymorphic "Kind" enum of the class (nothing without sub-class)
+    fn get_bpmn_xml_kind(&self, context: &BpmnXmlContext) -> Result<TokenStream, anyhow::Error> {
+        if !self.has_domain_kind()? {
+            return Ok(quote!());
+        }
+
+        let kind_name = rust_ident(&self.get_domain_type_name()?)?;
+        let model_name = rust_ident(&self.model_name)?;
+        let (package_name, _) = get_entity_module(&self.package_lowercase_name, &self.table_name);
+        let mut variants = Vec::new();
+        for (_, class) in self.get_all_descendant_class()? {
+            if !class.is_abstract {
+                let cfg = get_bpmn_xml_cfg(&class, &package_name);
+                let metamodel_name = class.get_metamodel_name();
+                let variant = rust_ident(&class.model_name)?;
+                variants.push(quote! {
+                    #cfg
+                    if is_same_bpmn_xml_name(#metamodel_name, type_name) {
+                        let value = #variant::from_bpmn_xml(node, reader)?;
+                        return Ok(#kind_name::#variant(Box::new(value)));
+                    }
+                });
+            }
+        }
+
+        // Class itself if no concrete sub-class is matching
+        let fallback = if self.is_abstract {
+            quote!(Err(BpmnXmlError::UnexpectedElement(type_name.to_string())))
+        } else {
+            quote!(Ok(#kind_name::#model_name(Box::new(#model_name::from_bpmn_xml(node, reader)?))))
+        };
+
+        // Concrete class of a value named by the property, as "xsi:type"
+        let mut write_variants = Vec::new();
+        if !self.is_abstract {
+            write_variants.push(quote! {
+                #kind_name::#model_name(ref value) => value.to_bpmn_xml(name),
+            });
+        }
+        for (_, class) in self.get_all_descendant_class()? {
+            if !class.is_abstract {
+                let cfg = get_bpmn_xml_cfg(&class, &package_name);
+                let variant = rust_ident(&class.model_name)?;
+                let type_name = context.get_qualified_name(
+                    &class.xmi_id.get_package_id(),
+                    &format!("t{}", class.get_metamodel_name()),
+                );
+                write_variants.push(quote! {
+                    #cfg
+                    #kind_name::#variant(ref value) => value.to_bpmn_xml(name).with_xsi_type(name, #type_name),
+                });
+            }
+        }
+
+        Ok(quote! {
+            impl FromBpmnXml for #kind_name {
+                fn from_bpmn_xml(node: roxmltree::Node<'_, '_>, reader: &mut BpmnXmlReader) -> Result<Self, BpmnXmlError> {
+                    let type_name = reader.get_type_name(node);
+                    #(#variants)*
+                    #fallback
+                }
+            }
+
+            impl ToBpmnXml for #kind_name {
+                fn to_bpmn_xml(&self, name: Option<&str>) -> BpmnXmlElement {
+                    match *self {
+                        #(#write_variants)*
+                    }
+                }
+            }
+        })
+    }
+
+    /// Metamodel names of the class and of its concrete sub-class (element names of an owned value)
+    pub fn get_bpmn_xml_element_names(&self) -> Result<Vec<String>, anyhow::Error> {
+        let mut result = Vec::new();
+        if !self.is_abstract {
+            result.push(self.get_metamodel_name());
+        }
+        for (_, class) in self.get_all_descendant_class()? {
+            if !class.is_abstract {
+                result.push(class.get_metamodel_name());
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// "#[cfg]" attribute of a sub-class of an other package, used from the package "from_package_name"
+fn get_bpmn_xml_cfg(class: &CMOFClass, from_package_name: &str) -> TokenStream {
+    match class.get_other_package_feature(from_package_name) {
+        Some(feature) => quote!(#[cfg(feature = #feature)]),
+        None => quote!(),
+    }
+}
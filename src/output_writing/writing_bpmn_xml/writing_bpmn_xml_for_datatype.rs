@@ -0,0 +1,105 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_bpmn_xml::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::writing_entity::get_entity_module;
+use crate::output_writing::*;
+
+// Dependencies section
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFDataType {
+    /// Write BPMN XML reading and writing content to output file, from "CMOFDataType" object
+    pub fn write_bpmn_xml(
+        &self,
+        wrt: &mut File,
+        context: &BpmnXmlContext,
+    ) -> Result<(), anyhow::Error> {
+        let mut hierarchy_names = Vec::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            hierarchy_names.extend(content.get_bpmn_xml_own_names());
+        }
+        let (package_name, _) = get_entity_module(&self.package_lowercase_name, &self.table_name);
+        let mut fields = Vec::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            fields.push(content.get_bpmn_xml_field(context, &hierarchy_names, &package_name)?);
+        }
+        let mut is_read = false;
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            is_read |= content.is_bpmn_xml_read()?;
+        }
+        let mut writes = Vec::new();
+        for property in self.get_owned_attribute_in_order() {
+            writes.extend(property.get_bpmn_xml_write(context, &package_name)?);
+        }
+        let element = if writes.is_empty() {
+            quote!(element)
+        } else {
+            quote!(mut element)
+        };
+        let (node, reader) = if is_read {
+            (rust_ident("node")?, rust_ident("reader")?)
+        } else {
+            (rust_ident("_node")?, rust_ident("_reader")?)
+        };
+
+        let header = rust_doc(&format!("datatype : {}", self.full_name));
+        let name = rust_ident(&self.model_name)?;
+        let element_name =
+            context.get_qualified_name(&self.xmi_id.get_package_id(), &self.get_metamodel_name());
+
+        let tokens = quote! {
+            #![doc = #header]
+            use crate::bpmn_xml::*;
+            use crate::domain_model::*;
+
+            impl FromBpmnXml for #name {
+                fn from_bpmn_xml(#node: roxmltree::Node<'_, '_>, #reader: &mut BpmnXmlReader) -> Result<Self, BpmnXmlError> {
+                    Ok(#name {
+                        #(#fields)*
+                    })
+                }
+            }
+
+            impl ToBpmnXml for #name {
+                fn to_bpmn_xml(&self, name: Option<&str>) -> BpmnXmlElement {
+                    let #element = BpmnXmlElement::new(name.unwrap_or(#element_name));
+                    #(#writes)*
+                    element
+                }
+            }
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+}
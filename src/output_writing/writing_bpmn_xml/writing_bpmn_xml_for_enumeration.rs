@@ -0,0 +1,88 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_bpmn_xml::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFEnumeration {
+    /// Write BPMN XML reading and writing content to output file, from "CMOFEnumeration" object
+    ///
+    /// Literals are compared without case ("None" in XML files, "none" in the metamodel), and written with the designation of the metamodel
+    pub fn write_bpmn_xml(&self, wrt: &mut File) -> Result<(), anyhow::Error> {
+        let name = rust_ident(&self.model_name)?;
+        let mut literals = Vec::new();
+        let mut display_literals = Vec::new();
+        for literal in self.get_all_literal()? {
+            let literal_name = rust_ident(&literal.litteral_name)?;
+            let designation = &literal.litteral_designation;
+            let lowercase_designation = literal.litteral_designation.to_ascii_lowercase();
+            display_literals.push(quote!(#name::#literal_name => f.write_str(#designation),));
+            literals.push(quote!(#lowercase_designation => Ok(#name::#literal_name),));
+        }
+
+        let header = rust_doc(&format!("enumeration : {}", self.full_name));
+        let model_name = &self.model_name;
+
+        let tokens = quote! {
+            #![doc = #header]
+            use crate::bpmn_xml::*;
+            use crate::domain_model::*;
+            use std::fmt;
+            use std::str::FromStr;
+
+            impl FromStr for #name {
+                type Err = BpmnXmlError;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    match value.trim().to_ascii_lowercase().as_str() {
+                        #(#literals)*
+                        _ => Err(BpmnXmlError::InvalidValue(
+                            value.to_string(),
+                            String::from(#model_name),
+                        )),
+                    }
+                }
+            }
+
+            impl fmt::Display for #name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    match self {
+                        #(#display_literals)*
+                    }
+                }
+            }
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+}
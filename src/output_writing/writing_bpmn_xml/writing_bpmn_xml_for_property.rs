@@ -0,0 +1,339 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_bpmn_xml::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use proc_macro2::TokenStream;
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Names of the property in XML : metamodel name, and singular for child elements ("flowNodeRefs" --> "flowNodeRef")
+    pub fn get_bpmn_xml_own_names(&self) -> Vec<String> {
+        let name = self.get_metamodel_name();
+        let mut result = vec![name.clone()];
+        if let Some(singular) = name.strip_suffix("ies") {
+            result.push(format!("{}y", singular));
+        } else if let Some(singular) = name.strip_suffix('s') {
+            if !singular.is_empty() {
+                result.push(singular.to_string());
+            }
+        }
+        result
+    }
+
+    /// Place of the property in XML files, from "bpmn_xml_name.json" ("#text" for text, "@name" for attribute), or default
+    ///
+    /// - Class without composition, enumeration and primitive type : attribute, or child elements if "upper > 1"
+    /// - Class with composition : child elements named by class if the type is abstract or if "upper > 1"
+    /// - Other : child elements named by the property (singular if "upper > 1")
+    pub fn get_bpmn_xml_placement(
+        &self,
+        context: &BpmnXmlContext,
+    ) -> Result<BpmnXmlPlacement, anyhow::Error> {
+        let is_many = self.upper > infinitable::Finite(1);
+        let package_id = self.xmi_id.get_package_id();
+        let singular = self
+            .get_bpmn_xml_own_names()
+            .pop()
+            .unwrap_or(self.get_metamodel_name());
+        let property_type = self.get_type()?.upgrade()?;
+
+        if let Some(name) = context.bpmn_xml_names.get(&self.xmi_id.label()?) {
+            if name == "#text" {
+                return Ok(BpmnXmlPlacement::Text);
+            }
+            if let Some(attribute) = name.strip_prefix('@') {
+                return Ok(BpmnXmlPlacement::Attribute(attribute.to_string()));
+            }
+            // Element named by the type are in the namespace of the type ("dc:Bounds")
+            let type_package_id = match &property_type {
+                EnumCMOF::CMOFClass(c) if c.get_metamodel_name() == *name => {
+                    c.xmi_id.get_package_id()
+                }
+                EnumCMOF::CMOFDataType(c) if c.get_metamodel_name() == *name => {
+                    c.xmi_id.get_package_id()
+                }
+                _ => package_id,
+            };
+            return Ok(BpmnXmlPlacement::Element(
+                context.get_qualified_name(&type_package_id, name),
+            ));
+        }
+
+        Ok(match &property_type {
+            EnumCMOF::CMOFClass(c) if self.is_composite => {
+                if c.is_abstract || is_many {
+                    BpmnXmlPlacement::ClassElement
+                } else {
+                    BpmnXmlPlacement::Element(
+                        context.get_qualified_name(&package_id, &self.get_metamodel_name()),
+                    )
+                }
+            }
+            EnumCMOF::CMOFDataType(_) => {
+                BpmnXmlPlacement::Element(context.get_qualified_name(&package_id, &singular))
+            }
+            _ => {
+                if is_many {
+                    BpmnXmlPlacement::Element(context.get_qualified_name(&package_id, &singular))
+                } else {
+                    BpmnXmlPlacement::Attribute(self.get_metamodel_name())
+                }
+            }
+        })
+    }
+
+    /// Names of the property in XML, with the names of the types for owned values ("bounds", "Bounds")
+    ///
+    /// The name of the placement is the first, as attribute name. Names of the type which are also property names in the hierarchy of the owning class are ignored
+    pub fn get_bpmn_xml_names(
+        &self,
+        context: &BpmnXmlContext,
+        hierarchy_names: &[String],
+    ) -> Result<Vec<String>, anyhow::Error> {
+        let mut names = Vec::new();
+        match self.get_bpmn_xml_placement(context)? {
+            BpmnXmlPlacement::Attribute(name) => names.push(name),
+            BpmnXmlPlacement::Element(name) => {
+                let local_name = name.rsplit(':').next().unwrap_or(&name);
+                names.push(local_name.to_string());
+            }
+            _ => {}
+        }
+        let mut other_names = self.get_bpmn_xml_own_names();
+        match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) if self.is_composite => {
+                for type_name in c.get_bpmn_xml_element_names()? {
+                    let is_property_name = hierarchy_names
+                        .iter()
+                        .any(|name| name.eq_ignore_ascii_case(&type_name));
+                    if !is_property_name {
+                        other_names.push(type_name);
+                    }
+                }
+            }
+            EnumCMOF::CMOFDataType(c) => other_names.push(c.get_metamodel_name()),
+            _ => {}
+        };
+        for name in other_names {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        Ok(names)
+    }
+
+    /// Field of a BPMN XML reading, from the property
+    ///
+    /// - Derived property : not in XML files, default value (read anyway if the value have no default)
+    /// - Class without composition : IDREF, as attribute or child element text
+    /// - Class with composition, and datatype : child element
+    /// - Enumeration and primitive type : attribute or child element text
+    ///
+    /// A property typed in an other package than "from_package_name" is read only with the feature of this package
+    pub fn get_bpmn_xml_field(
+        &self,
+        context: &BpmnXmlContext,
+        hierarchy_names: &[String],
+        from_package_name: &str,
+    ) -> Result<TokenStream, anyhow::Error> {
+        let content = if self.is_bpmn_xml_read()? {
+            self.get_bpmn_xml_read_content(context, hierarchy_names)?
+        } else {
+            quote!(Default::default())
+        };
+        let cfg = self.get_bpmn_xml_cfg(from_package_name)?;
+        let field_name = rust_ident(&self.get_domain_field_name()?)?;
+        Ok(quote! {
+            #cfg
+            #field_name: #content,
+        })
+    }
+
+    /// "#[cfg]" attribute of a property typed in an other package, used from the package "from_package_name"
+    fn get_bpmn_xml_cfg(&self, from_package_name: &str) -> Result<TokenStream, anyhow::Error> {
+        Ok(match self.get_other_package_feature(from_package_name)? {
+            Some(feature) => quote!(#[cfg(feature = #feature)]),
+            None => quote!(),
+        })
+    }
+
+    /// Check if the property is read from XML files (not derived, or without default value)
+    pub fn is_bpmn_xml_read(&self) -> Result<bool, anyhow::Error> {
+        Ok(!(self.is_derived || self.is_derived_union) || self.is_domain_mandatory_kind()?)
+    }
+
+    /// Reading call of the property value
+    fn get_bpmn_xml_read_content(
+        &self,
+        context: &BpmnXmlContext,
+        hierarchy_names: &[String],
+    ) -> Result<TokenStream, anyhow::Error> {
+        let is_many = self.upper > infinitable::Finite(1);
+        let is_optional = self.lower == 0;
+        let is_text = self.get_bpmn_xml_placement(context)? == BpmnXmlPlacement::Text;
+        let names = self.get_bpmn_xml_names(context, hierarchy_names)?;
+        let names = quote!(&[#(#names),*]);
+        Ok(match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) if !self.is_composite => {
+                // References to classes outside of the XML files aren't checked
+                let expected = if context.is_bpmn_xml_class(&c) {
+                    let name = c.get_metamodel_name();
+                    quote!(Some(#name))
+                } else {
+                    quote!(None)
+                };
+                if is_many {
+                    quote!(reader.read_references(node, #names, #expected))
+                } else if is_optional {
+                    quote!(reader.read_optional_reference(node, #names, #is_text, #expected))
+                } else {
+                    quote!(reader.read_reference(node, #names, #is_text, #expected))
+                }
+            }
+            EnumCMOF::CMOFClass(_) | EnumCMOF::CMOFDataType(_) => {
+                if is_many {
+                    quote!(reader.read_elements(node, #names)?)
+                } else if is_optional {
+                    quote!(reader.read_optional_element(node, #names)?)
+                } else {
+                    quote!(reader.read_element(node, #names)?)
+                }
+            }
+            EnumCMOF::CMOFEnumeration(_) | EnumCMOF::CMOFPrimitiveType(_) => {
+                if is_many {
+                    quote!(reader.read_values(node, #names)?)
+                } else if is_optional {
+                    quote!(reader.read_optional_value(node, #names, #is_text)?)
+                } else {
+                    let default = self.get_bpmn_xml_default();
+                    quote!(reader.read_value(node, #names, #is_text, #default)?)
+                }
+            }
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        })
+    }
+
+    /// Default value of the metamodel, as Rust code ("Some(\"false\")" or "None")
+    fn get_bpmn_xml_default(&self) -> TokenStream {
+        match &self.default {
+            Some(default) => quote!(Some(#default)),
+            None => quote!(None),
+        }
+    }
+
+    /// Writing call of the property value, in the XML element of the owner (nothing for derived property)
+    ///
+    /// Values equal to the default value aren't written. A property typed in an other package than "from_package_name" is written only with the feature of this package
+    pub fn get_bpmn_xml_write(
+        &self,
+        context: &BpmnXmlContext,
+        from_package_name: &str,
+    ) -> Result<Option<TokenStream>, anyhow::Error> {
+        if self.is_derived || self.is_derived_union {
+            return Ok(None);
+        }
+        let is_many = self.upper > infinitable::Finite(1);
+        let is_optional = self.lower == 0;
+        let field_name = rust_ident(&self.get_domain_field_name()?)?;
+        let field = quote!(&self.#field_name);
+        let default = self.get_bpmn_xml_default();
+        let is_owned = match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(_) => self.is_composite,
+            EnumCMOF::CMOFDataType(_) => true,
+            _ => false,
+        };
+
+        let content = match (self.get_bpmn_xml_placement(context)?, is_owned) {
+            (BpmnXmlPlacement::Element(name), true) => {
+                let name = quote!(Some(#name));
+                if is_many {
+                    quote!(element.write_elements(#name, #field);)
+                } else if is_optional {
+                    quote!(element.write_optional_element(#name, #field);)
+                } else {
+                    quote!(element.write_element(#name, #field);)
+                }
+            }
+            (BpmnXmlPlacement::ClassElement, true) => {
+                if is_many {
+                    quote!(element.write_elements(None, #field);)
+                } else if is_optional {
+                    quote!(element.write_optional_element(None, #field);)
+                } else {
+                    quote!(element.write_element(None, #field);)
+                }
+            }
+            (BpmnXmlPlacement::Attribute(name), false) => {
+                if is_many {
+                    quote!(element.write_attributes(#name, #field);)
+                } else if is_optional {
+                    quote!(element.write_optional_attribute(#name, #field);)
+                } else {
+                    quote!(element.write_attribute(#name, #field, #default);)
+                }
+            }
+            (BpmnXmlPlacement::Element(name), false) => {
+                if is_many {
+                    quote!(element.write_value_elements(#name, #field);)
+                } else if is_optional {
+                    quote!(element.write_optional_value_element(#name, #field);)
+                } else {
+                    quote!(element.write_value_element(#name, #field, #default);)
+                }
+            }
+            (BpmnXmlPlacement::Text, false) if !is_many => {
+                if is_optional {
+                    quote!(element.write_optional_text(#field);)
+                } else {
+                    quote!(element.write_text(#field, #default);)
+                }
+            }
+            (placement, _) => {
+                return Err(anyhow::format_err!(
+                    "Unexpected BPMN XML placement {:?} for \"{}\"",
+                    placement,
+                    self.xmi_id.label()?
+                ));
+            }
+        };
+        let cfg = self.get_bpmn_xml_cfg(from_package_name)?;
+        Ok(Some(quote! {
+            #cfg
+            #content
+        }))
+    }
+}
@@ -0,0 +1,144 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_builder.md")]
+
+// Mod section
+mod writing_builder_for_class;
+mod writing_builder_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::writing_entity::{ENTITY_FOLDER, SEA_ORM_DEPENDENCY};
+use crate::output_writing::*;
+
+// Dependencies section
+
+/// Sub-folder of the source folder (module of the output crate) used by the builders
+pub const BUILDER_FOLDER: &str = "builder";
+
+/// Module of the output crate written by the builders of the entities (see [`CrateModule`])
+pub const BUILDER_CRATE_MODULE: CrateModule = CrateModule {
+    name: BUILDER_FOLDER,
+    description: "Builders of the entities, with the rows of the super classes",
+    dependencies: &[SEA_ORM_DEPENDENCY],
+    required_modules: &[ENTITY_FOLDER],
+    prelude_items: &["*"],
+    crate_attributes: &[],
+};
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Builder backend (one typed builder by concrete class, in "builder" module)
+pub struct BuilderGenerator;
+
+impl Generator for BuilderGenerator {
+    fn get_name(&self) -> &str {
+        "builder"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_builder(sink)
+    }
+
+    fn get_crate_module(&self) -> Option<CrateModule> {
+        Some(BUILDER_CRATE_MODULE)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make builder files (one by concrete class), in "builder" sub-folder
+    pub fn write_builder(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let enumeration_default_values = read_enumeration_default_values()?;
+        let primitive_type_conversion = read_primitive_type_conversion()?;
+
+        // Head of mod.rs, using template
+        let (_, mut mod_writer) = sink.get_module_file(BUILDER_FOLDER, "mod.rs");
+        let _ = writeln!(
+            mod_writer,
+            include_str!("template/builder_mod_common.tmpl"),
+            folder_name = self.get_input_folder_name(),
+        );
+
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating builder for \"{label}\" : START");
+            let mut objects = Vec::new();
+            for entity in pckg.get_json().owned_member.values() {
+                // Datatype rows are made by their own ActiveModel, other members have no rows
+                let EnumOwnedMember::Class(content) = entity else {
+                    continue;
+                };
+                if content.is_abstract {
+                    // Only as super class rows of the concrete classes
+                    continue;
+                }
+                let (_, mut wrt) = sink.get_object_module_file(BUILDER_FOLDER, entity);
+                let r = content.write_builder(
+                    &mut wrt,
+                    &primitive_type_conversion,
+                    &enumeration_default_values,
+                );
+                catch_error_and_log(r, content)?;
+                objects.push(entity);
+            }
+            // Module of the package (gated by its feature in mod.rs), declaring the modules of its objects
+            if !objects.is_empty() {
+                let package = pckg.get_json();
+                let (_, mut package_writer) =
+                    sink.get_package_module_file(BUILDER_FOLDER, &package.lowercase_name);
+                let _ = write!(
+                    package_writer,
+                    include_str!("template/builder_package_mod_common.tmpl"),
+                    uri = package.uri,
+                    folder_name = self.get_input_folder_name(),
+                );
+                for entity in objects {
+                    let (_, module_name) = entity.get_entity_module();
+                    let _ = write!(
+                        package_writer,
+                        include_str!("template/builder_mod_part_2_object.tmpl"),
+                        model_name = entity.get_model_name(),
+                        module_name = module_name,
+                    );
+                }
+                let _ = writeln!(
+                    mod_writer,
+                    include_str!("template/builder_mod_part_1_package.tmpl"),
+                    uri = package.uri,
+                    package_name = package.lowercase_name,
+                );
+            }
+            info!("Generating builder for \"{label}\" : Finished");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,379 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_builder::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+/// Field of a builder, from an entity field of the class or of one of its super classes
+struct BuilderField {
+    /// Label of the property ("BPMN20-FlowElement-name")
+    label: String,
+    /// Name in the builder, prefixed by the class if many classes of the chain have the same field name
+    name: String,
+    /// Field name in the entity
+    field_name: String,
+    /// Type of the value, without Option
+    value_type: RustType,
+    /// Entity field with Option
+    is_optional: bool,
+    /// Initial value, as Rust expression
+    default: Option<TokenStream>,
+}
+
+impl BuilderField {
+    /// Value saved by the setter
+    fn get_setter_value(&self) -> TokenStream {
+        if self.is_optional || self.default.is_none() {
+            quote!(Some(value))
+        } else {
+            quote!(value)
+        }
+    }
+
+    /// Check if the initial value is the default value of the field type (None, false or 0)
+    fn is_type_default(&self) -> bool {
+        match &self.default {
+            None => true,
+            Some(default) => {
+                let default = default.to_string();
+                !self.is_optional && (default == "false" || default.starts_with("0_"))
+            }
+        }
+    }
+
+    /// Type of the builder field (Option if the value can be missing)
+    fn get_field_type(&self) -> Result<RustType, anyhow::Error> {
+        if self.is_optional || self.default.is_none() {
+            RustType::wrap("Option", &self.value_type)
+        } else {
+            Ok(self.value_type.clone())
+        }
+    }
+
+    /// Initial value of the builder field
+    fn get_default_value(&self) -> TokenStream {
+        match (&self.default, self.is_optional) {
+            (Some(default), true) => quote!(Some(#default)),
+            (Some(default), false) => default.clone(),
+            (None, _) => quote!(None),
+        }
+    }
+
+    /// Value of the entity field, from the builder
+    fn get_row_value(&self) -> Result<TokenStream, anyhow::Error> {
+        let name = rust_ident(&self.name)?;
+        let label = &self.label;
+        Ok(if self.is_optional || self.default.is_some() {
+            quote!(self.#name)
+        } else {
+            quote!(self.#name.ok_or(BuilderError::MissingProperty(#label))?)
+        })
+    }
+}
+
+impl CMOFClass {
+    /// Write the builder of a concrete class, with the rows of its super classes
+    pub fn write_builder(
+        &self,
+        wrt: &mut File,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<(), anyhow::Error> {
+        let super_classes = self.get_builder_super_classes()?;
+        let mut chain: Vec<&CMOFClass> = super_classes.iter().map(|class| class.as_ref()).collect();
+        chain.push(self);
+
+        // Entity fields of each class of the chain
+        let mut class_fields: Vec<(&CMOFClass, Vec<BuilderField>)> = Vec::new();
+        for class in &chain {
+            let mut fields = Vec::new();
+            let mut properties = class.get_all_complex_field()?;
+            properties.append(&mut class.get_all_simple_field()?);
+            for property in properties {
+                fields.push(BuilderField {
+                    label: property.xmi_id.label()?,
+                    name: property.get_field_name(),
+                    field_name: property.get_field_name(),
+                    value_type: property.get_builder_value_type(primitive_type_conversion)?,
+                    is_optional: property.lower == 0,
+                    default: property.get_builder_default(
+                        primitive_type_conversion,
+                        enumeration_default_values,
+                    )?,
+                });
+            }
+            class_fields.push((class, fields));
+        }
+
+        // Prefix the names used by many classes of the chain
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for (_, fields) in &class_fields {
+            for field in fields {
+                *counts.entry(field.field_name.clone()).or_default() += 1;
+            }
+        }
+        for (class, fields) in &mut class_fields {
+            for field in fields {
+                if counts.get(&field.field_name) > Some(&1) {
+                    field.name = format!(
+                        "{}_{}",
+                        class.get_builder_row_name(),
+                        field.field_name.trim_start_matches("r#")
+                    );
+                }
+            }
+        }
+
+        let all_fields: Vec<&BuilderField> =
+            class_fields.iter().flat_map(|(_, fields)| fields).collect();
+        let is_derivable = all_fields.iter().all(|field| field.is_type_default());
+
+        let header = rust_doc(&format!("builder : {}", self.full_name));
+        let builder_doc = rust_doc(&format!(
+            "Builder of \"{}\", with the properties of its super classes",
+            self.model_name
+        ));
+        let rows_doc = rust_doc(&format!(
+            "Rows of \"{}\" and of its super classes (one by table), without ids",
+            self.model_name
+        ));
+        let insert_doc = rust_doc(&format!(
+            "Insert the rows (super classes first, with the \"super_*\" foreign keys), returning the id of the \"{}\" row",
+            self.model_name
+        ));
+        let builder_name = rust_ident(&format!("{}Builder", self.model_name))?;
+        let rows_name = rust_ident(&format!("{}Rows", self.model_name))?;
+        let derive = if is_derivable {
+            quote!(#[derive(Clone, Debug, Default, PartialEq)])
+        } else {
+            quote!(#[derive(Clone, Debug, PartialEq)])
+        };
+        let fields = CMOFClass::get_builder_fields(&all_fields)?;
+        let default = if is_derivable {
+            quote!()
+        } else {
+            CMOFClass::get_builder_default(&builder_name, &all_fields)?
+        };
+        let setters = CMOFClass::get_builder_setters(&all_fields)?;
+        let rows = CMOFClass::get_builder_rows(&class_fields)?;
+        let row_fields = CMOFClass::get_builder_row_fields(&chain)?;
+        let mut_self = if chain.len() > 1 {
+            quote!(mut)
+        } else {
+            quote!()
+        };
+        let inserts = CMOFClass::get_builder_inserts(&chain)?;
+
+        let tokens = quote! {
+            #![doc = #header]
+            use crate::builder::*;
+            use crate::entities::*;
+            use sea_orm::entity::prelude::*;
+            use sea_orm::ActiveValue;
+
+            #[doc = #builder_doc]
+            #derive
+            pub struct #builder_name {
+                #(#fields)*
+            }
+
+            #default
+
+            impl #builder_name {
+                #(#setters)*
+
+                /// Check the mandatory properties, and make the rows of the class and of its super classes
+                pub fn build(self) -> Result<#rows_name, BuilderError> {
+                    Ok(#rows_name {
+                        #(#rows)*
+                    })
+                }
+            }
+
+            #[doc = #rows_doc]
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct #rows_name {
+                #(#row_fields)*
+            }
+
+            impl #rows_name {
+                #[doc = #insert_doc]
+                pub async fn insert<C: ConnectionTrait>(#mut_self self, db: &C) -> Result<i64, DbErr> {
+                    #(#inserts)*
+                }
+            }
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+
+    /// All super classes, directly or not, each one after its own super classes
+    fn get_builder_super_classes(&self) -> Result<Vec<Rc<CMOFClass>>, anyhow::Error> {
+        let mut result: Vec<(usize, Rc<CMOFClass>)> = Vec::new();
+        for class in self.get_all_super_class()?.into_values() {
+            result.push((class.get_all_super_class()?.len(), class));
+        }
+        // A super class have less super classes than its sub-classes (stable sort, keep label order)
+        result.sort_by_key(|(depth, _)| *depth);
+        Ok(result.into_iter().map(|(_, class)| class).collect())
+    }
+
+    /// Name of the row of the class, in "${Class}Rows" and in "insert"
+    fn get_builder_row_name(&self) -> String {
+        self.model_name.to_case(Case::Snake)
+    }
+
+    /// Fields of the builder struct
+    fn get_builder_fields(fields: &[&BuilderField]) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+        for field in fields {
+            let doc = rust_doc(&field.label);
+            let name = rust_ident(&field.name)?;
+            let field_type = field.get_field_type()?;
+            result.push(quote! {
+                #[doc = #doc]
+                #name: #field_type,
+            });
+        }
+        Ok(result)
+    }
+
+    /// "Default" implementation of the builder, when the initial values aren't the default values of the field types
+    fn get_builder_default(
+        builder_name: &Ident,
+        fields: &[&BuilderField],
+    ) -> Result<TokenStream, anyhow::Error> {
+        let mut defaults = Vec::new();
+        for field in fields {
+            let name = rust_ident(&field.name)?;
+            let value = field.get_default_value();
+            defaults.push(quote!(#name: #value,));
+        }
+        Ok(quote! {
+            impl Default for #builder_name {
+                fn default() -> Self {
+                    #builder_name {
+                        #(#defaults)*
+                    }
+                }
+            }
+        })
+    }
+
+    /// Setters of the builder ("with_${field}")
+    fn get_builder_setters(fields: &[&BuilderField]) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+        for field in fields {
+            let doc = rust_doc(&format!("Set {}", field.label));
+            let name = rust_ident(&field.name)?;
+            let setter_name = rust_ident(&format!("with_{}", field.name.trim_start_matches("r#")))?;
+            let value_type = &field.value_type;
+            let value = field.get_setter_value();
+            result.push(quote! {
+                #[doc = #doc]
+                pub fn #setter_name(mut self, value: #value_type) -> Self {
+                    self.#name = #value;
+                    self
+                }
+            });
+        }
+        Ok(result)
+    }
+
+    /// Rows made by "build", one by class of the chain
+    fn get_builder_rows(
+        class_fields: &[(&CMOFClass, Vec<BuilderField>)],
+    ) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+        for (class, fields) in class_fields {
+            let row_name = rust_ident(&class.get_builder_row_name())?;
+            let model_name = rust_ident(&format!("{}Model", class.model_name))?;
+            if fields.is_empty() {
+                result.push(quote!(#row_name: #model_name::new(),));
+                continue;
+            }
+            let mut values = Vec::new();
+            for field in fields {
+                let field_name = rust_ident(&field.field_name)?;
+                let value = field.get_row_value()?;
+                values.push(quote!(#field_name: ActiveValue::Set(#value),));
+            }
+            result.push(quote! {
+                #row_name: #model_name {
+                    #(#values)*
+                    ..Default::default()
+                },
+            });
+        }
+        Ok(result)
+    }
+
+    /// Fields of the rows struct, one by class of the chain
+    fn get_builder_row_fields(chain: &[&CMOFClass]) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+        for class in chain {
+            let doc = rust_doc(&format!("Row of \"{}\"", class.model_name));
+            let row_name = rust_ident(&class.get_builder_row_name())?;
+            let model_name = rust_ident(&format!("{}Model", class.model_name))?;
+            result.push(quote! {
+                #[doc = #doc]
+                pub #row_name: #model_name,
+            });
+        }
+        Ok(result)
+    }
+
+    /// Statements of "insert", super classes first
+    fn get_builder_inserts(chain: &[&CMOFClass]) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+        for (index, class) in chain.iter().enumerate() {
+            let row_name = rust_ident(&class.get_builder_row_name())?;
+            for super_class in class.get_super_class()?.values() {
+                let super_class = get_object_as_class(super_class)?;
+                let super_field_name = rust_ident(&super_class.super_field_name)?;
+                let super_id = rust_ident(&format!("{}_id", super_class.get_builder_row_name()))?;
+                result
+                    .push(quote!(self.#row_name.#super_field_name = ActiveValue::Set(#super_id);));
+            }
+            // The class itself is the last one of the chain
+            if index + 1 == chain.len() {
+                result.push(quote!(Ok(self.#row_name.insert(db).await?.id)));
+            } else {
+                let id = rust_ident(&format!("{}_id", class.get_builder_row_name()))?;
+                result.push(quote!(let #id = self.#row_name.insert(db).await?.id;));
+            }
+        }
+        Ok(result)
+    }
+}
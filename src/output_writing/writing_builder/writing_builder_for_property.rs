@@ -0,0 +1,103 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_builder::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use proc_macro2::TokenStream;
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Value type of the builder field (type of the entity field, without Option)
+    pub fn get_builder_value_type(
+        &self,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+    ) -> Result<RustType, anyhow::Error> {
+        self.get_field_value_type(primitive_type_conversion)
+    }
+
+    /// Initial value of the builder field, as Rust expression (None if the value must be set)
+    ///
+    /// - Default value of the metamodel, for enumeration and primitive type
+    /// - Else, default literal of the enumeration for mandatory property (see "enumeration_default_value.json")
+    pub fn get_builder_default(
+        &self,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<Option<TokenStream>, anyhow::Error> {
+        let value_type = self.get_builder_value_type(primitive_type_conversion)?;
+        match (self.get_type()?.upgrade()?, &self.default) {
+            (EnumCMOF::CMOFEnumeration(c), Some(default)) => {
+                match c.get_literal_by_designation(default)? {
+                    Some(literal) => {
+                        let enumeration = rust_ident(&c.model_name)?;
+                        let literal = rust_ident(&literal.litteral_name)?;
+                        Ok(Some(quote!(#enumeration::#literal)))
+                    }
+                    None => Err(anyhow::format_err!(
+                        "No literal \"{}\" in \"{}\" for \"{}\"",
+                        default,
+                        c.model_name,
+                        self.xmi_id.label()?
+                    )),
+                }
+            }
+            (EnumCMOF::CMOFEnumeration(c), None) if self.lower > 0 => {
+                match enumeration_default_values.get(&c.model_name) {
+                    Some(literal) => {
+                        let enumeration = rust_ident(&c.model_name)?;
+                        let literal = rust_ident(literal)?;
+                        Ok(Some(quote!(#enumeration::#literal)))
+                    }
+                    None => Err(anyhow::format_err!(
+                        "No enuneration default value for {}",
+                        &c.model_name
+                    )),
+                }
+            }
+            (EnumCMOF::CMOFPrimitiveType(_), Some(default)) => {
+                let value_type_name = value_type.to_string();
+                match value_type_name.strip_prefix("std::primitive::") {
+                    Some("bool") => Ok(Some(rust_expr(default)?)),
+                    Some(suffix) => Ok(Some(rust_expr(&format!("{}_{}", default, suffix))?)),
+                    None if value_type_name == "std::string::String" => {
+                        Ok(Some(quote!(#value_type::from(#default))))
+                    }
+                    None => Err(anyhow::format_err!(
+                        "No default value conversion for \"{}\" ({})",
+                        self.xmi_id.label()?,
+                        value_type
+                    )),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,476 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_crate.md")]
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use std::collections::BTreeMap;
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Crate files backend (__Cargo.toml__, __src/lib.rs__, __src/prelude.rs__ and __README.md__), for the Rust modules of the selected generators
+pub struct CrateGenerator {
+    /// Name of the output crate
+    crate_name: String,
+    /// Rust modules of the crate, in selection order
+    modules: Vec<CrateModule>,
+}
+
+impl CrateGenerator {
+    /// Crate of the modules written by the generators (error if a module needs a module without generator)
+    pub fn new(crate_name: &str, generators: &[&dyn Generator]) -> Result<Self, anyhow::Error> {
+        let modules: Vec<CrateModule> = generators
+            .iter()
+            .filter_map(|generator| generator.get_crate_module())
+            .collect();
+        for module in &modules {
+            for required_module in module.required_modules {
+                if !modules.iter().any(|m| m.name == *required_module) {
+                    return Err(anyhow::format_err!(
+                        "Module \"{}\" of the output crate needs module \"{}\" (select its generator)",
+                        module.name,
+                        required_module
+                    ));
+                }
+            }
+        }
+        Ok(CrateGenerator {
+            crate_name: crate_name.to_string(),
+            modules,
+        })
+    }
+
+    /// Dependencies of the modules, by crate name (error if two modules use different specifications)
+    pub fn get_dependencies(&self) -> Result<BTreeMap<&str, &str>, anyhow::Error> {
+        let mut result: BTreeMap<&str, &str> = BTreeMap::new();
+        for module in &self.modules {
+            for (name, specification) in module.dependencies {
+                match result.get(name) {
+                    Some(other) if other != specification => {
+                        return Err(anyhow::format_err!(
+                            "Dependency \"{}\" of module \"{}\" ({}) differs from an other module ({})",
+                            name,
+                            module.name,
+                            specification,
+                            other
+                        ));
+                    }
+                    _ => {
+                        result.insert(name, specification);
+                    }
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Write __Cargo.toml__, with one feature by package (enabled by default)
+    fn write_cargo_toml(
+        &self,
+        features: &[PackageFeature],
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        let (_, mut writer) = sink.get_crate_file("Cargo.toml");
+        let dependencies: String = self
+            .get_dependencies()?
+            .iter()
+            .map(|(name, specification)| format!("{} = {}\n", name, specification))
+            .collect();
+        let packages: Vec<&str> = features
+            .iter()
+            .map(|feature| feature.name.as_str())
+            .collect();
+        let default_features: Vec<String> = packages
+            .iter()
+            .map(|package| format!("{:?}", package))
+            .collect();
+        let features: String = features
+            .iter()
+            .map(|feature| feature.get_cargo_line() + "\n")
+            .collect();
+        let _ = write!(
+            writer,
+            include_str!("template/crate_cargo_toml.tmpl"),
+            crate_name = self.crate_name,
+            version = env!("CARGO_PKG_VERSION"),
+            packages = packages.join(", "),
+            dependencies = dependencies,
+            default_features = default_features.join(", "),
+            features = features,
+        );
+        Ok(())
+    }
+
+    /// Write __src/lib.rs__ (module tree) and __src/prelude.rs__
+    fn write_lib_file(
+        &self,
+        packages: &[String],
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        let (_, mut writer) = sink.get_project_lib_file();
+        let mut attributes = String::new();
+        let mut modules = String::new();
+        for module in &self.modules {
+            for attribute in module.crate_attributes {
+                let attribute = format!("#![{}]\n", attribute);
+                if !attributes.contains(&attribute) {
+                    attributes.push_str(&attribute);
+                }
+            }
+            modules.push_str(&format!(
+                include_str!("template/crate_lib_part_module.tmpl"),
+                description = module.description,
+                name = module.name,
+            ));
+        }
+        let _ = write!(
+            writer,
+            include_str!("template/crate_lib.tmpl"),
+            packages = packages.join(", "),
+            folder_name = model.get_input_folder_name(),
+            attributes = attributes,
+            modules = modules,
+        );
+
+        let (_, mut writer) = sink.get_source_file("prelude.rs");
+        let mut items = String::new();
+        for module in &self.modules {
+            for item in module.prelude_items {
+                items.push_str(&format!("pub use crate::{}::{};\n", module.name, item));
+            }
+        }
+        let _ = write!(
+            writer,
+            include_str!("template/crate_prelude.tmpl"),
+            crate_name = self.crate_name,
+            items = items,
+        );
+        Ok(())
+    }
+
+    /// Write __README.md__
+    fn write_readme(
+        &self,
+        packages: &[String],
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        let (_, mut writer) = sink.get_crate_file("README.md");
+        let mut modules = String::new();
+        for module in &self.modules {
+            modules.push_str(&format!(
+                include_str!("template/crate_readme_part_module.tmpl"),
+                name = module.name,
+                description = module.description,
+            ));
+        }
+        let features: Vec<String> = packages
+            .iter()
+            .map(|package| format!("`{}`", package))
+            .collect();
+        let _ = write!(
+            writer,
+            include_str!("template/crate_readme.tmpl"),
+            crate_name = self.crate_name,
+            packages = packages.join(", "),
+            modules = modules,
+            features = features.join(", "),
+        );
+        Ok(())
+    }
+}
+
+/// Feature of a package in the output crate, enabling the features of the packages it imports
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackageFeature {
+    /// Name of the feature (`lowercase_name` of the package, "bpmndi")
+    pub name: String,
+    /// Features of the imported packages (["dc", "di"])
+    pub imported: Vec<String>,
+}
+
+impl PackageFeature {
+    /// Line of the feature in __Cargo.toml__ : bpmndi = ["dc", "di"]
+    pub fn get_cargo_line(&self) -> String {
+        let imported: Vec<String> = self
+            .imported
+            .iter()
+            .map(|package| format!("{:?}", package))
+            .collect();
+        format!("{} = [{}]", self.name, imported.join(", "))
+    }
+}
+
+/// Features of the packages of the model, in package order (from "packageImport" only)
+///
+/// Other referenced packages (as "Extensibility.cmof#Element" in DI, without "packageImport") aren't enabled : the items using them have their own feature
+pub fn get_package_features(model: &LoadingTracker) -> Result<Vec<PackageFeature>, anyhow::Error> {
+    let packages = model.get_package_in_order();
+    let names: BTreeMap<String, String> = packages
+        .values()
+        .map(|package| {
+            (
+                package.get_json().xmi_id.get_package_id(),
+                package.get_json().lowercase_name.clone(),
+            )
+        })
+        .collect();
+    let mut result = Vec::new();
+    for package in packages.values() {
+        let mut imported = Vec::new();
+        for package_import in package.get_json().package_import.values() {
+            let EnumPackageImport::PackageImport(content) = package_import;
+            let EnumImportedPackage::ImportedPackage(link) = &content.imported_package;
+            let package_id = link.href.get_package_id();
+            let name = names.get(&package_id).ok_or(anyhow::format_err!(
+                "Imported package \"{}\" of \"{}\" isn't loaded",
+                package_id,
+                package.get_json().xmi_id.get_package_id()
+            ))?;
+            imported.push(name.clone());
+        }
+        imported.sort();
+        imported.dedup();
+        result.push(PackageFeature {
+            name: package.get_json().lowercase_name.clone(),
+            imported,
+        });
+    }
+    Ok(result)
+}
+
+impl Generator for CrateGenerator {
+    fn get_name(&self) -> &str {
+        "crate"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        let features = get_package_features(model)?;
+        let packages: Vec<String> = features
+            .iter()
+            .map(|feature| feature.name.clone())
+            .collect();
+        debug!("Generating crate \"{}\" : START", self.crate_name);
+        self.write_cargo_toml(&features, sink)?;
+        self.write_lib_file(&packages, model, sink)?;
+        self.write_readme(&packages, sink)?;
+        info!("Generating crate \"{}\" : Finished", self.crate_name);
+        Ok(())
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+    use crate::output_result_manager::open_env;
+    use crate::output_writing::writing_builder::BuilderGenerator;
+    use crate::output_writing::writing_entity::SeaOrmGenerator;
+    use crate::output_writing::writing_graphviz::GraphvizGenerator;
+    use std::process::Command;
+
+    /// Generator writing nothing, with a Rust module
+    struct ModuleGenerator(CrateModule);
+
+    impl Generator for ModuleGenerator {
+        fn get_name(&self) -> &str {
+            self.0.name
+        }
+
+        fn generate(
+            &self,
+            _model: &LoadingTracker,
+            _sink: &dyn WrittingPath,
+        ) -> Result<(), anyhow::Error> {
+            Ok(())
+        }
+
+        fn get_crate_module(&self) -> Option<CrateModule> {
+            Some(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn writing_crate_01_required_modules() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let entities = SeaOrmGenerator::new(None);
+            let builder = BuilderGenerator;
+            let graphviz = GraphvizGenerator;
+
+            // Builder needs entities
+            let r = CrateGenerator::new("output", &[&builder, &graphviz]);
+            assert_eq!(
+                r.err().map(|error| error.to_string()),
+                Some(String::from(
+                    "Module \"builder\" of the output crate needs module \"entities\" (select its generator)"
+                ))
+            );
+
+            // Generators without Rust module are ignored
+            let crate_generator = CrateGenerator::new("output", &[&builder, &graphviz, &entities])?;
+            let names: Vec<&str> = crate_generator.modules.iter().map(|m| m.name).collect();
+            assert_eq!(names, vec!["builder", "entities"]);
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn writing_crate_02_dependencies() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            // Same specification of "sea-orm" in both modules
+            let entities = SeaOrmGenerator::new(None);
+            let builder = BuilderGenerator;
+            let crate_generator = CrateGenerator::new("output", &[&entities, &builder])?;
+            let names: Vec<&str> = crate_generator.get_dependencies()?.into_keys().collect();
+            assert_eq!(names, vec!["sea-orm", "serde_json"]);
+
+            // Other specification of "serde_json"
+            let other = ModuleGenerator(CrateModule {
+                name: "other",
+                description: "Other module",
+                dependencies: &[("serde_json", r#""0.9""#)],
+                required_modules: &[],
+                prelude_items: &[],
+                crate_attributes: &[],
+            });
+            let crate_generator = CrateGenerator::new("output", &[&entities, &other])?;
+            assert!(crate_generator.get_dependencies().is_err());
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn writing_crate_03_package_features() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let feature = PackageFeature {
+                name: String::from("bpmndi"),
+                imported: vec![String::from("dc"), String::from("di")],
+            };
+            assert_eq!(feature.get_cargo_line(), r#"bpmndi = ["dc", "di"]"#);
+
+            let feature = PackageFeature {
+                name: String::from("dc"),
+                imported: Vec::new(),
+            };
+            assert_eq!(feature.get_cargo_line(), "dc = []");
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    #[ignore = "checks the generated crate with cargo for each feature (slow, needs its dependencies in the cargo registry)"]
+    fn writing_crate_04_check_each_feature() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let test_folder = "tests/writing_crate/writing_crate_04_check_each_feature/";
+            let file_env = open_env(
+                "metamodel_file",
+                &format!("{test_folder}output"),
+                &format!("{test_folder}result"),
+            )?;
+            let mut loading_env = open_loader(file_env)?;
+            loading_env.make_prepare("BPMNDI.json", "_0", "root")?;
+
+            // Output crate with all the Rust modules
+            let generators = [
+                "sea_orm",
+                "builder",
+                "domain_model",
+                "execution",
+                "bpmn_xml",
+                "diesel",
+            ];
+            let mut registry = GeneratorRegistry::with_default_generators(None)?;
+            let crate_generator =
+                CrateGenerator::new("entities", &registry.get_selection(&generators)?)?;
+            registry.register(Box::new(crate_generator))?;
+            let sink = OutputSink::new(&loading_env.get_output_folder(), false);
+            registry.run(&[&generators[..], &["crate"]].concat(), &loading_env, &sink)?;
+
+            // Each feature alone, without the default features
+            let manifest_path = sink.get_output_folder().join("Cargo.toml");
+            let target_folder = std::env::temp_dir().join("imbriqua_crate_features");
+            let mut failures = Vec::new();
+            for feature in get_package_features(&loading_env)? {
+                let output = Command::new("cargo")
+                    .args(["check", "--offline", "--no-default-features", "--features"])
+                    .arg(&feature.name)
+                    .arg("--manifest-path")
+                    .arg(&manifest_path)
+                    .env("CARGO_TARGET_DIR", &target_folder)
+                    // Incremental files of the whole crate take tens of GB for each feature
+                    .env("CARGO_INCREMENTAL", "0")
+                    .output()?;
+                if !output.status.success() {
+                    failures.push(format!(
+                        "Feature \"{}\" : {}",
+                        feature.name,
+                        String::from_utf8_lossy(&output.stderr)
+                    ));
+                }
+            }
+
+            loading_env.close()?;
+            std::fs::remove_dir_all(&target_folder)?;
+            // Only this test uses "tests/writing_crate/"
+            std::fs::remove_dir_all("tests/writing_crate")?;
+            assert!(failures.is_empty(), "{}", failures.join("\n"));
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+}
@@ -0,0 +1,383 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_diesel.md")]
+
+// Mod section
+mod writing_diesel_for_association;
+mod writing_diesel_for_class;
+mod writing_diesel_for_datatype;
+mod writing_diesel_for_enumeration;
+mod writing_diesel_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeSet;
+
+/// Sub-folder of the source folder (module of the output crate) used by the Diesel writing
+pub const DIESEL_FOLDER: &str = "diesel";
+
+/// Module of the output crate written by the Diesel schema and models (see [`CrateModule`])
+pub const DIESEL_CRATE_MODULE: CrateModule = CrateModule {
+    name: DIESEL_FOLDER,
+    description: "Diesel schema and models",
+    dependencies: &[(
+        "diesel",
+        r#"{ version = "2.2", features = ["64-column-tables", "postgres_backend", "serde_json"] }"#,
+    )],
+    required_modules: &[],
+    prelude_items: &[],
+    crate_attributes: &[r#"recursion_limit = "1024""#],
+};
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[derive(Clone, Debug)]
+/// Column of a Diesel table, with the matching field of the model struct
+pub struct DieselColumn {
+    /// Comment of the column (xmi label, or super class)
+    pub comment: String,
+    /// Name of the column and of the model field
+    pub column_name: String,
+    /// Real name of the column, if "column_name" is renamed (Rust keyword)
+    pub sql_name: Option<String>,
+    /// Diesel SQL type (with Nullable)
+    pub sql_type: RustType,
+    /// Rust type of the model field (with Option)
+    pub rust_type: RustType,
+    /// Table targeted by the column, if the column is a foreign key
+    pub foreign_table: Option<String>,
+}
+
+impl DieselColumn {
+    /// Primary key column "id"
+    pub fn new_primary_key() -> Result<Self, anyhow::Error> {
+        Ok(DieselColumn {
+            comment: String::from("PRIMARY KEY"),
+            column_name: String::from("id"),
+            sql_name: None,
+            sql_type: RustType::parse("BigInt")?,
+            rust_type: RustType::parse("i64")?,
+            foreign_table: None,
+        })
+    }
+
+    /// Foreign key column, not nullable
+    pub fn new_foreign_key(
+        comment: &str,
+        column_name: &str,
+        foreign_table: &str,
+    ) -> Result<Self, anyhow::Error> {
+        Ok(DieselColumn {
+            comment: String::from(comment),
+            column_name: String::from(column_name),
+            sql_name: None,
+            sql_type: RustType::parse("BigInt")?,
+            rust_type: RustType::parse("i64")?,
+            foreign_table: Some(String::from(foreign_table)),
+        })
+    }
+
+    /// Column for "table!" macro (text : macro bodies aren't laid out by the Rust printer)
+    pub fn format_table_column(&self) -> Result<String, anyhow::Error> {
+        Ok(format!(
+            include_str!("template/diesel_sub_table_column.tmpl"),
+            comment = self.comment,
+            sql_name = match &self.sql_name {
+                Some(name) => format!("        #[sql_name = {:?}]\n", name),
+                None => String::new(),
+            },
+            column_name = rust_ident(&self.column_name)?,
+            sql_type = self.sql_type,
+        ))
+    }
+
+    /// Field for the model struct
+    pub fn get_model_field(&self) -> Result<TokenStream, anyhow::Error> {
+        let doc = rust_doc(&self.comment);
+        let column_name = rust_ident(&self.column_name)?;
+        let rust_type = &self.rust_type;
+        Ok(quote! {
+            #[doc = #doc]
+            pub #column_name: #rust_type,
+        })
+    }
+}
+
+/// Write model structs of a table : "Queryable" with "id", and "Insertable" without "id"
+pub fn write_diesel_model(
+    wrt: &mut File,
+    header: &str,
+    table_name: &str,
+    model_name: &str,
+    columns: &[DieselColumn],
+) -> Result<(), anyhow::Error> {
+    let header = rust_doc(header);
+    let table_name = rust_ident(table_name)?;
+    let doc = rust_doc(model_name);
+    let name = rust_ident(model_name)?;
+    let primary_key = DieselColumn::new_primary_key()?.get_model_field()?;
+    let fields = columns
+        .iter()
+        .map(|x| x.get_model_field())
+        .collect::<Result<Vec<_>, _>>()?;
+    let insertable = if columns.is_empty() {
+        // Nothing to insert, "id" is generated by the database
+        quote!()
+    } else {
+        let doc = rust_doc(&format!("{model_name} (insertion)"));
+        let name = rust_ident(&format!("New{model_name}"))?;
+        quote! {
+            #[doc = #doc]
+            #[derive(Clone, Debug, PartialEq, Insertable)]
+            #[diesel(table_name = #table_name)]
+            pub struct #name {
+                #(#fields)*
+            }
+        }
+    };
+
+    let tokens = quote! {
+        #![doc = #header]
+        use crate::diesel::schema::#table_name;
+        use diesel::prelude::*;
+
+        #[doc = #doc]
+        #[derive(Clone, Debug, PartialEq, Queryable, Selectable, Identifiable)]
+        #[diesel(table_name = #table_name)]
+        pub struct #name {
+            #primary_key
+            #(#fields)*
+        }
+
+        #insertable
+    };
+    let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+    Ok(())
+}
+
+#[derive(Clone, Debug, Default)]
+/// Content of "schema.rs" file, collected from all objects
+pub struct DieselSchema {
+    /// "table!" macro of all tables
+    pub tables: String,
+    /// "joinable!" links : (child table, parent table, foreign key column)
+    pub joinables: Vec<(String, String, String)>,
+    /// All table names, for "allow_tables_to_appear_in_same_query!"
+    pub table_names: Vec<String>,
+}
+
+impl DieselSchema {
+    /// Add a table to the schema
+    pub fn add_table(
+        &mut self,
+        comment: &str,
+        table_name: &str,
+        primary_key: &[&str],
+        columns: &[DieselColumn],
+    ) -> Result<(), anyhow::Error> {
+        let mut content = String::new();
+        for column in columns {
+            content.push_str(&column.format_table_column()?);
+            if let Some(foreign_table) = &column.foreign_table {
+                self.joinables.push((
+                    String::from(table_name),
+                    foreign_table.clone(),
+                    column.column_name.clone(),
+                ));
+            }
+        }
+        let primary_key = primary_key
+            .iter()
+            .map(|x| Ok(rust_ident(x)?.to_string()))
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        self.tables.push_str(&format!(
+            include_str!("template/diesel_sub_table.tmpl"),
+            comment = comment,
+            table_name = rust_ident(table_name)?,
+            primary_key = primary_key.join(", "),
+            columns = content,
+        ));
+        self.table_names.push(String::from(table_name));
+        Ok(())
+    }
+
+    /// Write "schema.rs" content
+    pub fn write_content(&self, wrt: &mut File) -> Result<(), anyhow::Error> {
+        // Only one "joinable!" by couple of tables (link in both directions), and not for self-referencing
+        let mut done: BTreeSet<(String, String)> = BTreeSet::new();
+        let mut joinables = String::new();
+        for (child, parent, column) in &self.joinables {
+            let couple = if child < parent {
+                (child.clone(), parent.clone())
+            } else {
+                (parent.clone(), child.clone())
+            };
+            if child == parent || !done.insert(couple) {
+                trace!("Skip \"joinable!\" for {child} -> {parent} ({column})");
+                continue;
+            }
+            joinables.push_str(&format!(
+                "diesel::joinable!({child} -> {parent} ({column}));\n"
+            ));
+        }
+
+        let _ = writeln!(
+            wrt,
+            include_str!("template/diesel_main_schema.tmpl"),
+            tables = self.tables,
+            joinables = joinables,
+            table_names = self
+                .table_names
+                .iter()
+                .map(|x| format!("    {},\n", x))
+                .collect::<String>(),
+        );
+        Ok(())
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Diesel backend (schema and one file by member of the packages, in "diesel" module)
+pub struct DieselGenerator;
+
+impl Generator for DieselGenerator {
+    fn get_name(&self) -> &str {
+        "diesel"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_diesel(sink)
+    }
+
+    fn get_crate_module(&self) -> Option<CrateModule> {
+        Some(DIESEL_CRATE_MODULE)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make Diesel files ("schema.rs" and model structs), in "diesel" sub-folder
+    pub fn write_diesel(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let enumeration_default_values = read_enumeration_default_values()?;
+        let diesel_type_conversion = read_diesel_type_conversion()?;
+        let mut schema = DieselSchema::default();
+
+        // Head of mod.rs, using template
+        let (_, mut mod_writer) = sink.get_module_file(DIESEL_FOLDER, "mod.rs");
+        let _ = writeln!(
+            mod_writer,
+            include_str!("template/diesel_mod_common.tmpl"),
+            folder_name = self.get_input_folder_name(),
+        );
+
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating Diesel files for \"{label}\" : START");
+            let mut objects = Vec::new();
+            for entity in pckg.get_json().owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Association(content) => {
+                        // Only for "Many to Many", as join table
+                        if !content.need_diesel_table()? {
+                            continue;
+                        }
+                        let (_, mut wrt) = sink.get_object_module_file(DIESEL_FOLDER, entity);
+                        let r = content.write_diesel(&mut wrt, &mut schema);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::Class(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(DIESEL_FOLDER, entity);
+                        let r =
+                            content.write_diesel(&mut wrt, &mut schema, &diesel_type_conversion);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::DataType(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(DIESEL_FOLDER, entity);
+                        let r =
+                            content.write_diesel(&mut wrt, &mut schema, &diesel_type_conversion);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::Enumeration(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(DIESEL_FOLDER, entity);
+                        let r = content.write_diesel(&mut wrt, &enumeration_default_values);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::PrimitiveType(_) => {
+                        // Primitive types are converted as SQL type
+                        continue;
+                    }
+                }
+                objects.push(entity);
+            }
+            // Module of the package (gated by its feature in mod.rs), declaring the modules of its objects
+            if !objects.is_empty() {
+                let package = pckg.get_json();
+                let (_, mut package_writer) =
+                    sink.get_package_module_file(DIESEL_FOLDER, &package.lowercase_name);
+                let _ = writeln!(
+                    package_writer,
+                    include_str!("template/diesel_package_mod_common.tmpl"),
+                    uri = package.uri,
+                    folder_name = self.get_input_folder_name(),
+                );
+                for entity in objects {
+                    let (_, module_name) = entity.get_entity_module();
+                    let _ = writeln!(
+                        package_writer,
+                        include_str!("template/diesel_mod_part_2_object.tmpl"),
+                        model_name = entity.get_model_name(),
+                        module_name = module_name,
+                    );
+                }
+                let _ = writeln!(
+                    mod_writer,
+                    include_str!("template/diesel_mod_part_1_package.tmpl"),
+                    uri = package.uri,
+                    package_name = package.lowercase_name,
+                );
+            }
+            info!("Generating Diesel files for \"{label}\" : Finished");
+        }
+
+        // Write schema.rs
+        let (_, mut schema_writer) = sink.get_module_file(DIESEL_FOLDER, "schema.rs");
+        schema.write_content(&mut schema_writer)?;
+        Ok(())
+    }
+}
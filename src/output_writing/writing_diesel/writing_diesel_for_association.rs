@@ -0,0 +1,106 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_diesel::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFAssociation {
+    /// Provide information about need of a join table ("Many to Many" only, not self-referencing)
+    pub fn need_diesel_table(&self) -> Result<bool, anyhow::Error> {
+        let relation = self.get_association_relation()?;
+        Ok(matches!(relation, Relation::ManyToManyRelation(_))
+            && !relation.is_self_referencing()?)
+    }
+
+    /// Write Diesel join table content to output file, from "CMOFAssociation" object
+    pub fn write_diesel(
+        &self,
+        wrt: &mut File,
+        schema: &mut DieselSchema,
+    ) -> Result<(), anyhow::Error> {
+        let relation = self.get_association_relation()?;
+        // Same column names as the sea-orm join table
+        let column_a = Self::get_diesel_join_column(&relation.get_to(), "a")?;
+        let column_b = Self::get_diesel_join_column(&relation.get_from(), "b")?;
+        let columns = vec![column_a.clone(), column_b.clone()];
+
+        schema.add_table(
+            &self.xmi_id.label()?,
+            &self.table_name,
+            &[&column_a.column_name, &column_b.column_name],
+            &columns,
+        )?;
+
+        let header = rust_doc(&format!("association : {}", self.full_name));
+        let table_name = rust_ident(&self.table_name)?;
+        let doc = rust_doc(&format!("{} (join table)", self.model_name));
+        let name = rust_ident(&self.model_name)?;
+        let fields = columns
+            .iter()
+            .map(|x| x.get_model_field())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let tokens = quote! {
+            #![doc = #header]
+            use crate::diesel::schema::#table_name;
+            use diesel::prelude::*;
+
+            #[doc = #doc]
+            #[derive(Clone, Debug, PartialEq, Queryable, Selectable, Insertable)]
+            #[diesel(table_name = #table_name)]
+            pub struct #name {
+                #(#fields)*
+            }
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+
+    /// Foreign key column of the join table, for one end of the association
+    fn get_diesel_join_column(
+        property: &CMOFProperty,
+        suffix: &str,
+    ) -> Result<DieselColumn, anyhow::Error> {
+        match property.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => DieselColumn::new_foreign_key(
+                &property.xmi_id.label()?,
+                &format!("{}_{}_id", c.model_name.to_case(Case::Snake), suffix),
+                &c.table_name,
+            ),
+            _ => Err(anyhow::format_err!(
+                "Association end \"{}\" isn't a class",
+                property.xmi_id.label()?
+            )),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_diesel::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFDataType {
+    /// Write Diesel table and model content to output file, from "CMOFDataType" object
+    pub fn write_diesel(
+        &self,
+        wrt: &mut File,
+        schema: &mut DieselSchema,
+        diesel_type_conversion: &DieselTypeConversion,
+    ) -> Result<(), anyhow::Error> {
+        let mut columns: Vec<DieselColumn> = Vec::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            if content.is_diesel_column() {
+                columns.push(content.get_diesel_column(diesel_type_conversion)?);
+            }
+        }
+
+        let mut all_columns = vec![DieselColumn::new_primary_key()?];
+        all_columns.extend(columns.iter().cloned());
+        schema.add_table(
+            &self.xmi_id.label()?,
+            &self.table_name,
+            &["id"],
+            &all_columns,
+        )?;
+
+        write_diesel_model(
+            wrt,
+            &format!("datatype : {}", self.full_name),
+            &self.table_name,
+            &self.model_name,
+            &columns,
+        )
+    }
+}
@@ -0,0 +1,115 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFEnumeration {
+    /// Write Diesel content to output file, from "CMOFEnumeration" object (SQL Text value)
+    pub fn write_diesel(
+        &self,
+        wrt: &mut File,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<(), anyhow::Error> {
+        let mut fields = Vec::new();
+        let mut to_sql = Vec::new();
+        let mut from_sql = Vec::new();
+        let name = rust_ident(&self.model_name)?;
+        for literal in self.get_all_literal()? {
+            let doc = rust_doc(&format!(
+                "ENUMERATION LITERAL : {}",
+                literal.xmi_id.label()?
+            ));
+            let is_default = if literal.is_default(enumeration_default_values)? {
+                quote!(#[default])
+            } else {
+                quote!()
+            };
+            let literal_name = rust_ident(&literal.litteral_name)?;
+            let designation = &literal.litteral_designation;
+            fields.push(quote! {
+                #[doc = #doc]
+                #is_default
+                #literal_name,
+            });
+            to_sql.push(quote!(#name::#literal_name => #designation.to_sql(out),));
+            from_sql.push(quote!(#designation => Ok(#name::#literal_name),));
+        }
+
+        let header = rust_doc(&format!("enumeration : {}", self.full_name));
+        let doc = rust_doc(&format!("{} (stored as Text)", self.model_name));
+        let unknown = format!("Unknown {} value : {{other}}", self.model_name);
+
+        let tokens = quote! {
+            #![doc = #header]
+            use diesel::backend::Backend;
+            use diesel::deserialize::{self, FromSql, FromSqlRow};
+            use diesel::expression::AsExpression;
+            use diesel::serialize::{self, Output, ToSql};
+            use diesel::sql_types::Text;
+
+            #[doc = #doc]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, AsExpression, FromSqlRow)]
+            #[diesel(sql_type = Text)]
+            pub enum #name {
+                #(#fields)*
+            }
+
+            impl<DB> ToSql<Text, DB> for #name
+            where
+                DB: Backend,
+                str: ToSql<Text, DB>,
+            {
+                fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+                    match self {
+                        #(#to_sql)*
+                    }
+                }
+            }
+
+            impl<DB> FromSql<Text, DB> for #name
+            where
+                DB: Backend,
+                String: FromSql<Text, DB>,
+            {
+                fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+                    match String::from_sql(bytes)?.as_str() {
+                        #(#from_sql)*
+                        other => Err(format!(#unknown).into()),
+                    }
+                }
+            }
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+}
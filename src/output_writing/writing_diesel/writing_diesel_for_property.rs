@@ -0,0 +1,116 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_diesel::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Check if the property is stored as a column (single value only, "upper <= 1")
+    pub fn is_diesel_column(&self) -> bool {
+        self.upper <= infinitable::Finite(1)
+    }
+
+    /// Column of the property, for Diesel table and model struct
+    ///
+    /// - Class and DataType as foreign key (BigInt)
+    /// - Enumeration as Text
+    /// - Primitive type using "diesel_type_conversion.json"
+    /// - Nullable if "lower == 0"
+    pub fn get_diesel_column(
+        &self,
+        diesel_type_conversion: &DieselTypeConversion,
+    ) -> Result<DieselColumn, anyhow::Error> {
+        // "id" is the primary key, so "bpmn_id" (as sea-orm entities)
+        let name = self.get_field_name().trim_start_matches("r#").to_string();
+        // Keyword (raw identifier) can't be used as column name in "table!" macro
+        let is_keyword = rust_ident(&name)?.to_string().starts_with("r#");
+        let (column_name, sql_name) = if is_keyword {
+            (format!("{}_", name), Some(name))
+        } else {
+            (name, None)
+        };
+
+        let (sql_type, rust_type, foreign_table) = match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => (
+                RustType::parse("BigInt")?,
+                RustType::parse("i64")?,
+                Some(c.table_name.clone()),
+            ),
+            EnumCMOF::CMOFDataType(c) => (
+                RustType::parse("BigInt")?,
+                RustType::parse("i64")?,
+                Some(c.table_name.clone()),
+            ),
+            EnumCMOF::CMOFEnumeration(c) => (
+                RustType::parse("Text")?,
+                RustType::parse(&format!("crate::diesel::{}", c.model_name))?,
+                None,
+            ),
+            EnumCMOF::CMOFPrimitiveType(c) => {
+                let (sql_type, rust_type) =
+                    diesel_type_conversion
+                        .get(&c.model_name)
+                        .ok_or(anyhow::format_err!(
+                            "No Diesel type conversion for \"{}\"",
+                            c.model_name
+                        ))?;
+                (
+                    RustType::parse(sql_type)?,
+                    RustType::parse(rust_type)?,
+                    None,
+                )
+            }
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        };
+
+        let (sql_type, rust_type) = if self.lower == 0 {
+            (
+                RustType::wrap("Nullable", &sql_type)?,
+                RustType::wrap("Option", &rust_type)?,
+            )
+        } else {
+            (sql_type, rust_type)
+        };
+
+        Ok(DieselColumn {
+            comment: self.xmi_id.label()?,
+            column_name,
+            sql_name,
+            sql_type,
+            rust_type,
+            foreign_table,
+        })
+    }
+}
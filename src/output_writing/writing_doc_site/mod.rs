@@ -0,0 +1,272 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_doc_site.md")]
+
+// Mod section
+mod writing_doc_site_for_class;
+mod writing_doc_site_for_datatype;
+mod writing_doc_site_for_enumeration;
+mod writing_doc_site_for_package;
+mod writing_doc_site_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use serde_json::{json, Value};
+
+/// Sub-folder of the output folder used by the documentation site writing
+pub const DOC_SITE_FOLDER: &str = "doc_site";
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// One end of an association, seen from the class typing "own_end"
+pub struct DocSiteAssociationEnd {
+    /// Association
+    pub association: Rc<CMOFAssociation>,
+    /// End typed by the class of the page
+    pub own_end: Rc<CMOFProperty>,
+    /// Opposite end, reached from the class of the page
+    pub other_end: Rc<CMOFProperty>,
+}
+
+/// Shared content during the writing of the documentation site
+#[derive(Default)]
+pub struct DocSiteContext {
+    /// Page of all packages (key : package id)
+    pub packages: BTreeMap<String, String>,
+    /// Association ends by class (key : xmi label of the class)
+    pub associations: BTreeMap<String, Vec<DocSiteAssociationEnd>>,
+    /// Entries of the search index
+    pub search_index: Vec<Value>,
+}
+
+impl DocSiteContext {
+    /// Page of a package
+    pub fn get_package_page(&self, package_id: &str) -> Result<String, anyhow::Error> {
+        Ok(self
+            .packages
+            .get(package_id)
+            .ok_or(anyhow::format_err!(
+                "No documentation page for \"{}\"",
+                package_id
+            ))?
+            .clone())
+    }
+
+    /// Link to the page of a package
+    pub fn get_package_link(&self, package_id: &str) -> Result<String, anyhow::Error> {
+        Ok(format!(
+            "<a href=\"{}\">{}</a>",
+            self.get_package_page(package_id)?,
+            package_id
+        ))
+    }
+
+    /// Add an entry in the search index
+    pub fn add_search_entry(&mut self, name: &str, kind: &str, package: &str, url: &str) {
+        self.search_index.push(json!({
+            "name": name,
+            "kind": kind,
+            "package": package,
+            "url": url,
+        }));
+    }
+}
+
+/// Escape text for HTML content and attribute values
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// HTML list of constraints (name, language and body)
+pub fn get_doc_site_constraint(
+    owned_rule: &BTreeMap<String, EnumOwnedRule>,
+) -> Result<String, anyhow::Error> {
+    if owned_rule.is_empty() {
+        return Ok(String::from("<p>None</p>\n"));
+    }
+    let mut result = String::new();
+    for rule in owned_rule.values() {
+        let EnumOwnedRule::Constraint(constraint) = rule;
+        let EnumSpecification::OpaqueExpression(specification) = &constraint.specification;
+        result.push_str(&format!(
+            "<h3 id=\"{}\">{} <small>({})</small></h3>\n<pre>{}</pre>\n",
+            escape_html(&constraint.xmi_id.label()?),
+            escape_html(constraint.get_name()),
+            escape_html(&specification.language),
+            escape_html(specification.body.trim()),
+        ));
+    }
+    Ok(result)
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Documentation site backend (one page by package and by member, in "doc_site" folder)
+pub struct DocSiteGenerator;
+
+impl Generator for DocSiteGenerator {
+    fn get_name(&self) -> &str {
+        "doc_site"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_doc_site(sink)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make static HTML documentation site, in "doc_site" sub-folder
+    ///
+    /// - "index.html" : packages, in load order
+    /// - "{package lowercase name}.html" : one page by package, with datatypes and enumerations
+    /// - "{class full name}.html" : one page by class
+    /// - "search_index.js", "search.js", "style.css" : offline search and style
+    pub fn write_doc_site(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let enumeration_default_values = read_enumeration_default_values()?;
+        let mut context = DocSiteContext::default();
+
+        // Page of all packages
+        for pckg in self.get_package_in_order().values() {
+            let package = pckg.get_json();
+            context.packages.insert(
+                package.xmi_id.get_package_id(),
+                format!("{}.html", package.lowercase_name),
+            );
+        }
+
+        // Association ends of each class, for both directions
+        for pckg in self.get_package_in_order().values() {
+            for entity in pckg.get_json().owned_member.values() {
+                if let EnumOwnedMember::Association(association) = entity {
+                    let (end_a, end_b) = association.get_member_end()?;
+                    for (own_end, other_end) in [(&end_a, &end_b), (&end_b, &end_a)] {
+                        context
+                            .associations
+                            .entry(own_end.get_class_type()?.xmi_id.label()?)
+                            .or_default()
+                            .push(DocSiteAssociationEnd {
+                                association: association.clone(),
+                                own_end: own_end.clone(),
+                                other_end: other_end.clone(),
+                            });
+                    }
+                }
+            }
+        }
+
+        let mut packages = String::new();
+        for (order, label) in &self.importing_order {
+            let loaded_packages = self.get_package_in_order();
+            let pckg = loaded_packages
+                .get(label)
+                .ok_or(anyhow::format_err!("Package \"{}\" not loaded", label))?;
+            debug!("Generating documentation site for \"{label}\" : START");
+            let package = pckg.get_json();
+            packages.push_str(&package.get_doc_site_summary(*order));
+
+            // Package page
+            let content =
+                package.get_doc_site_content(&mut context, &enumeration_default_values)?;
+            self.write_doc_site_page(
+                sink,
+                &format!("{}.html", package.lowercase_name),
+                &package.xmi_id.get_package_id(),
+                &content,
+            );
+
+            // Class pages
+            for entity in package.owned_member.values() {
+                if let EnumOwnedMember::Class(class) = entity {
+                    let r = class.get_doc_site_content(&mut context);
+                    let content = catch_error_and_log(r, class)?;
+                    self.write_doc_site_page(
+                        sink,
+                        &format!("{}.html", class.full_name),
+                        &class.model_name,
+                        &content,
+                    );
+                }
+            }
+            info!("Generating documentation site for \"{label}\" : Finished");
+        }
+
+        // Index page
+        self.write_doc_site_page(
+            sink,
+            "index.html",
+            "Metamodel",
+            &format!(
+                include_str!("template/doc_site_main_index.tmpl"),
+                packages = packages
+            ),
+        );
+
+        // Offline search (script instead of JSON file, "fetch" isn't allowed on "file://")
+        let (_, mut wrt) = sink.get_backend_file(DOC_SITE_FOLDER, "search_index.js");
+        let _ = writeln!(
+            wrt,
+            "window.SEARCH_INDEX = {};",
+            serde_json::to_string_pretty(&context.search_index)?
+        );
+        let (_, mut wrt) = sink.get_backend_file(DOC_SITE_FOLDER, "search.js");
+        let _ = wrt.write_all(include_str!("template/doc_site_search.js").as_bytes());
+        let (_, mut wrt) = sink.get_backend_file(DOC_SITE_FOLDER, "style.css");
+        let _ = wrt.write_all(include_str!("template/doc_site_style.css").as_bytes());
+        Ok(())
+    }
+
+    /// Write a HTML page, with navigation and search
+    fn write_doc_site_page(
+        &self,
+        sink: &dyn WrittingPath,
+        file_name: &str,
+        title: &str,
+        content: &str,
+    ) {
+        let (_, mut wrt) = sink.get_backend_file(DOC_SITE_FOLDER, file_name);
+        let _ = write!(
+            wrt,
+            include_str!("template/doc_site_main_page.tmpl"),
+            title = escape_html(title),
+            content = content,
+        );
+    }
+}
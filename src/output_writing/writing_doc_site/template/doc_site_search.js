@@ -0,0 +1,24 @@
+// Offline search, using "window.SEARCH_INDEX" from "search_index.js"
+(function () {
+    const input = document.getElementById("search");
+    const output = document.getElementById("search-result");
+    input.addEventListener("input", function () {
+        const query = input.value.trim().toLowerCase();
+        output.innerHTML = "";
+        if (query.length === 0) {
+            return;
+        }
+        const found = window.SEARCH_INDEX
+            .filter(function (entry) { return entry.name.toLowerCase().includes(query); })
+            .slice(0, 50);
+        for (const entry of found) {
+            const item = document.createElement("li");
+            const link = document.createElement("a");
+            link.href = entry.url;
+            link.textContent = entry.name;
+            item.appendChild(link);
+            item.appendChild(document.createTextNode(" " + entry.kind + " (" + entry.package + ")"));
+            output.appendChild(item);
+        }
+    });
+})();
@@ -0,0 +1,166 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_doc_site::writing_doc_site_for_property::*;
+use crate::output_writing::writing_doc_site::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// Name of the class, with "(abstract)" mark
+    pub fn get_doc_site_name(&self) -> String {
+        if self.is_abstract {
+            format!(
+                "{} <span class=\"abstract\">(abstract)</span>",
+                self.model_name
+            )
+        } else {
+            self.model_name.clone()
+        }
+    }
+
+    /// Link to the page of the class
+    pub fn get_doc_site_link(&self) -> String {
+        format!(
+            "<a href=\"{}.html\">{}</a>",
+            self.full_name, self.model_name
+        )
+    }
+
+    /// Page of the class
+    pub fn get_doc_site_content(
+        &self,
+        context: &mut DocSiteContext,
+    ) -> Result<String, anyhow::Error> {
+        let package_id = self.xmi_id.get_package_id();
+
+        // Own attributes, also in the search index
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            context.add_search_entry(
+                &format!("{}.{}", self.model_name, content.get_metamodel_name()),
+                "attribute",
+                &package_id,
+                &format!("{}.html#{}", self.full_name, content.xmi_id.label()?),
+            );
+        }
+        let attributes = get_doc_site_attribute_table(&self.owned_attribute, context)?;
+
+        let mut super_classes: Vec<String> = Vec::new();
+        for class in self.get_super_class()?.values() {
+            super_classes.push(get_object_as_class(class)?.get_doc_site_link());
+        }
+        let mut sub_classes = self.get_reverse_super_class()?;
+        sub_classes.sort_by(|a, b| a.model_name.cmp(&b.model_name));
+        let sub_classes: Vec<String> = sub_classes.iter().map(|x| x.get_doc_site_link()).collect();
+
+        // Inherited attributes, by super class (directly or not)
+        let mut inherited_attributes = String::new();
+        for class in self.get_all_super_class()?.values() {
+            inherited_attributes.push_str(&format!(
+                "<h3>From {}</h3>\n{}",
+                class.get_doc_site_link(),
+                get_doc_site_attribute_table(&class.owned_attribute, context)?
+            ));
+        }
+        if inherited_attributes.is_empty() {
+            inherited_attributes = String::from("<p>None</p>\n");
+        }
+
+        Ok(format!(
+            include_str!("template/doc_site_main_class.tmpl"),
+            model_name = self.model_name,
+            is_abstract = if self.is_abstract {
+                " <span class=\"abstract\">(abstract)</span>"
+            } else {
+                ""
+            },
+            package_page = context.get_package_page(&package_id)?,
+            package_id = package_id,
+            label = escape_html(&self.xmi_id.label()?),
+            table_name = self.table_name,
+            full_name = self.full_name,
+            super_classes = get_doc_site_list(&super_classes),
+            sub_classes = get_doc_site_list(&sub_classes),
+            attributes = attributes,
+            inherited_attributes = inherited_attributes,
+            associations = self.get_doc_site_association(context)?,
+            constraints = get_doc_site_constraint(&self.owned_rule)?,
+        ))
+    }
+
+    /// Table of the associations of the class, in both directions
+    ///
+    /// Each row is the opposite end, reached from this class
+    fn get_doc_site_association(&self, context: &DocSiteContext) -> Result<String, anyhow::Error> {
+        let ends = match context.associations.get(&self.xmi_id.label()?) {
+            Some(ends) if !ends.is_empty() => ends,
+            _ => return Ok(String::from("<p>None</p>\n")),
+        };
+        let mut result = String::from(
+            "<table>\n<tr><th>Role</th><th>Class</th><th>Multiplicity</th><th>Opposite role</th><th>Kind</th><th>Association</th></tr>\n",
+        );
+        for end in ends {
+            let kind = if end.other_end.is_composite {
+                "composition (whole)"
+            } else if end.own_end.is_composite {
+                "composition (part)"
+            } else {
+                "reference"
+            };
+            result.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{} [{}]</td><td>{}</td><td><code>{}</code></td></tr>\n",
+                escape_html(&end.other_end.get_metamodel_name()),
+                end.other_end.get_class_type()?.get_doc_site_link(),
+                end.other_end.get_multiplicity(),
+                escape_html(&end.own_end.get_metamodel_name()),
+                end.own_end.get_multiplicity(),
+                kind,
+                end.association.model_name,
+            ));
+        }
+        result.push_str("</table>\n");
+        Ok(result)
+    }
+}
+
+/// HTML list of links
+fn get_doc_site_list(links: &[String]) -> String {
+    if links.is_empty() {
+        String::from("<p>None</p>\n")
+    } else {
+        format!(
+            "<ul>\n{}</ul>\n",
+            links
+                .iter()
+                .map(|x| format!("<li>{}</li>\n", x))
+                .collect::<String>()
+        )
+    }
+}
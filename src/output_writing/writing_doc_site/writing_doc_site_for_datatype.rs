@@ -0,0 +1,52 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_doc_site::writing_doc_site_for_property::*;
+use crate::output_writing::writing_doc_site::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFDataType {
+    /// Section of the datatype in the package page, with attributes and constraints
+    pub fn get_doc_site_content(&self, context: &DocSiteContext) -> Result<String, anyhow::Error> {
+        Ok(format!(
+            "<h3 id=\"{}\">{}</h3>\n<p><code>{}</code> - table_name <code>{}</code></p>\n{}{}",
+            self.full_name,
+            self.model_name,
+            escape_html(&self.xmi_id.label()?),
+            self.table_name,
+            get_doc_site_attribute_table(&self.owned_attribute, context)?,
+            if self.owned_rule.is_empty() {
+                String::new()
+            } else {
+                get_doc_site_constraint(&self.owned_rule)?
+            },
+        ))
+    }
+}
@@ -0,0 +1,62 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_doc_site::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::output_writing::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFEnumeration {
+    /// Section of the enumeration in the package page, with literals (default one marked)
+    pub fn get_doc_site_content(
+        &self,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<String, anyhow::Error> {
+        let mut literals = String::new();
+        for literal in self.get_all_literal()? {
+            literals.push_str(&format!(
+                "<li><code>{}</code>{}</li>\n",
+                escape_html(&literal.litteral_designation),
+                if literal.is_default(enumeration_default_values)? {
+                    " (default)"
+                } else {
+                    ""
+                }
+            ));
+        }
+
+        Ok(format!(
+            "<h3 id=\"{}\">{}</h3>\n<p><code>{}</code></p>\n<ul>\n{}</ul>\n",
+            self.full_name,
+            self.model_name,
+            escape_html(&self.xmi_id.label()?),
+            literals
+        ))
+    }
+}
@@ -0,0 +1,138 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_doc_site::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::output_writing::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFPackage {
+    /// Row of the package in the index page
+    pub fn get_doc_site_summary(&self, order: usize) -> String {
+        let (mut classes, mut datatypes, mut enumerations) = (0, 0, 0);
+        for entity in self.owned_member.values() {
+            match entity {
+                EnumOwnedMember::Class(_) => classes += 1,
+                EnumOwnedMember::DataType(_) => datatypes += 1,
+                EnumOwnedMember::Enumeration(_) => enumerations += 1,
+                _ => {}
+            }
+        }
+        format!(
+            "<tr><td>{}</td><td><a href=\"{}.html\">{}</a></td><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            order,
+            self.lowercase_name,
+            self.xmi_id.get_package_id(),
+            escape_html(&self.uri),
+            classes,
+            datatypes,
+            enumerations
+        )
+    }
+
+    /// Package page, listing classes, with datatypes and enumerations in full
+    pub fn get_doc_site_content(
+        &self,
+        context: &mut DocSiteContext,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<String, anyhow::Error> {
+        let package_id = self.xmi_id.get_package_id();
+        let package_page = format!("{}.html", self.lowercase_name);
+        context.add_search_entry(&package_id, "package", &package_id, &package_page);
+
+        let mut imports: Vec<String> = Vec::new();
+        for package_import in self.package_import.values() {
+            let EnumPackageImport::PackageImport(content) = package_import;
+            let EnumImportedPackage::ImportedPackage(link) = &content.imported_package;
+            imports.push(context.get_package_link(&link.href.get_package_id())?);
+        }
+
+        let mut classes = String::new();
+        let mut datatypes = String::new();
+        let mut enumerations = String::new();
+        for entity in self.owned_member.values() {
+            match entity {
+                EnumOwnedMember::Class(content) => {
+                    let url = format!("{}.html", content.full_name);
+                    context.add_search_entry(&content.model_name, "class", &package_id, &url);
+                    classes.push_str(&format!(
+                        "<li><a href=\"{}\">{}</a></li>\n",
+                        url,
+                        content.get_doc_site_name()
+                    ));
+                }
+                EnumOwnedMember::DataType(content) => {
+                    context.add_search_entry(
+                        &content.model_name,
+                        "datatype",
+                        &package_id,
+                        &format!("{}#{}", package_page, content.full_name),
+                    );
+                    datatypes.push_str(&content.get_doc_site_content(context)?);
+                }
+                EnumOwnedMember::Enumeration(content) => {
+                    context.add_search_entry(
+                        &content.model_name,
+                        "enumeration",
+                        &package_id,
+                        &format!("{}#{}", package_page, content.full_name),
+                    );
+                    enumerations
+                        .push_str(&content.get_doc_site_content(enumeration_default_values)?);
+                }
+                _ => {}
+            }
+        }
+
+        let none = |x: String| {
+            if x.is_empty() {
+                String::from("<p>None</p>\n")
+            } else {
+                x
+            }
+        };
+        Ok(format!(
+            include_str!("template/doc_site_main_package.tmpl"),
+            package_id = package_id,
+            uri = escape_html(&self.uri),
+            imports = if imports.is_empty() {
+                String::from("None")
+            } else {
+                imports.join(", ")
+            },
+            classes = if classes.is_empty() {
+                String::from("<p>None</p>\n")
+            } else {
+                format!("<ul>\n{}</ul>\n", classes)
+            },
+            datatypes = none(datatypes),
+            enumerations = none(enumerations),
+        ))
+    }
+}
@@ -0,0 +1,95 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_doc_site::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Row of the property in an attribute table
+    pub fn get_doc_site_row(&self, context: &DocSiteContext) -> Result<String, anyhow::Error> {
+        Ok(format!(
+            "<tr id=\"{}\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            escape_html(&self.xmi_id.label()?),
+            escape_html(&self.get_metamodel_name()),
+            self.get_doc_site_type_link(context)?,
+            self.get_multiplicity(),
+            escape_html(self.default.as_deref().unwrap_or("")),
+            if self.is_composite { "yes" } else { "" },
+        ))
+    }
+
+    /// Type of the property, with link to its page (class) or its section (datatype and enumeration)
+    pub fn get_doc_site_type_link(
+        &self,
+        context: &DocSiteContext,
+    ) -> Result<String, anyhow::Error> {
+        Ok(match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => c.get_doc_site_link(),
+            EnumCMOF::CMOFDataType(c) => format!(
+                "<a href=\"{}#{}\">{}</a>",
+                context.get_package_page(&c.xmi_id.get_package_id())?,
+                c.full_name,
+                c.model_name
+            ),
+            EnumCMOF::CMOFEnumeration(c) => format!(
+                "<a href=\"{}#{}\">{}</a>",
+                context.get_package_page(&c.xmi_id.get_package_id())?,
+                c.full_name,
+                c.model_name
+            ),
+            EnumCMOF::CMOFPrimitiveType(c) => c.model_name.clone(),
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        })
+    }
+}
+
+/// HTML table of properties
+pub fn get_doc_site_attribute_table(
+    owned_attribute: &BTreeMap<String, EnumOwnedAttribute>,
+    context: &DocSiteContext,
+) -> Result<String, anyhow::Error> {
+    if owned_attribute.is_empty() {
+        return Ok(String::from("<p>None</p>\n"));
+    }
+    let mut result = String::from(
+        "<table>\n<tr><th>Name</th><th>Type</th><th>Multiplicity</th><th>Default</th><th>Composite</th></tr>\n",
+    );
+    for property in owned_attribute.values() {
+        let EnumOwnedAttribute::Property(content) = property;
+        result.push_str(&content.get_doc_site_row(context)?);
+    }
+    result.push_str("</table>\n");
+    Ok(result)
+}
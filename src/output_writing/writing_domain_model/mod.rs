@@ -0,0 +1,168 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_domain_model.md")]
+
+// Mod section
+mod writing_domain_model_for_class;
+mod writing_domain_model_for_datatype;
+mod writing_domain_model_for_enumeration;
+mod writing_domain_model_for_primitive_type;
+mod writing_domain_model_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+
+/// Sub-folder of the source folder (module of the output crate) used by the domain model writing
+pub const DOMAIN_MODEL_FOLDER: &str = "domain_model";
+
+/// Module of the output crate written by the plain domain model (see [`CrateModule`])
+pub const DOMAIN_MODEL_CRATE_MODULE: CrateModule = CrateModule {
+    name: DOMAIN_MODEL_FOLDER,
+    description: "Plain Rust domain model (serde-enabled, without ORM)",
+    dependencies: &[
+        ("serde", r#"{ version = "1.0", features = ["derive"] }"#),
+        ("serde_json", r#""1.0""#),
+    ],
+    required_modules: &[],
+    prelude_items: &[],
+    crate_attributes: &[],
+};
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Plain Rust domain model backend (one file by member of the packages, in "domain_model" module)
+pub struct DomainModelGenerator;
+
+impl Generator for DomainModelGenerator {
+    fn get_name(&self) -> &str {
+        "domain_model"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_domain_model(sink)
+    }
+
+    fn get_crate_module(&self) -> Option<CrateModule> {
+        Some(DOMAIN_MODEL_CRATE_MODULE)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make plain Rust domain model files (serde-enabled, without ORM), in "domain_model" sub-folder
+    pub fn write_domain_model(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let enumeration_default_values = read_enumeration_default_values()?;
+        let primitive_type_conversion = read_primitive_type_conversion()?;
+
+        // Head of mod.rs, using template
+        let (_, mut mod_writer) = sink.get_module_file(DOMAIN_MODEL_FOLDER, "mod.rs");
+        let _ = writeln!(
+            mod_writer,
+            include_str!("template/domain_mod_common.tmpl"),
+            folder_name = self.get_input_folder_name(),
+        );
+
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating domain model for \"{label}\" : START");
+            let mut objects = Vec::new();
+            for entity in pckg.get_json().owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Association(_) => {
+                        // Associations are written as fields of their member ends
+                        continue;
+                    }
+                    EnumOwnedMember::Class(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(DOMAIN_MODEL_FOLDER, entity);
+                        let r = content.write_domain_model(
+                            &mut wrt,
+                            &primitive_type_conversion,
+                            &enumeration_default_values,
+                        );
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::DataType(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(DOMAIN_MODEL_FOLDER, entity);
+                        let r = content.write_domain_model(
+                            &mut wrt,
+                            &primitive_type_conversion,
+                            &enumeration_default_values,
+                        );
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::Enumeration(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(DOMAIN_MODEL_FOLDER, entity);
+                        let r = content.write_domain_model(&mut wrt, &enumeration_default_values);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::PrimitiveType(content) => {
+                        let (_, mut wrt) = sink.get_object_module_file(DOMAIN_MODEL_FOLDER, entity);
+                        let r = content.write_domain_model(&mut wrt, &primitive_type_conversion);
+                        catch_error_and_log(r, content)?
+                    }
+                }
+                objects.push(entity);
+            }
+            // Module of the package (gated by its feature in mod.rs), declaring the modules of its objects
+            if !objects.is_empty() {
+                let package = pckg.get_json();
+                let (_, mut package_writer) =
+                    sink.get_package_module_file(DOMAIN_MODEL_FOLDER, &package.lowercase_name);
+                let _ = writeln!(
+                    package_writer,
+                    include_str!("template/domain_package_mod_common.tmpl"),
+                    uri = package.uri,
+                    folder_name = self.get_input_folder_name(),
+                );
+                for entity in objects {
+                    let (_, module_name) = entity.get_entity_module();
+                    let _ = writeln!(
+                        package_writer,
+                        include_str!("template/domain_mod_part_2_object.tmpl"),
+                        model_name = entity.get_model_name(),
+                        module_name = module_name,
+                    );
+                }
+                let _ = writeln!(
+                    mod_writer,
+                    include_str!("template/domain_mod_part_1_package.tmpl"),
+                    uri = package.uri,
+                    package_name = package.lowercase_name,
+                );
+            }
+            info!("Generating domain model for \"{label}\" : Finished");
+        }
+        Ok(())
+    }
+}
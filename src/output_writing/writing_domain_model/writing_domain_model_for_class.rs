@@ -0,0 +1,230 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_domain_model::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::writing_entity::get_entity_module;
+use crate::output_writing::*;
+
+// Dependencies section
+use proc_macro2::TokenStream;
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// Write domain model content to output file, from "CMOFClass" object
+    pub fn write_domain_model(
+        &self,
+        wrt: &mut File,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<(), anyhow::Error> {
+        let header = rust_doc(&format!("class : {}", self.full_name));
+        let import = if self.need_domain_import()? {
+            quote!(
+                use crate::domain_model::*;
+            )
+        } else {
+            quote!()
+        };
+        let doc = rust_doc(&format!(
+            "{}{}",
+            self.model_name,
+            if self.is_abstract { " (abstract)" } else { "" }
+        ));
+        let derive = if self.is_domain_default(enumeration_default_values)? {
+            quote!(#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)])
+        } else {
+            quote!(#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)])
+        };
+        let name = rust_ident(&self.model_name)?;
+        let fields = self.get_domain_fields(primitive_type_conversion)?;
+        let kind = self.get_domain_kind()?;
+
+        let tokens = quote! {
+            #![doc = #header]
+            #import
+            use serde::{Deserialize, Serialize};
+
+            #[doc = #doc]
+            #derive
+            pub struct #name {
+                #(#fields)*
+            }
+
+            #kind
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+
+    /// Check if the class have sub-class, so need a polymorphic "Kind" enum
+    pub fn has_domain_kind(&self) -> Result<bool, anyhow::Error> {
+        Ok(!self.get_reverse_super_class()?.is_empty())
+    }
+
+    /// Name of the type to use for a owned value of the class (polymorphic "Kind" enum if needed)
+    pub fn get_domain_type_name(&self) -> Result<String, anyhow::Error> {
+        Ok(if self.has_domain_kind()? {
+            format!("{}Kind", self.model_name)
+        } else {
+            self.model_name.clone()
+        })
+    }
+
+    /// Check if the struct of the class (and all its super class) can derive "Default"
+    pub fn is_domain_default(
+        &self,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<bool, anyhow::Error> {
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            if content.is_domain_without_default(enumeration_default_values)? {
+                return Ok(false);
+            }
+        }
+        for (_, class) in self.get_super_class()? {
+            if !get_object_as_class(class)?.is_domain_default(enumeration_default_values)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Check if the struct need other domain model objects (super class, sub-class, or not primitive field)
+    fn need_domain_import(&self) -> Result<bool, anyhow::Error> {
+        if !self.get_super_class()?.is_empty() || self.has_domain_kind()? {
+            return Ok(true);
+        }
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            if !matches!(content.get_type()?, EnumWeakCMOF::CMOFPrimitiveType(_)) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Fields of the struct : super class, then owned attribute
+    fn get_domain_fields(
+        &self,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+    ) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+
+        // For super class, as composition
+        for (_, class) in self.get_super_class()? {
+            let super_class = get_object_as_class(class)?;
+            let doc = rust_doc(&format!("SUPER FIELD : {}", super_class.super_model_name));
+            let field_name = rust_ident(&super_class.super_field_name)?;
+            let field_type = RustType::parse(&super_class.model_name)?;
+            result.push(quote! {
+                #[doc = #doc]
+                pub #field_name: #field_type,
+            });
+        }
+
+        // For all property
+        let (package_name, _) = get_entity_module(&self.package_lowercase_name, &self.table_name);
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            result.push(content.get_domain_field(primitive_type_conversion, &package_name)?);
+        }
+
+        Ok(result)
+    }
+
+    /// Polymorphic "Kind" enum, if the class have sub-class
+    fn get_domain_kind(&self) -> Result<TokenStream, anyhow::Error> {
+        if !self.has_domain_kind()? {
+            return Ok(quote!());
+        }
+
+        let mut variants = Vec::new();
+        let mut cfgs = Vec::new();
+        if !self.is_abstract {
+            variants.push(rust_ident(&self.model_name)?);
+            cfgs.push(quote!());
+        }
+        // Sub-class of an other package, only with its feature
+        let (package_name, _) = get_entity_module(&self.package_lowercase_name, &self.table_name);
+        for (_, class) in self.get_all_descendant_class()? {
+            if !class.is_abstract {
+                variants.push(rust_ident(&class.model_name)?);
+                cfgs.push(match class.get_other_package_feature(&package_name) {
+                    Some(feature) => quote!(#[cfg(feature = #feature)]),
+                    None => quote!(),
+                });
+            }
+        }
+
+        let doc = rust_doc(&format!(
+            "Any concrete {} (polymorphic value)",
+            self.model_name
+        ));
+        let name = rust_ident(&self.get_domain_type_name()?)?;
+        Ok(quote! {
+            #[doc = #doc]
+            #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+            pub enum #name {
+                #(#cfgs #variants(Box<#variants>),)*
+            }
+        })
+    }
+}
+
+impl CMOFProperty {
+    /// Field of a domain model struct, from a property of the package "from_package_name"
+    ///
+    /// A field typed in an other package is gated by the feature of this package
+    pub fn get_domain_field(
+        &self,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+        from_package_name: &str,
+    ) -> Result<TokenStream, anyhow::Error> {
+        let cfg = match self.get_other_package_feature(from_package_name)? {
+            Some(feature) => quote!(#[cfg(feature = #feature)]),
+            None => quote!(),
+        };
+        let is_optional = self.lower == 0 || self.upper > infinitable::Finite(1);
+        let doc = rust_doc(&format!("FIELD : {}", self.xmi_id.label()?));
+        let serde = if is_optional {
+            quote!(#[serde(default)])
+        } else {
+            quote!()
+        };
+        let field_name = rust_ident(&self.get_domain_field_name()?)?;
+        let field_type = self.get_domain_field_type(primitive_type_conversion)?;
+        Ok(quote! {
+            #[doc = #doc]
+            #cfg
+            #serde
+            pub #field_name: #field_type,
+        })
+    }
+}
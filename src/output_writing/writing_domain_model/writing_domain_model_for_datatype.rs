@@ -0,0 +1,91 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_domain_model::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::writing_entity::get_entity_module;
+use crate::output_writing::*;
+
+// Dependencies section
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFDataType {
+    /// Write domain model content to output file, from "CMOFDataType" object
+    pub fn write_domain_model(
+        &self,
+        wrt: &mut File,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<(), anyhow::Error> {
+        let mut fields = Vec::new();
+        let mut need_import = false;
+        let mut is_default = true;
+        let (package_name, _) = get_entity_module(&self.package_lowercase_name, &self.table_name);
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            fields.push(content.get_domain_field(primitive_type_conversion, &package_name)?);
+            if content.is_domain_without_default(enumeration_default_values)? {
+                is_default = false;
+            }
+            // Only primitive type don't need other domain model objects
+            if !matches!(content.get_type()?, EnumWeakCMOF::CMOFPrimitiveType(_)) {
+                need_import = true;
+            }
+        }
+
+        let header = rust_doc(&format!("datatype : {}", self.full_name));
+        let import = if need_import {
+            quote!(
+                use crate::domain_model::*;
+            )
+        } else {
+            quote!()
+        };
+        let doc = rust_doc(&self.model_name);
+        let name = rust_ident(&self.model_name)?;
+        let derive = if is_default {
+            quote!(#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)])
+        } else {
+            quote!(#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)])
+        };
+        let tokens = quote! {
+            #![doc = #header]
+            #import
+            use serde::{Deserialize, Serialize};
+
+            #[doc = #doc]
+            #derive
+            pub struct #name {
+                #(#fields)*
+            }
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_domain_model::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFEnumeration {
+    /// Write domain model content to output file, from "CMOFEnumeration" object
+    pub fn write_domain_model(
+        &self,
+        wrt: &mut File,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<(), anyhow::Error> {
+        // "Default" only with a default literal in "enumeration_default_value.json"
+        let has_default = enumeration_default_values.contains_key(&self.model_name);
+        let mut literals = Vec::new();
+        for literal in self.get_all_literal()? {
+            let doc = rust_doc(&format!(
+                "ENUMERATION LITERAL : {}",
+                literal.xmi_id.label()?
+            ));
+            let default = if has_default && literal.is_default(enumeration_default_values)? {
+                quote!(#[default])
+            } else {
+                quote!()
+            };
+            let designation = &literal.litteral_designation;
+            let name = rust_ident(&literal.litteral_name)?;
+            literals.push(quote! {
+                #[doc = #doc]
+                #default
+                #[serde(rename = #designation)]
+                #name,
+            });
+        }
+
+        let header = rust_doc(&format!("enumeration : {}", self.full_name));
+        let doc = rust_doc(&self.model_name);
+        let name = rust_ident(&self.model_name)?;
+        let derive = if has_default {
+            quote!(#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)])
+        } else {
+            quote!(#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)])
+        };
+        let tokens = quote! {
+            #![doc = #header]
+            use serde::{Deserialize, Serialize};
+
+            #[doc = #doc]
+            #derive
+            pub enum #name {
+                #(#literals)*
+            }
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_domain_model::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFPrimitiveType {
+    /// Write domain model content to output file, from "CMOFPrimitiveType" object
+    pub fn write_domain_model(
+        &self,
+        wrt: &mut File,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+    ) -> Result<(), anyhow::Error> {
+        let standard_object =
+            primitive_type_conversion
+                .get(&self.model_name)
+                .ok_or(anyhow::format_err!(
+                    "No primitive type conversion for \"{}\"",
+                    self.model_name
+                ))?;
+        let header = rust_doc(&format!("primitive_type : {}", self.full_name));
+        let standard_object = RustType::parse(standard_object)?;
+        let name = rust_ident(&self.model_name)?;
+        let tokens = quote! {
+            #![doc = #header]
+            pub use #standard_object as #name;
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+}
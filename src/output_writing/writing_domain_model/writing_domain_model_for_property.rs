@@ -0,0 +1,118 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_domain_model::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Field name for the plain domain model (raw identifier if needed)
+    pub fn get_domain_field_name(&self) -> Result<String, anyhow::Error> {
+        Ok(rust_ident(&self.name.to_case(Case::Snake))?.to_string())
+    }
+
+    /// Field type for the plain domain model
+    ///
+    /// - Option if "lower == 0" and "upper <= 1", Vec if "upper > 1"
+    /// - Class with composition as owned value, polymorphic if the class have sub-class
+    /// - Class without composition as typed reference (Ref)
+    pub fn get_domain_field_type(
+        &self,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+    ) -> Result<RustType, anyhow::Error> {
+        let is_many = self.upper > infinitable::Finite(1);
+        let content = match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => {
+                let class_type = RustType::parse(&c.model_name)?;
+                if !self.is_composite {
+                    RustType::wrap("Ref", &class_type)?
+                } else if c.has_domain_kind()? || is_many {
+                    RustType::parse(&c.get_domain_type_name()?)?
+                } else {
+                    RustType::wrap("Box", &class_type)?
+                }
+            }
+            EnumCMOF::CMOFDataType(c) => RustType::parse(&c.model_name)?,
+            EnumCMOF::CMOFEnumeration(c) => RustType::parse(&c.model_name)?,
+            EnumCMOF::CMOFPrimitiveType(c) => {
+                RustType::parse(primitive_type_conversion.get(&c.model_name).ok_or(
+                    anyhow::format_err!("No primitive type conversion for \"{}\"", c.model_name),
+                )?)?
+            }
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        };
+
+        if is_many {
+            RustType::wrap("Vec", &content)
+        } else if self.lower == 0 {
+            RustType::wrap("Option", &content)
+        } else {
+            Ok(content)
+        }
+    }
+
+    /// Check if the field type can't give a "Default" value (prevent "Default" derive)
+    ///
+    /// Mandatory single polymorphic value, or mandatory single enumeration without default literal
+    pub fn is_domain_without_default(
+        &self,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<bool, anyhow::Error> {
+        if self.is_domain_mandatory_kind()? {
+            return Ok(true);
+        }
+        if self.lower == 0 || self.upper > infinitable::Finite(1) {
+            return Ok(false);
+        }
+        match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFEnumeration(c) => {
+                Ok(!enumeration_default_values.contains_key(&c.model_name))
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Check if the field is a mandatory single polymorphic value (prevent "Default" derive)
+    pub fn is_domain_mandatory_kind(&self) -> Result<bool, anyhow::Error> {
+        if self.lower == 0 || self.upper > infinitable::Finite(1) || !self.is_composite {
+            return Ok(false);
+        }
+        match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => c.has_domain_kind(),
+            _ => Ok(false),
+        }
+    }
+}
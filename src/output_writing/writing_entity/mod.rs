@@ -21,6 +21,7 @@ If not, see <https://www.gnu.org/licenses/>.
 #![doc = include_str!("../../../doc/writing_entity.md")]
 
 // Mod section
+pub mod writing_entity_context;
 pub mod writing_entity_for_association;
 pub mod writing_entity_for_class;
 pub mod writing_entity_for_datatype;
@@ -28,6 +29,7 @@ pub mod writing_entity_for_enumeration;
 pub mod writing_entity_for_primitive_type;
 pub mod writing_entity_for_property;
 pub mod writting_relation;
+pub use writing_entity_context::*;
 pub use writing_entity_for_association::*;
 pub use writing_entity_for_class::*;
 pub use writing_entity_for_datatype::*;
@@ -42,14 +44,191 @@ use crate::custom_log_tools::*;
 use crate::output_writing::*;
 
 // Dependencies section
+use std::path::{Path, PathBuf};
+
+/// Sub-folder of the source folder used by the Sea-ORM entities (module of the output crate)
+pub const ENTITY_FOLDER: &str = "entities";
+
+/// Sea-ORM dependency of the output crate (without database driver)
+pub const SEA_ORM_DEPENDENCY: (&str, &str) = (
+    "sea-orm",
+    r#"{ version = "1.1", default-features = false, features = ["macros", "with-json"] }"#,
+);
+
+/// Module of the output crate written by the Sea-ORM entities (see [`CrateModule`])
+pub const ENTITY_CRATE_MODULE: CrateModule = CrateModule {
+    name: ENTITY_FOLDER,
+    description: "Sea-ORM entities (one module by package, gated by its feature)",
+    dependencies: &[SEA_ORM_DEPENDENCY, ("serde_json", r#""1.0""#)],
+    required_modules: &[],
+    prelude_items: &["*"],
+    crate_attributes: &[],
+};
 
 // ####################################################################################################
 //
 // ####################################################################################################
 
+/// Built-in templates of the Sea-ORM backend (name, content), see [`TemplateSet`]
+pub const SEA_ORM_TEMPLATES: [(&str, &str); 7] = [
+    (
+        "entity_mod.jinja",
+        include_str!("template/entity_mod.jinja"),
+    ),
+    (
+        "entity_package_mod.jinja",
+        include_str!("template/entity_package_mod.jinja"),
+    ),
+    (
+        "entity_main_association.jinja",
+        include_str!("template/entity_main_association.jinja"),
+    ),
+    (
+        "entity_main_class.jinja",
+        include_str!("template/entity_main_class.jinja"),
+    ),
+    (
+        "entity_main_datatype.jinja",
+        include_str!("template/entity_main_datatype.jinja"),
+    ),
+    (
+        "entity_main_enumeration.jinja",
+        include_str!("template/entity_main_enumeration.jinja"),
+    ),
+    (
+        "entity_main_primitive_type.jinja",
+        include_str!("template/entity_main_primitive_type.jinja"),
+    ),
+];
+
+/// Sea-ORM entities backend (__mod.rs__ and one file by member of the packages, in "entities" module)
+pub struct SeaOrmGenerator {
+    /// Folder of "*.jinja" files overriding [`SEA_ORM_TEMPLATES`]
+    template_folder: Option<PathBuf>,
+}
+
+impl SeaOrmGenerator {
+    /// Backend using the built-in templates, overridden by the files of the template folder
+    pub fn new(template_folder: Option<&Path>) -> Self {
+        SeaOrmGenerator {
+            template_folder: template_folder.map(Path::to_path_buf),
+        }
+    }
+}
+
+impl Generator for SeaOrmGenerator {
+    fn get_name(&self) -> &str {
+        "sea_orm"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        let templates = TemplateSet::new(&SEA_ORM_TEMPLATES, self.template_folder.as_deref())?;
+        model.write_lib_file(sink, &templates)?;
+        model.write_mod_object(sink, &templates)
+    }
+
+    fn get_crate_module(&self) -> Option<CrateModule> {
+        Some(ENTITY_CRATE_MODULE)
+    }
+
+    fn use_template_folder(&self) -> bool {
+        true
+    }
+}
+
+/// Module of the package and module of the member of an entity, from the "lowercase_name" of its package and its table name
+///
+/// ("bpmn_20", "bpmn_20_sequence_flow") --> ("bpmn_20", "sequence_flow")
+pub fn get_entity_module(package_lowercase_name: &str, table_name: &str) -> (String, String) {
+    let module_name = table_name
+        .strip_prefix(&format!("{}_", package_lowercase_name))
+        .unwrap_or(table_name)
+        .to_string();
+    (package_lowercase_name.to_string(), module_name)
+}
+
+/// Path of the module of an entity, from an entity of the package "from_package_name"
+///
+/// "super::sequence_flow" in the same package, "crate::entities::dc::bounds" from an other package
+pub fn get_entity_module_path(
+    package_name: &str,
+    module_name: &str,
+    from_package_name: &str,
+) -> String {
+    if package_name == from_package_name {
+        format!("super::{}", module_name)
+    } else {
+        format!(
+            "crate::{}::{}::{}",
+            ENTITY_FOLDER, package_name, module_name
+        )
+    }
+}
+
+impl MemberContext {
+    /// Member context, with the path of its module from an entity of the package "from_package_name"
+    pub fn new(
+        kind: &str,
+        package_lowercase_name: &str,
+        model_name: &str,
+        table_name: &str,
+        from_package_name: &str,
+    ) -> Self {
+        let (package_name, module_name) = get_entity_module(package_lowercase_name, table_name);
+        MemberContext {
+            kind: kind.to_string(),
+            model_name: model_name.to_string(),
+            table_name: table_name.to_string(),
+            module_path: get_entity_module_path(&package_name, &module_name, from_package_name),
+            module_name,
+        }
+    }
+}
+
+impl CMOFClass {
+    /// Feature of the package of the class, when used from the package "from_package_name" (None in the same package)
+    pub fn get_other_package_feature(&self, from_package_name: &str) -> Option<String> {
+        let package_name = &self.package_lowercase_name;
+        (package_name != from_package_name).then(|| package_name.clone())
+    }
+}
+
+impl CMOFProperty {
+    /// Feature of the package of the type of the property, when used from the package "from_package_name" (None for a primitive type, or in the same package)
+    ///
+    /// "BPMN20-Definitions-diagrams" (type "BPMNDI.cmof#BPMNDiagram") --> Some("bpmndi")
+    pub fn get_other_package_feature(
+        &self,
+        from_package_name: &str,
+    ) -> Result<Option<String>, anyhow::Error> {
+        let package_name = match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => c.package_lowercase_name.clone(),
+            EnumCMOF::CMOFDataType(c) => c.package_lowercase_name.clone(),
+            EnumCMOF::CMOFEnumeration(c) => c.package_lowercase_name.clone(),
+            _ => return Ok(None),
+        };
+        Ok((package_name != from_package_name).then_some(package_name))
+    }
+}
+
+impl EnumOwnedMember {
+    /// Module of the package and module of the member (see [`get_entity_module`])
+    pub fn get_entity_module(&self) -> (String, String) {
+        get_entity_module(&self.get_package_lowercase_name(), &self.get_table_name())
+    }
+}
+
 impl LoadingTracker {
     /// Make a module file for each pckg
-    pub fn write_mod_object(&mut self) -> Result<(), anyhow::Error> {
+    pub fn write_mod_object(
+        &self,
+        sink: &dyn WrittingPath,
+        templates: &TemplateSet,
+    ) -> Result<(), anyhow::Error> {
         let enumeration_default_values = read_enumeration_default_values()?;
         let primitive_type_conversion = read_primitive_type_conversion()?;
         for (label, pckg) in self.get_package_in_order() {
@@ -61,38 +240,42 @@ impl LoadingTracker {
                         let association = content.get_association_relation()?;
                         if content.need_file(association)? {
                             // Get file
-                            let (_, mut wrt) = self.get_object_file(entity);
+                            let (_, mut wrt) = sink.get_object_file(entity);
                             //
-                            let r = content.write_content(&mut wrt);
+                            let r = content.write_content(&mut wrt, templates);
                             catch_error_and_log(r, content)?
                         }
                     }
                     EnumOwnedMember::Class(content) => {
                         // Get file
-                        let (_, mut wrt) = self.get_object_file(entity);
+                        let (_, mut wrt) = sink.get_object_file(entity);
                         //
-                        let r = content.write_content(&mut wrt, &primitive_type_conversion);
+                        let r =
+                            content.write_content(&mut wrt, &primitive_type_conversion, templates);
                         catch_error_and_log(r, content)?
                     }
                     EnumOwnedMember::DataType(content) => {
                         // Get file
-                        let (_, mut wrt) = self.get_object_file(entity);
+                        let (_, mut wrt) = sink.get_object_file(entity);
                         //
-                        let r = content.write_content(&mut wrt, &primitive_type_conversion);
+                        let r =
+                            content.write_content(&mut wrt, &primitive_type_conversion, templates);
                         catch_error_and_log(r, content)?
                     }
                     EnumOwnedMember::Enumeration(content) => {
                         // Get file
-                        let (_, mut wrt) = self.get_object_file(entity);
+                        let (_, mut wrt) = sink.get_object_file(entity);
                         //
-                        let r = content.write_content(&mut wrt, &enumeration_default_values);
+                        let r =
+                            content.write_content(&mut wrt, &enumeration_default_values, templates);
                         catch_error_and_log(r, content)?;
                     }
                     EnumOwnedMember::PrimitiveType(content) => {
                         // Get file
-                        let (_, mut wrt) = self.get_object_file(entity);
+                        let (_, mut wrt) = sink.get_object_file(entity);
                         //
-                        let r = content.write_content(&mut wrt, &primitive_type_conversion);
+                        let r =
+                            content.write_content(&mut wrt, &primitive_type_conversion, templates);
                         catch_error_and_log(r, content)?
                     }
                 }
@@ -0,0 +1,206 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+//! Context objects of the Sea-ORM templates (see [`crate::output_writing::TemplateSet`])
+
+// Package section
+
+// Dependencies section
+use serde::Serialize;
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Context of "entity_mod.jinja" : __entities/mod.rs__ file
+#[derive(Clone, Debug, Serialize)]
+pub struct LibContext {
+    /// Input folder of the generation (name only)
+    pub folder_name: String,
+    /// Packages, in package order
+    pub packages: Vec<PackageContext>,
+}
+
+/// Context of "entity_package_mod.jinja" : __entities/${package}/mod.rs__ file
+#[derive(Clone, Debug, Serialize)]
+pub struct PackageContext {
+    /// Input folder of the generation (name only)
+    pub folder_name: String,
+    /// Name of the package and of its module ("bpmn_20")
+    pub name: String,
+    /// URI of the package
+    pub uri: String,
+    /// Exported members of the package
+    pub members: Vec<MemberContext>,
+}
+
+/// Member of a package (in "entity_package_mod.jinja"), or type of an association end
+#[derive(Clone, Debug, Serialize)]
+pub struct MemberContext {
+    /// "Association", "Class", "DataType", "Enumeration" or "PrimitiveType"
+    pub kind: String,
+    /// Name of the Rust type ("SequenceFlow")
+    pub model_name: String,
+    /// Name of the table ("bpmn_20_sequence_flow")
+    pub table_name: String,
+    /// Name of the module, in the module of its package ("sequence_flow")
+    pub module_name: String,
+    /// Path of the module, from an entity of the package using it ("super::sequence_flow", or "crate::entities::dc::bounds" from an other package)
+    pub module_path: String,
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Context of "entity_main_class.jinja" : entity of a class
+#[derive(Clone, Debug, Serialize)]
+pub struct ClassContext {
+    /// Unique name of the class ("bpmn_20_class_sequence_flow")
+    pub full_name: String,
+    /// Name of the Rust type ("SequenceFlow")
+    pub model_name: String,
+    /// Name of the table ("bpmn_20_sequence_flow")
+    pub table_name: String,
+    /// Abstract class of the metamodel
+    pub is_abstract: bool,
+    /// A simple field uses a type of the entities (need "use crate::entities::*;")
+    pub need_crate_import: bool,
+    /// Direct super classes (one row of each, linked by a "super_*" foreign key)
+    pub super_classes: Vec<SuperClassContext>,
+    /// Direct sub-classes (linked to this row by their "super_*" foreign key)
+    pub sub_classes: Vec<SuperClassContext>,
+    /// Fields from the properties, complex fields first
+    pub fields: Vec<FieldContext>,
+    /// Debug output of the class, as comment
+    pub raw: String,
+}
+
+/// Class linked by generalization (super class or sub-class)
+#[derive(Clone, Debug, Serialize)]
+pub struct SuperClassContext {
+    /// Name of the Rust type ("FlowNode")
+    pub model_name: String,
+    /// Name of the table ("bpmn_20_flow_node")
+    pub table_name: String,
+    /// Path of the module, from the class using it ("super::flow_node", or "crate::entities::di::shape" from an other package)
+    pub module_path: String,
+    /// Feature gating the module, when it's in an other package ("di", else None)
+    pub feature: Option<String>,
+    /// Column of the foreign key to this class in its sub-classes ("SuperFlowNode")
+    pub super_model_name: String,
+    /// Field of the foreign key to this class in its sub-classes ("super_flow_node")
+    pub super_field_name: String,
+}
+
+/// Field of an entity, from a property
+#[derive(Clone, Debug, Serialize)]
+pub struct FieldContext {
+    /// "simple" (primitive type or enumeration), "complex" (foreign key to a class or a datatype), or "data" (field of a datatype)
+    pub kind: String,
+    /// Label of the property ("BPMN20-SequenceFlow-sourceRef")
+    pub label: String,
+    /// Name of the field ("source_ref")
+    pub name: String,
+    /// Rust type of the field, with Option if "lower == 0" ("Option<i64>")
+    pub rust_type: String,
+    /// Default value of the metamodel
+    pub default: Option<String>,
+    /// Lower bound of the multiplicity
+    pub lower: i32,
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Context of "entity_main_datatype.jinja" : entity of a datatype
+#[derive(Clone, Debug, Serialize)]
+pub struct DataTypeContext {
+    /// Unique name of the datatype ("dc_datatype_font")
+    pub full_name: String,
+    /// Name of the Rust type ("Font")
+    pub model_name: String,
+    /// Name of the table ("dc_font")
+    pub table_name: String,
+    /// Fields from all the properties
+    pub fields: Vec<FieldContext>,
+    /// Debug output of the datatype, as comment
+    pub raw: String,
+}
+
+/// Context of "entity_main_enumeration.jinja" : Rust enum of an enumeration
+#[derive(Clone, Debug, Serialize)]
+pub struct EnumerationContext {
+    /// Unique name of the enumeration ("bpmn_20_enumeration_process_type")
+    pub full_name: String,
+    /// Name of the Rust type ("ProcessType")
+    pub model_name: String,
+    /// Literals, in metamodel order
+    pub literals: Vec<LiteralContext>,
+    /// Debug output of the enumeration, as comment
+    pub raw: String,
+}
+
+/// Literal of an enumeration
+#[derive(Clone, Debug, Serialize)]
+pub struct LiteralContext {
+    /// Label of the literal ("BPMN20-ProcessType-None")
+    pub label: String,
+    /// Name of the Rust variant ("None")
+    pub name: String,
+    /// Value of the literal in the metamodel ("None")
+    pub designation: String,
+    /// Default literal of the enumeration (see "enumeration_default_value.json")
+    pub is_default: bool,
+}
+
+/// Context of "entity_main_primitive_type.jinja" : alias of a primitive type
+#[derive(Clone, Debug, Serialize)]
+pub struct PrimitiveTypeContext {
+    /// Unique name of the primitive type
+    pub full_name: String,
+    /// Name of the Rust alias ("Integer")
+    pub model_name: String,
+    /// Rust type of the alias (see "primitive_type_conversion.json")
+    pub standard_object: String,
+    /// Debug output of the primitive type, as comment
+    pub raw: String,
+}
+
+/// Context of "entity_main_association.jinja" : entity of an association
+#[derive(Clone, Debug, Serialize)]
+pub struct AssociationContext {
+    /// Unique name of the association
+    pub full_name: String,
+    /// Name of the Rust type ("AIncomingFlowNode")
+    pub model_name: String,
+    /// Name of the table
+    pub table_name: String,
+    /// "one_to_one", "one_to_many" or "many_to_many"
+    pub relation: String,
+    /// Type of the first end (only for "many_to_many")
+    pub from: Option<MemberContext>,
+    /// Type of the second end (only for "many_to_many")
+    pub to: Option<MemberContext>,
+    /// Debug output of the association, as comment
+    pub raw: String,
+}
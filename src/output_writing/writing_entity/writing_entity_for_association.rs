@@ -34,125 +34,49 @@ use crate::custom_log_tools::*;
 // // ####################################################################################################
 
 impl CMOFAssociation {
-    /// write content to output file,from "CMOFClass" object
-    pub fn write_content(&self, wrt: &mut File) -> Result<(), anyhow::Error> {
-        // // Only for "Many to Many"
-        // let association = content.get_association_relation();
-        // if association.ponteration_type == RelationPonderationType::ManyToMany {
-        //     if !association.is_self_referencing {
-        //         // // Get file
-        //         // let (_, mut wrt) = self.get_object_file(entity);
-        //         // //
-        //         // content.write_content(&mut wrt, &self.pre_calculation)?;
-        //     } else {
-        //         warn!(
-        //             "Need association file implement for \"{}\" because it's referencin itself",
-        //             content.model_name
-        //         );
-        //     }
-        // }
-        let association = self.get_association_relation()?;
-        match association {
-            Relation::OneToOneRelation(relation) => self.write_one_to_one(wrt, relation)?,
-            Relation::OneToManyRelation(relation) => self.write_one_to_many(wrt, relation)?,
-            Relation::ManyToManyRelation(relation) => self.write_many_to_many(wrt, relation)?,
-        }
-        Ok(())
-    }
-
-    fn write_one_to_one(
-        &self,
-        wrt: &mut File,
-        _relation: OneToOneRelation,
-    ) -> Result<(), anyhow::Error> {
-        let _ = writeln!(
-            wrt,
-            include_str!("template/entity_main_association_one_to_one.tmpl"),
-            full_name = self.full_name,
-            raw = format!("{:#?}", self).prefix("// "),
-        );
-        Ok(())
-    }
-
-    fn write_one_to_many(
+    /// write content to output file,from "CMOFAssociation" object, using "entity_main_association.jinja"
+    pub fn write_content(
         &self,
         wrt: &mut File,
-        _relation: OneToManyRelation,
+        templates: &TemplateSet,
     ) -> Result<(), anyhow::Error> {
+        let context = self.get_association_context()?;
         let _ = writeln!(
             wrt,
-            include_str!("template/entity_main_association_one_to_many.tmpl"),
-            full_name = self.full_name,
-            raw = format!("{:#?}", self).prefix("// "),
+            "{}",
+            templates.render("entity_main_association.jinja", &context)?
         );
         Ok(())
     }
 
-    fn write_many_to_many(
-        &self,
-        wrt: &mut File,
-        relation: ManyToManyRelation,
-    ) -> Result<(), anyhow::Error> {
-        // Get relation 1 content
-        let relation_1 = relation.get_from().get_type()?.upgrade()?;
-        let relation_1_named = match relation_1.clone() {
-            EnumCMOF::CMOFAssociation(c) => c.model_name.clone(),
-            EnumCMOF::CMOFClass(c) => c.model_name.clone(),
-            EnumCMOF::CMOFDataType(c) => c.model_name.clone(),
-            EnumCMOF::CMOFEnumeration(c) => c.model_name.clone(),
-            EnumCMOF::CMOFPrimitiveType(c) => c.model_name.clone(),
-            _ => panic!("jfsrs894g8sg98"),
-        };
-        let relation_1_table_name = match relation_1.clone() {
-            EnumCMOF::CMOFAssociation(c) => c.table_name.clone(),
-            EnumCMOF::CMOFClass(c) => c.table_name.clone(),
-            EnumCMOF::CMOFDataType(c) => c.table_name.clone(),
-            EnumCMOF::CMOFEnumeration(c) => c.table_name.clone(),
-            EnumCMOF::CMOFPrimitiveType(c) => c.table_name.clone(),
-            _ => panic!("jfsrs894g8sg9ef'z-ursuefs8"),
-        };
-        let relation_1_column_name_camel = &&relation_1_named;
-        // Get relation 2 content
-        let relation_2 = relation.get_to().get_type()?.upgrade()?;
-        let relation_2_named = match relation_2.clone() {
-            EnumCMOF::CMOFAssociation(c) => c.model_name.clone(),
-            EnumCMOF::CMOFClass(c) => c.model_name.clone(),
-            EnumCMOF::CMOFDataType(c) => c.model_name.clone(),
-            EnumCMOF::CMOFEnumeration(c) => c.model_name.clone(),
-            EnumCMOF::CMOFPrimitiveType(c) => c.model_name.clone(),
-            _ => panic!("jfsrs894g8sg98"),
+    /// Context of "entity_main_association.jinja"
+    pub fn get_association_context(&self) -> Result<AssociationContext, anyhow::Error> {
+        let (package_name, _) = get_entity_module(&self.package_lowercase_name, &self.table_name);
+        let (relation, from, to) = match self.get_association_relation()? {
+            Relation::OneToOneRelation(_) => ("one_to_one", None, None),
+            Relation::OneToManyRelation(_) => ("one_to_many", None, None),
+            Relation::ManyToManyRelation(relation) => (
+                "many_to_many",
+                Some(get_end_type_context(
+                    relation.get_from().get_type()?.upgrade()?,
+                    &package_name,
+                )?),
+                Some(get_end_type_context(
+                    relation.get_to().get_type()?.upgrade()?,
+                    &package_name,
+                )?),
+            ),
         };
-        let relation_2_table_name = match relation_2.clone() {
-            EnumCMOF::CMOFAssociation(c) => c.table_name.clone(),
-            EnumCMOF::CMOFClass(c) => c.table_name.clone(),
-            EnumCMOF::CMOFDataType(c) => c.table_name.clone(),
-            EnumCMOF::CMOFEnumeration(c) => c.table_name.clone(),
-            EnumCMOF::CMOFPrimitiveType(c) => c.table_name.clone(),
-            _ => panic!("jfsrs894g8sg9efefs8"),
-        };
-        let relation_2_column_name_camel = &relation_2_named;
-        let _ = writeln!(
-            wrt,
-            include_str!("template/entity_main_association_many_to_many.tmpl"),
-            full_name = self.full_name,
-            import = self.get_import_content(),
-            table_name = self.table_name,
-            relation_1_table_name = relation_1_table_name,
-            relation_2_table_name = relation_2_table_name,
-            relation_1_column_name_snake = relation_1_column_name_camel.to_case(Case::Snake),
-            relation_2_column_name_snake = relation_2_column_name_camel.to_case(Case::Snake),
-            relation_1_column_name_camel = relation_1_column_name_camel,
-            relation_2_column_name_camel = relation_2_column_name_camel,
-            raw = format!("{:#?}", self).prefix("// "),
-        );
-        Ok(())
-    }
 
-    /// "import" content for entity_class_main.tmpl
-    fn get_import_content(&self) -> String {
-        let mut result = String::from("\n");
-        result.push_str("use sea_orm::entity::prelude::*;\n");
-        result
+        Ok(AssociationContext {
+            full_name: self.full_name.clone(),
+            model_name: self.model_name.clone(),
+            table_name: self.table_name.clone(),
+            relation: relation.to_string(),
+            from,
+            to,
+            raw: format!("{:#?}", self).prefix("// "),
+        })
     }
 
     /// Provide information about need to export the association
@@ -164,3 +88,55 @@ impl CMOFAssociation {
         Ok(true)
     }
 }
+
+/// Type of an association end, as member context (module path from the package of the association)
+fn get_end_type_context(
+    end_type: EnumCMOF,
+    package_name: &str,
+) -> Result<MemberContext, anyhow::Error> {
+    let (kind, package_lowercase_name, model_name, table_name) = match &end_type {
+        EnumCMOF::CMOFAssociation(c) => (
+            "Association",
+            &c.package_lowercase_name,
+            &c.model_name,
+            &c.table_name,
+        ),
+        EnumCMOF::CMOFClass(c) => (
+            "Class",
+            &c.package_lowercase_name,
+            &c.model_name,
+            &c.table_name,
+        ),
+        EnumCMOF::CMOFDataType(c) => (
+            "DataType",
+            &c.package_lowercase_name,
+            &c.model_name,
+            &c.table_name,
+        ),
+        EnumCMOF::CMOFEnumeration(c) => (
+            "Enumeration",
+            &c.package_lowercase_name,
+            &c.model_name,
+            &c.table_name,
+        ),
+        EnumCMOF::CMOFPrimitiveType(c) => (
+            "PrimitiveType",
+            &c.package_lowercase_name,
+            &c.model_name,
+            &c.table_name,
+        ),
+        _ => {
+            return Err(anyhow::format_err!(
+                "Association end type isn't a member of a package ({:?})",
+                end_type
+            ))
+        }
+    };
+    Ok(MemberContext::new(
+        kind,
+        package_lowercase_name,
+        model_name,
+        table_name,
+        package_name,
+    ))
+}
@@ -34,36 +34,49 @@ use crate::custom_log_tools::*;
 // // ####################################################################################################
 
 impl CMOFDataType {
-    /// write content to output file,from "CMOFDataType" object
+    /// write content to output file,from "CMOFDataType" object, using "entity_main_datatype.jinja"
     pub fn write_content(
         &self,
         wrt: &mut File,
         primitive_type_conversion: &PrimitiveTypeConversion,
+        templates: &TemplateSet,
     ) -> Result<(), anyhow::Error> {
+        let context = self.get_datatype_context(primitive_type_conversion)?;
         let _ = writeln!(
             wrt,
-            include_str!("template/entity_main_datatype.tmpl"),
-            full_name = self.full_name,
-            table_name = self.table_name,
-            fields = self.get_fields_content(primitive_type_conversion)?,
-            raw = format!("{:#?}", self).prefix("// "),
+            "{}",
+            templates.render("entity_main_datatype.jinja", &context)?
         );
         Ok(())
     }
 
-    fn get_fields_content(
+    /// Context of "entity_main_datatype.jinja"
+    pub fn get_datatype_context(
         &self,
         primitive_type_conversion: &PrimitiveTypeConversion,
-    ) -> Result<String, anyhow::Error> {
-        let mut result = String::from("");
-
+    ) -> Result<DataTypeContext, anyhow::Error> {
         // For all property
-        for field in self.get_all_field()? {
-            let content = CMOFDataType::write_field_property(&field, primitive_type_conversion)?;
-            result.push_str(content.as_str());
+        let mut fields = Vec::new();
+        for property in self.get_all_field()? {
+            fields.push(FieldContext {
+                kind: String::from("data"),
+                label: property.xmi_id.label()?,
+                name: property.name.to_case(Case::Snake),
+                rust_type: property
+                    .get_field_type(primitive_type_conversion)?
+                    .to_string(),
+                default: property.default.clone(),
+                lower: property.lower,
+            });
         }
 
-        Ok(result)
+        Ok(DataTypeContext {
+            full_name: self.full_name.clone(),
+            model_name: self.model_name.clone(),
+            table_name: self.table_name.clone(),
+            fields,
+            raw: format!("{:#?}", self).prefix("// "),
+        })
     }
 
     fn get_all_field(&self) -> Result<Vec<&CMOFProperty>, anyhow::Error> {
@@ -80,40 +93,4 @@ impl CMOFDataType {
 
         Ok(result)
     }
-
-    fn write_field_property(
-        content: &CMOFProperty,
-        primitive_type_conversion: &PrimitiveTypeConversion,
-    ) -> Result<String, anyhow::Error> {
-        let mut result: String = String::new();
-
-        // Comment
-        result.push_str(
-            format!(
-                "    /// RUST DATA TYPE : {comment}\n",
-                comment = content.xmi_id.label()?
-            )
-            .as_str(),
-        );
-        // SEA_ORM element
-        if content.default.is_some() {
-            result.push_str(
-                format!(
-                    "    #[sea_orm(default_value = \"{default_value}\")]\n",
-                    default_value = content.default.as_ref().unwrap()
-                )
-                .as_str(),
-            );
-        };
-        // Pub element
-        result.push_str(
-            format!(
-                "    pub {field_name}: {field_type},\n",
-                field_name = &content.name.to_case(Case::Snake),
-                field_type = content.get_field_type(primitive_type_conversion)?,
-            )
-            .as_str(),
-        );
-        Ok(result)
-    }
 }
@@ -34,66 +34,50 @@ use crate::custom_log_tools::*;
 // // ####################################################################################################
 
 impl CMOFEnumeration {
-    /// write content to output file,from "CMOFEnumeration" object
+    /// write content to output file,from "CMOFEnumeration" object, using "entity_main_enumeration.jinja"
     pub fn write_content(
         &self,
         wrt: &mut File,
         enumeration_default_values: &EnumerationDefaultValues,
+        templates: &TemplateSet,
     ) -> Result<(), anyhow::Error> {
+        let context = self.get_enumeration_context(enumeration_default_values)?;
         let _ = writeln!(
             wrt,
-            include_str!("template/entity_main_enumeration.tmpl"),
-            full_name = self.full_name,
-            model_name = self.model_name,
-            fields = self.get_fields_content(enumeration_default_values)?,
-            raw = format!("{:#?}", self).prefix("// "),
+            "{}",
+            templates.render("entity_main_enumeration.jinja", &context)?
         );
         Ok(())
     }
 
-    fn get_fields_content(
+    /// Context of "entity_main_enumeration.jinja"
+    pub fn get_enumeration_context(
         &self,
         enumeration_default_values: &EnumerationDefaultValues,
-    ) -> Result<String, anyhow::Error> {
-        let mut result = String::from("");
-
+    ) -> Result<EnumerationContext, anyhow::Error> {
         // For all literal
+        let mut literals = Vec::new();
         for literal in self.get_all_literal()? {
-            self.write_literal_property(literal, &mut result, enumeration_default_values)?;
+            literals.push(LiteralContext {
+                label: literal.xmi_id.label()?,
+                name: literal.litteral_name.clone(),
+                designation: literal.litteral_designation.clone(),
+                is_default: literal.is_default(enumeration_default_values)?,
+            });
         }
 
-        Ok(result)
-    }
-
-    fn write_literal_property(
-        &self,
-        literal: &CMOFEnumerationLiteral,
-        result: &mut String,
-        enumeration_default_values: &EnumerationDefaultValues,
-    ) -> Result<(), anyhow::Error> {
-        // Default element
-        let is_default = if literal.is_default(enumeration_default_values)? {
-            "\n    #[default]"
-        } else {
-            ""
-        };
-
-        result.push_str(
-            format!(
-                include_str!("template/entity_sub_enumeration_literal.tmpl"),
-                comment = literal.xmi_id.label()?,
-                is_default = is_default,
-                enumeration_value_snake = literal.litteral_designation,
-                enumeration_value_camel = literal.litteral_name,
-            )
-            .as_str(),
-        );
-        Ok(())
+        Ok(EnumerationContext {
+            full_name: self.full_name.clone(),
+            model_name: self.model_name.clone(),
+            literals,
+            raw: format!("{:#?}", self).prefix("// "),
+        })
     }
 }
 
 impl CMOFEnumerationLiteral {
-    fn is_default(
+    /// Check if the literal is the default value of its enumeration (see "enumeration_default_value.json")
+    pub fn is_default(
         &self,
         enumeration_default_values: &EnumerationDefaultValues,
     ) -> Result<bool, anyhow::Error> {
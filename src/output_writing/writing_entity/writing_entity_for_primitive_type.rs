@@ -34,22 +34,24 @@ use crate::custom_log_tools::*;
 // // ####################################################################################################
 
 impl CMOFPrimitiveType {
-    /// write content to output file,from "CMOFPrimitiveType" object
+    /// write content to output file,from "CMOFPrimitiveType" object, using "entity_main_primitive_type.jinja"
     pub fn write_content(
         &self,
         wrt: &mut File,
         primitive_type_conversion: &PrimitiveTypeConversion,
+        templates: &TemplateSet,
     ) -> Result<(), anyhow::Error> {
-        let object_type = self.model_name.as_str();
-        if primitive_type_conversion.get(object_type).is_some() {
-            let content = primitive_type_conversion.get(object_type).unwrap();
+        if let Some(content) = primitive_type_conversion.get(self.model_name.as_str()) {
+            let context = PrimitiveTypeContext {
+                full_name: self.full_name.clone(),
+                model_name: self.model_name.clone(),
+                standard_object: content.clone(),
+                raw: format!("{:#?}", self).prefix("// "),
+            };
             let _ = writeln!(
                 wrt,
-                include_str!("template/entity_main_primitive_type.tmpl"),
-                full_name = self.full_name,
-                model_name = self.model_name,
-                standard_object = content,
-                raw = format!("{:#?}", self).prefix("// "),
+                "{}",
+                templates.render("entity_main_primitive_type.jinja", &context)?
             );
         }
         Ok(())
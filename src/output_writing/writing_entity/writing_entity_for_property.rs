@@ -25,7 +25,7 @@ use crate::output_writing::writing_entity::*;
 // Package section
 use crate::cmof_loader::*;
 use crate::custom_file_tools::*;
-use crate::custom_log_tools::*;
+use crate::output_writing::*;
 
 // Dependencies section
 
@@ -34,86 +34,76 @@ use crate::custom_log_tools::*;
 // // ####################################################################################################
 
 impl CMOFProperty {
-    /// Yep
+    /// Rust type of the field, with Option if "lower == 0" (error if the property isn't a field, "upper > 1")
     pub fn get_field_type(
         &self,
         primitive_type_conversion: &PrimitiveTypeConversion,
-    ) -> Result<String, anyhow::Error> {
-        let mut result = String::new();
+    ) -> Result<RustType, anyhow::Error> {
+        let content = self.get_field_value_type(primitive_type_conversion)?;
+        if self.lower == 0 {
+            RustType::wrap("Option", &content)
+        } else {
+            Ok(content)
+        }
+    }
 
+    /// Rust type of the value of the field, without Option
+    pub fn get_field_value_type(
+        &self,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+    ) -> Result<RustType, anyhow::Error> {
         if self.upper > infinitable::Finite(1) {
-            return Ok(result);
+            return Err(anyhow::format_err!(
+                "No field type for \"{}\" (upper > 1)",
+                self.xmi_id.label()?
+            ));
         }
 
-        // OPTION
-        result.push_str(if self.lower == 0 { "Option<" } else { "" });
-
-        // For field simple
-
-        let content = if self.simple_type.is_some() {
+        if let Some(simple_type) = &self.simple_type {
             if self.association.is_none() {
                 // Simple field, i.e. other Enumeration
-                let a = self.simple_type.as_ref().unwrap();
-                let a = get_object_as_enum(a)?;
-                match a {
-                    EnumCMOF::CMOFClass(c) => c.model_name.clone(),
+                match get_object_as_enum(simple_type)? {
+                    EnumCMOF::CMOFClass(c) => RustType::parse(&c.model_name),
                     EnumCMOF::CMOFPrimitiveType(c) => {
-                        let r = primitive_type_conversion.get(&c.model_name);
-                        if r.is_none() {
-                            error!("{}", c.model_name);
-                            String::new()
-                        } else {
-                            r.unwrap().clone()
-                        }
-                    }
-                    EnumCMOF::CMOFDataType(c) => c.model_name.clone(),
-                    EnumCMOF::CMOFEnumeration(c) => c.model_name.clone(),
-                    _ => {
-                        return Err(anyhow::format_err!(
-                            "not type for \"{}\"",
-                            &self.simple_type.as_ref().unwrap().label()?
-                        ));
+                        get_primitive_type(&c.model_name, primitive_type_conversion)
                     }
+                    EnumCMOF::CMOFDataType(c) => RustType::parse(&c.model_name),
+                    EnumCMOF::CMOFEnumeration(c) => RustType::parse(&c.model_name),
+                    _ => Err(anyhow::format_err!(
+                        "not type for \"{}\"",
+                        simple_type.label()?
+                    )),
                 }
             } else {
                 // Foreign field
-                String::from("i64")
+                RustType::parse("i64")
             }
         } else {
             match self.complex_type.as_ref().unwrap() {
                 EnumType::HRefPrimitiveType(link) => {
                     // Simple field
                     let c = link.href.get_object()?;
-                    let c = c.upgrade();
-                    let c = if c.is_some() {
-                        c.unwrap()
-                    } else {
-                        return Err(anyhow::format_err!("errberb"));
+                    let c = match c.upgrade() {
+                        Some(c) => c,
+                        None => {
+                            return Err(anyhow::format_err!(
+                                "Upgrade result 'None' : {:?}",
+                                link.href
+                            ));
+                        }
                     };
-                    let r = primitive_type_conversion.get(&c.model_name);
-                    if r.is_none() {
-                        error!("{}", c.model_name);
-                        String::new()
-                    } else {
-                        r.unwrap().clone()
-                    }
+                    get_primitive_type(&c.model_name, primitive_type_conversion)
                 }
                 EnumType::HRefClass(_) => {
                     // Foreign field
-                    String::from("i64")
+                    RustType::parse("i64")
                 }
                 EnumType::HRefDataType(_) => {
                     // Foreign field
-                    String::from("i64")
+                    RustType::parse("i64")
                 }
             }
-        };
-        result.push_str(content.as_str());
-
-        // OPTION
-        result.push_str(if self.lower == 0 { ">" } else { "" });
-
-        Ok(result)
+        }
     }
 
     /// Yep Yep
@@ -125,3 +115,16 @@ impl CMOFProperty {
         }
     }
 }
+
+/// Rust type of a primitive type (see "primitive_type_conversion.json")
+fn get_primitive_type(
+    model_name: &str,
+    primitive_type_conversion: &PrimitiveTypeConversion,
+) -> Result<RustType, anyhow::Error> {
+    match primitive_type_conversion.get(model_name) {
+        Some(content) => RustType::parse(content),
+        None => Err(anyhow::format_err!(
+            "No primitive type conversion for \"{model_name}\""
+        )),
+    }
+}
@@ -0,0 +1,327 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_execution.md")]
+
+// Mod section
+mod writing_execution_for_class;
+mod writing_execution_for_operation;
+mod writing_execution_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::writing_domain_model::DOMAIN_MODEL_FOLDER;
+use crate::output_writing::*;
+
+// Dependencies section
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Sub-folder of the source folder (module of the output crate) used by the execution traits writing
+pub const EXECUTION_FOLDER: &str = "execution";
+
+/// Module of the output crate written by the execution traits (see [`CrateModule`])
+pub const EXECUTION_CRATE_MODULE: CrateModule = CrateModule {
+    name: EXECUTION_FOLDER,
+    description: "Execution semantics traits, on the plain domain model",
+    dependencies: &[],
+    required_modules: &[DOMAIN_MODEL_FOLDER],
+    prelude_items: &["*"],
+    crate_attributes: &[],
+};
+
+/// Name of the tag making a property an execution method
+pub const EXECUTION_TAG_NAME: &str = "org.imbriqua.execution";
+
+/// Value of the tag making a property an execution method
+pub const EXECUTION_TAG_METHOD: &str = "method";
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Method of an execution trait, from an operation or a tagged property
+#[derive(Clone, Debug)]
+pub struct ExecutionMethod {
+    /// Label of the source element (operation or property)
+    pub label: String,
+    /// Name of the method, in snake_case (raw identifier if needed)
+    pub name: String,
+    /// Method without side effect on the element ("isQuery" operation, or tagged property)
+    pub is_query: bool,
+    /// Parameters of the signature, in order : (name, type)
+    pub parameters: Vec<(String, RustType)>,
+    /// Optional returned type
+    pub return_type: Option<RustType>,
+}
+
+impl ExecutionMethod {
+    /// Name of the method in the execution trait of a class, prefixed by the class ("process_start")
+    pub fn get_execution_name(&self, class: &CMOFClass) -> Result<Ident, anyhow::Error> {
+        rust_ident(&format!(
+            "{}_{}",
+            class.model_name.to_case(Case::Snake),
+            self.name.trim_start_matches("r#")
+        ))
+    }
+
+    /// Type of the element argument ("&mut Process", or "&Process" for query)
+    pub fn get_element_type(&self, class: &CMOFClass) -> Result<TokenStream, anyhow::Error> {
+        let borrow = self.get_borrow();
+        let name = rust_ident(&class.model_name)?;
+        Ok(quote!(#borrow #name))
+    }
+
+    /// Borrow of the element ("&mut", or "&" for query)
+    pub fn get_borrow(&self) -> TokenStream {
+        if self.is_query {
+            quote!(&)
+        } else {
+            quote!(&mut)
+        }
+    }
+
+    /// Parameters after the element or the engine ("name: Type")
+    pub fn get_parameters(&self) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+        for (name, parameter_type) in &self.parameters {
+            let name = rust_ident(name)?;
+            result.push(quote!(#name: #parameter_type));
+        }
+        Ok(result)
+    }
+
+    /// Arguments after the element ("name")
+    pub fn get_arguments(&self) -> Result<Vec<Ident>, anyhow::Error> {
+        self.parameters
+            .iter()
+            .map(|(name, _)| rust_ident(name))
+            .collect()
+    }
+
+    /// Returned type of the signature ("-> Type", or nothing)
+    pub fn get_return(&self) -> TokenStream {
+        match &self.return_type {
+            Some(return_type) => quote!(-> #return_type),
+            None => quote!(),
+        }
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Own and inherited methods of a class (key : method name), with the super class to delegate to for inherited ones
+pub type ExecutionMethods = BTreeMap<String, (ExecutionMethod, Option<Rc<CMOFClass>>)>;
+
+/// Shared content during the writing of the execution traits
+pub struct ExecutionContext {
+    /// Own methods of all classes (key : class label, then method name)
+    pub own_methods: BTreeMap<String, BTreeMap<String, ExecutionMethod>>,
+}
+
+impl ExecutionContext {
+    /// All methods of a class : own methods, then methods of the super classes (with the super class to delegate to)
+    pub fn get_all_method(&self, class: &CMOFClass) -> Result<ExecutionMethods, anyhow::Error> {
+        let mut result = ExecutionMethods::new();
+        if let Some(own_methods) = self.own_methods.get(&class.get_xmi_label()?) {
+            for (name, method) in own_methods {
+                result.insert(name.clone(), (method.clone(), None));
+            }
+        }
+        // First super class providing the method wins
+        for (_, class_reference) in class.get_super_class()? {
+            let super_class = get_object_as_class(class_reference)?;
+            for (name, (method, _)) in self.get_all_method(&super_class)? {
+                result
+                    .entry(name)
+                    .or_insert_with(|| (method, Some(super_class.clone())));
+            }
+        }
+        Ok(result)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Execution backend (execution traits, one file by class, in "execution" module)
+pub struct ExecutionGenerator;
+
+impl Generator for ExecutionGenerator {
+    fn get_name(&self) -> &str {
+        "execution"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_execution(sink)
+    }
+
+    fn get_crate_module(&self) -> Option<CrateModule> {
+        Some(EXECUTION_CRATE_MODULE)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make execution semantics traits (one trait by class), in "execution" sub-folder
+    pub fn write_execution(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let primitive_type_conversion = read_primitive_type_conversion()?;
+
+        // Own methods of all classes, from operations and tagged properties
+        let mut context = ExecutionContext {
+            own_methods: BTreeMap::new(),
+        };
+        for pckg in self.get_package_in_order().values() {
+            for entity in pckg.get_json().owned_member.values() {
+                if let EnumOwnedMember::Class(content) = entity {
+                    let r = content.get_execution_own_method(pckg, &primitive_type_conversion);
+                    let own_methods = catch_error_and_log(r, content)?;
+                    context
+                        .own_methods
+                        .insert(content.get_xmi_label()?, own_methods);
+                }
+            }
+        }
+
+        // Head of mod.rs, using template
+        let (_, mut mod_writer) = sink.get_module_file(EXECUTION_FOLDER, "mod.rs");
+        let _ = writeln!(
+            mod_writer,
+            include_str!("template/execution_mod_common.tmpl"),
+            folder_name = self.get_input_folder_name(),
+        );
+
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating execution traits for \"{label}\" : START");
+            let mut objects = Vec::new();
+            for entity in pckg.get_json().owned_member.values() {
+                if let EnumOwnedMember::Class(content) = entity {
+                    let (_, mut wrt) = sink.get_object_module_file(EXECUTION_FOLDER, entity);
+                    let r = content.write_execution(&mut wrt, &context);
+                    catch_error_and_log(r, content)?;
+                    objects.push(entity);
+                }
+            }
+            // Module of the package (gated by its feature in mod.rs), declaring the modules of its objects
+            if !objects.is_empty() {
+                let package = pckg.get_json();
+                let (_, mut package_writer) =
+                    sink.get_package_module_file(EXECUTION_FOLDER, &package.lowercase_name);
+                let _ = writeln!(
+                    package_writer,
+                    include_str!("template/execution_package_mod_common.tmpl"),
+                    uri = package.uri,
+                    folder_name = self.get_input_folder_name(),
+                );
+                for entity in objects {
+                    let (_, module_name) = entity.get_entity_module();
+                    let _ = writeln!(
+                        package_writer,
+                        include_str!("template/execution_mod_part_2_object.tmpl"),
+                        model_name = entity.get_model_name(),
+                        module_name = module_name,
+                    );
+                }
+                let _ = writeln!(
+                    mod_writer,
+                    include_str!("template/execution_mod_part_1_package.tmpl"),
+                    uri = package.uri,
+                    package_name = package.lowercase_name,
+                );
+            }
+            info!("Generating execution traits for \"{label}\" : Finished");
+        }
+        Ok(())
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+    use crate::output_result_manager::open_env;
+
+    #[test]
+    fn writing_execution_01_operation() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let test_folder = "tests/writing_execution/writing_execution_01_operation/";
+            let file_env = open_env(
+                &format!("{test_folder}input"),
+                &format!("{test_folder}output"),
+                &format!("{test_folder}result"),
+            )?;
+            let mut loading_env = open_loader(file_env)?;
+            loading_env.make_prepare("Sample.json", "_0", "root")?;
+            let sink = OutputSink::new(&loading_env.get_output_folder(), false);
+            ExecutionGenerator.generate(&loading_env, &sink)?;
+
+            let mut folder = loading_env.get_output_folder();
+            folder.push(SOURCE_FOLDER);
+            folder.push(EXECUTION_FOLDER);
+            folder.push("sample");
+
+            // Own methods : required if returning a value, no-op otherwise
+            let activity = folder.join("activity.rs").get_file_content()?;
+            assert!(activity.contains(
+                "    fn activity_is_ready(&mut self, element: &Activity) -> std::primitive::bool;\n"
+            ));
+            assert!(activity.contains(
+                "    fn activity_start(\n        &mut self,\n        element: &mut Activity,\n        count: &mut std::primitive::u64,\n    ) {\n        let _ = (element, count);\n    }\n"
+            ));
+            assert!(!activity.contains("unimplemented!"));
+
+            // Inherited methods delegate to the super class trait, tagged property is a required query
+            let task = folder.join("task.rs").get_file_content()?;
+            assert!(task.contains("pub trait TaskExecution: ActivityExecution {"));
+            assert!(task.contains("        self.activity_is_ready(&element.super_activity)\n"));
+            assert!(
+                task.contains("        self.activity_start(&mut element.super_activity, count)\n")
+            );
+            assert!(task.contains(
+                "    fn task_priority(&mut self, element: &Task) -> std::primitive::u64;\n"
+            ));
+
+            Path::new(&format!("{test_folder}output")).purge_folder()?;
+            Path::new(&format!("{test_folder}result")).purge_folder()?;
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+}
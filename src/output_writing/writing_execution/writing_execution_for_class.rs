@@ -0,0 +1,235 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_execution::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// Own methods of the class, from its operations and its tagged properties (key : method name)
+    pub fn get_execution_own_method(
+        &self,
+        package: &LoadingPackage,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+    ) -> Result<BTreeMap<String, ExecutionMethod>, anyhow::Error> {
+        let mut result = BTreeMap::new();
+        for operation in self.owned_operation.values() {
+            let EnumOwnedOperation::Operation(content) = operation;
+            let method = content.get_execution_method(primitive_type_conversion)?;
+            result.insert(method.name.clone(), method);
+        }
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            let tag = package.get_element_tag(&content.xmi_id.get_object_id(), EXECUTION_TAG_NAME);
+            if tag.as_deref() == Some(EXECUTION_TAG_METHOD) {
+                let method = content.get_execution_method(primitive_type_conversion)?;
+                result.insert(method.name.clone(), method);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Write execution traits content to output file, from "CMOFClass" object
+    pub fn write_execution(
+        &self,
+        wrt: &mut File,
+        context: &ExecutionContext,
+    ) -> Result<(), anyhow::Error> {
+        let methods = context.get_all_method(self)?;
+
+        // Imports : super class traits, and domain model types
+        let super_import = if self.get_super_class()?.is_empty() {
+            quote!()
+        } else {
+            quote!(
+                use crate::execution::*;
+            )
+        };
+        let domain_import = if methods.is_empty() {
+            quote!()
+        } else {
+            quote!(
+                use crate::domain_model::*;
+            )
+        };
+
+        let header = rust_doc(&format!("class : {}", self.full_name));
+        let doc = rust_doc(&format!(
+            "Execution semantics of {}{}, implemented by the engine overriding the default methods",
+            self.model_name,
+            if self.is_abstract { " (abstract)" } else { "" }
+        ));
+        let name = rust_ident(&format!("{}Execution", self.model_name))?;
+        let super_traits = self.get_execution_super_traits()?;
+        let super_traits = if super_traits.is_empty() {
+            quote!()
+        } else {
+            quote!(: #(#super_traits)+*)
+        };
+        let methods_content = self.get_execution_methods(&methods)?;
+        let operation = self.get_execution_operation(&methods)?;
+
+        let tokens = quote! {
+            #![doc = #header]
+            #super_import
+            #domain_import
+
+            #[doc = #doc]
+            pub trait #name #super_traits {
+                #(#methods_content)*
+            }
+
+            #operation
+        };
+        let _ = write!(wrt, "{}", rust_file_to_string(tokens)?);
+        Ok(())
+    }
+
+    /// Execution traits of the super classes
+    fn get_execution_super_traits(&self) -> Result<Vec<Ident>, anyhow::Error> {
+        let mut super_traits = Vec::new();
+        for (_, class) in self.get_super_class()? {
+            let super_class = get_object_as_class(class)?;
+            super_traits.push(rust_ident(&format!("{}Execution", super_class.model_name))?);
+        }
+        Ok(super_traits)
+    }
+
+    /// Methods of the execution trait : required, no-op, or delegating to the super class trait
+    fn get_execution_methods(
+        &self,
+        methods: &ExecutionMethods,
+    ) -> Result<Vec<TokenStream>, anyhow::Error> {
+        let mut result = Vec::new();
+        for (method, super_class) in methods.values() {
+            let method_name = method.get_execution_name(self)?;
+            let element_type = method.get_element_type(self)?;
+            let parameters = method.get_parameters()?;
+            let return_type = method.get_return();
+            match super_class {
+                None if method.return_type.is_some() => {
+                    // Own method returning a value : required, implemented by the engine
+                    let doc = rust_doc(&format!("OPERATION : {}", method.label));
+                    result.push(quote! {
+                        #[doc = #doc]
+                        fn #method_name(&mut self, element: #element_type #(, #parameters)*) #return_type;
+                    });
+                }
+                None => {
+                    // Own method without returned value : no-op
+                    let doc = rust_doc(&format!("OPERATION : {}", method.label));
+                    let arguments = method.get_arguments()?;
+                    let unused = if arguments.is_empty() {
+                        quote!(element)
+                    } else {
+                        quote!((element #(, #arguments)*))
+                    };
+                    result.push(quote! {
+                        #[doc = #doc]
+                        fn #method_name(&mut self, element: #element_type #(, #parameters)*) #return_type {
+                            let _ = #unused;
+                        }
+                    });
+                }
+                Some(super_class) => {
+                    // Inherited method : delegate to the super class trait
+                    let doc = rust_doc(&format!("INHERITED OPERATION : {}", method.label));
+                    let super_method_name = method.get_execution_name(super_class)?;
+                    let borrow = method.get_borrow();
+                    let super_field_name = rust_ident(&super_class.super_field_name)?;
+                    let arguments = method.get_arguments()?;
+                    result.push(quote! {
+                        #[doc = #doc]
+                        fn #method_name(&mut self, element: #element_type #(, #parameters)*) #return_type {
+                            self.#super_method_name(#borrow element.#super_field_name #(, #arguments)*)
+                        }
+                    });
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Model side trait of the class, with blanket implementation on the domain model struct
+    fn get_execution_operation(
+        &self,
+        methods: &ExecutionMethods,
+    ) -> Result<TokenStream, anyhow::Error> {
+        if methods.is_empty() {
+            return Ok(quote!());
+        }
+
+        let mut declarations = Vec::new();
+        let mut implementations = Vec::new();
+        for (method, _) in methods.values() {
+            let receiver = if method.is_query {
+                quote!(&self)
+            } else {
+                quote!(&mut self)
+            };
+            let doc = rust_doc(&format!("OPERATION : {}", method.label));
+            let name = rust_ident(&method.name)?;
+            let parameters = method.get_parameters()?;
+            let return_type = method.get_return();
+            let method_name = method.get_execution_name(self)?;
+            let arguments = method.get_arguments()?;
+            declarations.push(quote! {
+                #[doc = #doc]
+                fn #name(#receiver, engine: &mut E #(, #parameters)*) #return_type;
+            });
+            implementations.push(quote! {
+                fn #name(#receiver, engine: &mut E #(, #parameters)*) #return_type {
+                    engine.#method_name(self #(, #arguments)*)
+                }
+            });
+        }
+
+        let doc = rust_doc(&format!(
+            "Operations of {}, run by an execution engine",
+            self.model_name
+        ));
+        let model_name = rust_ident(&self.model_name)?;
+        let operation_name = rust_ident(&format!("{}Operation", self.model_name))?;
+        let execution_name = rust_ident(&format!("{}Execution", self.model_name))?;
+        Ok(quote! {
+            #[doc = #doc]
+            pub trait #operation_name<E: ?Sized> {
+                #(#declarations)*
+            }
+
+            impl<E: #execution_name + ?Sized> #operation_name<E> for #model_name {
+                #(#implementations)*
+            }
+        })
+    }
+}
@@ -0,0 +1,106 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_execution::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+/// Name in snake_case, with raw identifier if needed
+fn get_execution_identifier(name: &str) -> Result<String, anyhow::Error> {
+    let name = name.trim_start_matches("r#").to_case(Case::Snake);
+    Ok(rust_ident(&name)?.to_string())
+}
+
+impl CMOFOperation {
+    /// Method of the execution trait, from the operation
+    pub fn get_execution_method(
+        &self,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+    ) -> Result<ExecutionMethod, anyhow::Error> {
+        let mut parameters = Vec::new();
+        for parameter in self.get_signature_parameter() {
+            let parameter_type = parameter.get_execution_type(primitive_type_conversion)?;
+            let parameter_type = match parameter.direction {
+                UMLParameterDirectionKind::InOut | UMLParameterDirectionKind::Out => {
+                    RustType::parse(&format!("&mut {}", parameter_type))?
+                }
+                _ => parameter_type,
+            };
+            parameters.push((get_execution_identifier(&parameter.name)?, parameter_type));
+        }
+        let return_type = match self.get_return_parameter() {
+            Some(parameter) => Some(parameter.get_execution_type(primitive_type_conversion)?),
+            None => None,
+        };
+        Ok(ExecutionMethod {
+            label: self.xmi_id.label()?,
+            name: get_execution_identifier(&self.name)?,
+            is_query: self.is_query,
+            parameters,
+            return_type,
+        })
+    }
+}
+
+impl CMOFParameter {
+    /// Type of the parameter, using the domain model types
+    ///
+    /// - Option if "lower == 0" and "upper <= 1", Vec if "upper > 1"
+    /// - Class as typed reference (Ref)
+    pub fn get_execution_type(
+        &self,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+    ) -> Result<RustType, anyhow::Error> {
+        let content = match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => RustType::wrap("Ref", &RustType::parse(&c.model_name)?)?,
+            EnumCMOF::CMOFDataType(c) => RustType::parse(&c.model_name)?,
+            EnumCMOF::CMOFEnumeration(c) => RustType::parse(&c.model_name)?,
+            EnumCMOF::CMOFPrimitiveType(c) => {
+                RustType::parse(primitive_type_conversion.get(&c.model_name).ok_or(
+                    anyhow::format_err!("No primitive type conversion for \"{}\"", c.model_name),
+                )?)?
+            }
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        };
+
+        if self.upper > infinitable::Finite(1) {
+            RustType::wrap("Vec", &content)
+        } else if self.lower == 0 {
+            RustType::wrap("Option", &content)
+        } else {
+            Ok(content)
+        }
+    }
+}
@@ -0,0 +1,48 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_execution::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Method of the execution trait, from a tagged property (query without parameter, returning the property type)
+    pub fn get_execution_method(
+        &self,
+        primitive_type_conversion: &PrimitiveTypeConversion,
+    ) -> Result<ExecutionMethod, anyhow::Error> {
+        Ok(ExecutionMethod {
+            label: self.xmi_id.label()?,
+            name: self.get_domain_field_name()?,
+            is_query: true,
+            parameters: Vec::new(),
+            return_type: Some(self.get_domain_field_type(primitive_type_conversion)?),
+        })
+    }
+}
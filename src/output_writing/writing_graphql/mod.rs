@@ -0,0 +1,127 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_graphql.md")]
+
+// Mod section
+mod writing_graphql_for_class;
+mod writing_graphql_for_datatype;
+mod writing_graphql_for_enumeration;
+mod writing_graphql_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+
+/// Sub-folder of the output folder used by the GraphQL writing
+pub const GRAPHQL_FOLDER: &str = "graphql";
+
+/// Built-in scalar of GraphQL, others scalar need declaration
+pub const GRAPHQL_BUILT_IN_SCALAR: [&str; 5] = ["Int", "Float", "String", "Boolean", "ID"];
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// GraphQL backend (one schema of all the packages, in "graphql" folder)
+pub struct GraphqlGenerator;
+
+impl Generator for GraphqlGenerator {
+    fn get_name(&self) -> &str {
+        "graphql"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_graphql(sink)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make GraphQL SDL schema file ("schema.graphql"), in "graphql" sub-folder
+    pub fn write_graphql(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let graphql_type_conversion = read_graphql_type_conversion()?;
+
+        // Custom scalar, from primitive type conversion
+        let mut scalars = String::new();
+        for scalar in graphql_type_conversion.values() {
+            if !GRAPHQL_BUILT_IN_SCALAR.contains(&scalar.as_str())
+                && !scalars.contains(&format!("scalar {}\n", scalar))
+            {
+                scalars.push_str(&format!("scalar {}\n", scalar));
+            }
+        }
+
+        let mut definitions = String::new();
+        let mut queries = String::new();
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating GraphQL schema for \"{label}\" : START");
+            definitions.push_str(&format!("\n# Package : {}\n", label));
+            for entity in pckg.get_json().owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Association(_) => {
+                        // Associations are written as fields of their member ends
+                        continue;
+                    }
+                    EnumOwnedMember::Class(content) => {
+                        let r = content.get_graphql_content(&graphql_type_conversion);
+                        definitions.push_str(&catch_error_and_log(r, content)?);
+                        let r = content.get_graphql_query();
+                        queries.push_str(&catch_error_and_log(r, content)?);
+                    }
+                    EnumOwnedMember::DataType(content) => {
+                        let r = content.get_graphql_content(&graphql_type_conversion);
+                        definitions.push_str(&catch_error_and_log(r, content)?);
+                    }
+                    EnumOwnedMember::Enumeration(content) => {
+                        let r = content.get_graphql_content();
+                        definitions.push_str(&catch_error_and_log(r, content)?);
+                    }
+                    EnumOwnedMember::PrimitiveType(_) => {
+                        // Primitive types are converted as scalar
+                        continue;
+                    }
+                }
+            }
+            info!("Generating GraphQL schema for \"{label}\" : Finished");
+        }
+
+        let (_, mut wrt) = sink.get_backend_file(GRAPHQL_FOLDER, "schema.graphql");
+        let _ = write!(
+            wrt,
+            include_str!("template/graphql_main_schema.tmpl"),
+            scalars = scalars,
+            queries = queries,
+            definitions = definitions,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+
+/// Field added to object without field (not allowed by GraphQL), and to the types implementing them
+const GRAPHQL_PLACEHOLDER_FIELD: &str = "  _empty: Boolean\n";
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// GraphQL interface (abstract class) or type (concrete class) of the class
+    ///
+    /// A concrete class with sub-class gets also an interface, implemented by itself and by its sub-class (GraphQL object type can't extend an other one)
+    ///
+    /// GraphQL need all fields of the implemented interfaces, so inherited properties are copied
+    pub fn get_graphql_content(
+        &self,
+        graphql_type_conversion: &GraphqlTypeConversion,
+    ) -> Result<String, anyhow::Error> {
+        let mut interfaces: Vec<String> = self
+            .get_all_super_class()?
+            .values()
+            .map(|class| class.get_graphql_interface_name())
+            .collect();
+
+        let mut fields = String::new();
+        if self.need_graphql_placeholder()? {
+            fields.push_str(GRAPHQL_PLACEHOLDER_FIELD);
+        }
+        for property in self.get_all_property()?.values() {
+            fields.push_str(&property.get_graphql_field(graphql_type_conversion)?);
+        }
+
+        let mut result = String::new();
+        if self.is_abstract || self.has_graphql_interface()? {
+            result.push_str(&format!(
+                include_str!("template/graphql_main_object.tmpl"),
+                label = self.xmi_id.label()?,
+                kind = "interface",
+                model_name = self.get_graphql_interface_name(),
+                implements = get_graphql_implements(&interfaces),
+                fields = fields,
+            ));
+            interfaces.push(self.get_graphql_interface_name());
+        }
+        if !self.is_abstract {
+            result.push_str(&format!(
+                include_str!("template/graphql_main_object.tmpl"),
+                label = self.xmi_id.label()?,
+                kind = "type",
+                model_name = self.model_name,
+                implements = get_graphql_implements(&interfaces),
+                fields = fields,
+            ));
+        }
+        Ok(result)
+    }
+
+    /// Check if the class is concrete with sub-class, so need an interface in addition to its type
+    pub fn has_graphql_interface(&self) -> Result<bool, anyhow::Error> {
+        Ok(!self.is_abstract && !self.get_reverse_super_class()?.is_empty())
+    }
+
+    /// Name of the interface of the class ("${model_name}Interface" for a concrete class)
+    pub fn get_graphql_interface_name(&self) -> String {
+        if self.is_abstract {
+            self.model_name.clone()
+        } else {
+            format!("{}Interface", self.model_name)
+        }
+    }
+
+    /// Name to use for a value of the class : interface if the class is abstract or have sub-class
+    pub fn get_graphql_type_name(&self) -> Result<String, anyhow::Error> {
+        Ok(if self.has_graphql_interface()? {
+            self.get_graphql_interface_name()
+        } else {
+            self.model_name.clone()
+        })
+    }
+
+    /// Check if the class (or one of its interfaces) have no field, so need a placeholder field
+    fn need_graphql_placeholder(&self) -> Result<bool, anyhow::Error> {
+        if self.get_all_property()?.is_empty() {
+            return Ok(true);
+        }
+        for class in self.get_all_super_class()?.values() {
+            if class.get_all_property()?.is_empty() {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Field of "Query" type, fetching an element of the class (or of its sub-class) by id (only for concrete class)
+    pub fn get_graphql_query(&self) -> Result<String, anyhow::Error> {
+        Ok(if self.is_abstract {
+            String::new()
+        } else {
+            format!(
+                "  {}(id: ID!): {}\n",
+                self.model_name.to_case(Case::Camel),
+                self.get_graphql_type_name()?
+            )
+        })
+    }
+}
+
+/// " implements A & B" part of an interface or a type (empty without interface)
+fn get_graphql_implements(interfaces: &[String]) -> String {
+    if interfaces.is_empty() {
+        String::new()
+    } else {
+        format!(" implements {}", interfaces.join(" & "))
+    }
+}
@@ -0,0 +1,55 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+
+// Package section
+use crate::cmof_loader::*;
+use crate::output_writing::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFDataType {
+    /// GraphQL type of the datatype
+    pub fn get_graphql_content(
+        &self,
+        graphql_type_conversion: &GraphqlTypeConversion,
+    ) -> Result<String, anyhow::Error> {
+        let mut fields = String::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            fields.push_str(&content.get_graphql_field(graphql_type_conversion)?);
+        }
+
+        Ok(format!(
+            include_str!("template/graphql_main_object.tmpl"),
+            label = self.xmi_id.label()?,
+            kind = "type",
+            model_name = self.model_name,
+            implements = "",
+            fields = fields,
+        ))
+    }
+}
@@ -0,0 +1,72 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+
+// Package section
+use crate::cmof_loader::*;
+use crate::output_writing::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// GraphQL field of the property
+    ///
+    /// - Class (its interface if it have sub-class), DataType and Enumeration by name, primitive type as scalar ("ID" for "id")
+    /// - List if "upper > 1", non-null if "lower >= 1"
+    pub fn get_graphql_field(
+        &self,
+        graphql_type_conversion: &GraphqlTypeConversion,
+    ) -> Result<String, anyhow::Error> {
+        let name = self.get_metamodel_name();
+        let content = match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => c.get_graphql_type_name()?,
+            EnumCMOF::CMOFDataType(c) => c.model_name.clone(),
+            EnumCMOF::CMOFEnumeration(c) => c.model_name.clone(),
+            EnumCMOF::CMOFPrimitiveType(_) if name == "id" => String::from("ID"),
+            EnumCMOF::CMOFPrimitiveType(c) => graphql_type_conversion
+                .get(&c.model_name)
+                .ok_or(anyhow::format_err!(
+                    "No GraphQL type conversion for \"{}\"",
+                    c.model_name
+                ))?
+                .clone(),
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        };
+
+        let content = if self.upper > infinitable::Finite(1) {
+            format!("[{}!]", content)
+        } else {
+            content
+        };
+        let non_null = if self.lower >= 1 { "!" } else { "" };
+        Ok(format!("  {}: {}{}\n", name, content, non_null))
+    }
+}
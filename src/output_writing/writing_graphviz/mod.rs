@@ -0,0 +1,147 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_graphviz.md")]
+
+// Mod section
+mod writing_graphviz_for_association;
+mod writing_graphviz_for_class;
+mod writing_graphviz_for_package;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+
+/// Sub-folder of the output folder used by the Graphviz writing
+pub const GRAPHVIZ_FOLDER: &str = "graphviz";
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Graphviz backend (package import graph, inheritance forest and association graph, in "graphviz" folder)
+pub struct GraphvizGenerator;
+
+impl Generator for GraphvizGenerator {
+    fn get_name(&self) -> &str {
+        "graphviz"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_graphviz(sink)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make Graphviz DOT files, in "graphviz" sub-folder
+    ///
+    /// - "package_import.dot" : package import graph, with load order
+    /// - "inheritance.dot" : inheritance forest, abstract classes highlighted
+    /// - "association.dot" : association graph between classes
+    pub fn write_graphviz(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        // Package import graph
+        let loaded_packages = self.get_package_in_order();
+        let mut packages = String::new();
+        let mut imports = String::new();
+        for (order, label) in &self.importing_order {
+            let pckg = loaded_packages
+                .get(label)
+                .ok_or(anyhow::format_err!("Package \"{}\" not loaded", label))?;
+            let package = pckg.get_json();
+            packages.push_str(&package.get_graphviz_node(*order));
+            imports.push_str(&package.get_graphviz_import()?);
+        }
+        self.write_graphviz_file(
+            sink,
+            "package_import",
+            &format!("    rankdir=TB;\n    node [shape=folder];\n{packages}{imports}"),
+        );
+        info!("Generating Graphviz package import graph : Finished");
+
+        // Inheritance forest and association graph, classes grouped by package
+        let mut inheritance = String::new();
+        let mut inheritance_edges = String::new();
+        let mut association = String::new();
+        let mut association_edges = String::new();
+        for pckg in loaded_packages.values() {
+            let package = pckg.get_json();
+            let mut nodes = String::new();
+            for entity in package.owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Association(content) => {
+                        let r = content.get_graphviz_edge();
+                        association_edges.push_str(&catch_error_and_log(r, content)?);
+                    }
+                    EnumOwnedMember::Class(content) => {
+                        nodes.push_str(&catch_error_and_log(content.get_graphviz_node(), content)?);
+                        let r = content.get_graphviz_generalization();
+                        inheritance_edges.push_str(&catch_error_and_log(r, content)?);
+                    }
+                    _ => {}
+                }
+            }
+            let cluster = format!(
+                include_str!("template/graphviz_main_cluster.tmpl"),
+                cluster_name = package.lowercase_name,
+                label = package.xmi_id.get_package_id(),
+                nodes = nodes,
+            );
+            inheritance.push_str(&cluster);
+            association.push_str(&cluster);
+        }
+        self.write_graphviz_file(sink,
+            "inheritance",
+            &format!(
+                "    rankdir=BT;\n    node [shape=box];\n    edge [arrowhead=empty];\n{inheritance}{inheritance_edges}"
+            ),
+        );
+        info!("Generating Graphviz inheritance forest : Finished");
+        self.write_graphviz_file(
+            sink,
+            "association",
+            &format!("    node [shape=box];\n{association}{association_edges}"),
+        );
+        info!("Generating Graphviz association graph : Finished");
+        Ok(())
+    }
+
+    /// Write a "digraph" in "{name}.dot" file
+    fn write_graphviz_file(&self, sink: &dyn WrittingPath, name: &str, content: &str) {
+        let (_, mut wrt) = sink.get_backend_file(GRAPHVIZ_FOLDER, &format!("{}.dot", name));
+        let _ = write!(
+            wrt,
+            include_str!("template/graphviz_main_file.tmpl"),
+            name = name,
+            content = content,
+        );
+    }
+}
@@ -0,0 +1,59 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFAssociation {
+    /// Edge between the classes of the member ends, with role name and multiplicity at each end
+    ///
+    /// Composition diamond is on the side of the whole (the end opposite to the "isComposite" end)
+    pub fn get_graphviz_edge(&self) -> Result<String, anyhow::Error> {
+        let (end_a, end_b) = self.get_member_end()?;
+        let arrow = if end_a.is_composite {
+            "dir=forward, arrowhead=diamond"
+        } else if end_b.is_composite {
+            "dir=back, arrowtail=diamond"
+        } else {
+            "dir=none"
+        };
+
+        Ok(format!(
+            "    \"{}\" -> \"{}\" [label=\"{}\", taillabel=\"{} {}\", headlabel=\"{} {}\", {}];\n",
+            end_a.get_class_type()?.xmi_id.label()?,
+            end_b.get_class_type()?.xmi_id.label()?,
+            self.model_name,
+            end_a.get_metamodel_name(),
+            end_a.get_multiplicity(),
+            end_b.get_metamodel_name(),
+            end_b.get_multiplicity(),
+            arrow,
+        ))
+    }
+}
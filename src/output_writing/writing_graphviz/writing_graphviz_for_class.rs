@@ -0,0 +1,61 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// Node of the class (abstract class in italic, grey filled)
+    pub fn get_graphviz_node(&self) -> Result<String, anyhow::Error> {
+        let style = if self.is_abstract {
+            ", style=filled, fillcolor=lightgrey, fontname=\"Helvetica-Oblique\""
+        } else {
+            ""
+        };
+        Ok(format!(
+            "        \"{}\" [label=\"{}\"{}];\n",
+            self.xmi_id.label()?,
+            self.model_name,
+            style
+        ))
+    }
+
+    /// Edges to the super classes ("super_class" and "super_class_link")
+    pub fn get_graphviz_generalization(&self) -> Result<String, anyhow::Error> {
+        let mut result = String::new();
+        for label in self.get_super_class()?.keys() {
+            result.push_str(&format!(
+                "    \"{}\" -> \"{}\";\n",
+                self.xmi_id.label()?,
+                label
+            ));
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,59 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFPackage {
+    /// Node of the package, with its load order
+    pub fn get_graphviz_node(&self, order: usize) -> String {
+        format!(
+            "    \"{}\" [label=\"{}. {}\\n{}\"];\n",
+            self.xmi_id.get_package_id(),
+            order,
+            self.xmi_id.get_package_id(),
+            self.uri
+        )
+    }
+
+    /// Edges to the imported packages
+    pub fn get_graphviz_import(&self) -> Result<String, anyhow::Error> {
+        let mut result = String::new();
+        for package_import in self.package_import.values() {
+            let EnumPackageImport::PackageImport(content) = package_import;
+            let EnumImportedPackage::ImportedPackage(link) = &content.imported_package;
+            result.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"import\"];\n",
+                self.xmi_id.get_package_id(),
+                link.href.get_package_id()
+            ));
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,157 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_json_schema.md")]
+
+// Mod section
+mod writing_json_schema_for_class;
+mod writing_json_schema_for_datatype;
+mod writing_json_schema_for_enumeration;
+mod writing_json_schema_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use serde_json::{json, Map, Value};
+
+/// Sub-folder of the output folder used by the JSON Schema writing
+pub const JSON_SCHEMA_FOLDER: &str = "json_schema";
+
+/// JSON Schema dialect of the bundles
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Name of the schema bundle of a package
+pub fn get_json_schema_file_name(package_lowercase_name: &str) -> String {
+    format!("{}.schema.json", package_lowercase_name)
+}
+
+impl EnumCMOF {
+    /// "$ref" to the definition of the object, local or in the bundle of another package
+    pub fn get_json_schema_ref(
+        &self,
+        package_lowercase_name: &str,
+    ) -> Result<Value, anyhow::Error> {
+        let (xmi_id, model_name) = match self {
+            EnumCMOF::CMOFClass(c) => (&c.xmi_id, &c.model_name),
+            EnumCMOF::CMOFDataType(c) => (&c.xmi_id, &c.model_name),
+            EnumCMOF::CMOFEnumeration(c) => (&c.xmi_id, &c.model_name),
+            _ => {
+                return Err(anyhow::format_err!(
+                    "No JSON Schema definition for \"{}\"",
+                    self.label()?
+                ));
+            }
+        };
+        let target_package = xmi_id.get_package_id().to_case(Case::Snake);
+        let file_name = if target_package == package_lowercase_name {
+            String::new()
+        } else {
+            get_json_schema_file_name(&target_package)
+        };
+        Ok(json!({ "$ref": format!("{}#/$defs/{}", file_name, model_name) }))
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// JSON Schema backend (one schema by package, in "json_schema" folder)
+pub struct JsonSchemaGenerator;
+
+impl Generator for JsonSchemaGenerator {
+    fn get_name(&self) -> &str {
+        "json_schema"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_json_schema(sink)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make JSON Schema bundles (one by package), in "json_schema" sub-folder
+    pub fn write_json_schema(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let enumeration_default_values = read_enumeration_default_values()?;
+        let json_schema_type_conversion = read_json_schema_type_conversion()?;
+
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating JSON Schema for \"{label}\" : START");
+            let package = pckg.get_json();
+            let mut definitions: Map<String, Value> = Map::new();
+            for entity in package.owned_member.values() {
+                let definition = match entity {
+                    EnumOwnedMember::Association(_) => {
+                        // Associations are written as properties of their member ends
+                        continue;
+                    }
+                    EnumOwnedMember::Class(content) => {
+                        let r = content
+                            .get_json_schema(&package.lowercase_name, &json_schema_type_conversion);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::DataType(content) => {
+                        let r = content
+                            .get_json_schema(&package.lowercase_name, &json_schema_type_conversion);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::Enumeration(content) => {
+                        let r = content.get_json_schema(&enumeration_default_values);
+                        catch_error_and_log(r, content)?
+                    }
+                    EnumOwnedMember::PrimitiveType(_) => {
+                        // Primitive types are converted as JSON type
+                        continue;
+                    }
+                };
+                definitions.insert(entity.get_model_name(), definition);
+            }
+
+            let bundle = json!({
+                "$schema": JSON_SCHEMA_DIALECT,
+                "$id": get_json_schema_file_name(&package.lowercase_name),
+                "title": package.xmi_id.get_package_id(),
+                "description": package.uri,
+                "$defs": definitions,
+            });
+            let file_name = get_json_schema_file_name(&package.lowercase_name);
+            let (_, mut wrt) = sink.get_backend_file(JSON_SCHEMA_FOLDER, &file_name);
+            let _ = writeln!(wrt, "{}", serde_json::to_string_pretty(&bundle)?);
+            info!("Generating JSON Schema for \"{label}\" : Finished");
+        }
+        Ok(())
+    }
+}
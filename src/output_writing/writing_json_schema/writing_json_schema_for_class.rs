@@ -0,0 +1,84 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_json_schema::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+use serde_json::{json, Map, Value};
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// JSON Schema definition of the class
+    ///
+    /// Super classes are combined with "allOf", before the own properties of the class
+    pub fn get_json_schema(
+        &self,
+        package_lowercase_name: &str,
+        json_schema_type_conversion: &JsonSchemaTypeConversion,
+    ) -> Result<Value, anyhow::Error> {
+        let mut properties = Map::new();
+        let mut required: Vec<String> = Vec::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            properties.insert(
+                content.get_metamodel_name(),
+                content.get_json_schema(package_lowercase_name, json_schema_type_conversion)?,
+            );
+            if content.is_json_schema_required() {
+                required.push(content.get_metamodel_name());
+            }
+        }
+        let mut own = Map::new();
+        own.insert(String::from("type"), json!("object"));
+        own.insert(String::from("properties"), Value::Object(properties));
+        if !required.is_empty() {
+            own.insert(String::from("required"), json!(required));
+        }
+
+        let mut result = Map::new();
+        result.insert(String::from("title"), json!(self.model_name));
+        result.insert(String::from("description"), json!(self.xmi_id.label()?));
+        if self.is_abstract {
+            result.insert(String::from("$comment"), json!("Abstract class"));
+        }
+
+        let super_class = self.get_super_class()?;
+        if super_class.is_empty() {
+            result.append(&mut own);
+        } else {
+            let mut all_of: Vec<Value> = Vec::new();
+            for class in super_class.values() {
+                let object = EnumCMOF::CMOFClass(get_object_as_class(class)?);
+                all_of.push(object.get_json_schema_ref(package_lowercase_name)?);
+            }
+            all_of.push(Value::Object(own));
+            result.insert(String::from("allOf"), json!(all_of));
+        }
+        Ok(Value::Object(result))
+    }
+}
@@ -0,0 +1,61 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+use serde_json::{json, Value};
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFEnumeration {
+    /// JSON Schema definition of the enumeration (string with "enum" and "default")
+    pub fn get_json_schema(
+        &self,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<Value, anyhow::Error> {
+        let mut values: Vec<String> = Vec::new();
+        let mut default: Option<String> = None;
+        for literal in self.get_all_literal()? {
+            if literal.is_default(enumeration_default_values)? {
+                default = Some(literal.litteral_designation.clone());
+            }
+            values.push(literal.litteral_designation.clone());
+        }
+
+        let mut result = json!({
+            "title": self.model_name,
+            "description": self.xmi_id.label()?,
+            "type": "string",
+            "enum": values,
+        });
+        if let Some(default) = default {
+            result["default"] = json!(default);
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,115 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_json_schema::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+use serde_json::{json, Map, Value};
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Schema of the property value
+    ///
+    /// - Primitive type as JSON type, using "json_schema_type_conversion.json"
+    /// - Enumeration, DataType and Class with composition as "$ref" to the definition
+    /// - Class without composition as reference (string identifier)
+    /// - Array if "upper > 1", with "minItems" and "maxItems"
+    pub fn get_json_schema(
+        &self,
+        package_lowercase_name: &str,
+        json_schema_type_conversion: &JsonSchemaTypeConversion,
+    ) -> Result<Value, anyhow::Error> {
+        let object = self.get_type()?.upgrade()?;
+        let mut content = match &object {
+            EnumCMOF::CMOFClass(c) => {
+                if self.is_composite {
+                    object.get_json_schema_ref(package_lowercase_name)?
+                } else {
+                    json!({
+                        "type": "string",
+                        "description": format!("Reference to {}", c.xmi_id.label()?),
+                    })
+                }
+            }
+            EnumCMOF::CMOFDataType(_) | EnumCMOF::CMOFEnumeration(_) => {
+                object.get_json_schema_ref(package_lowercase_name)?
+            }
+            EnumCMOF::CMOFPrimitiveType(c) => {
+                let json_type =
+                    json_schema_type_conversion
+                        .get(&c.model_name)
+                        .ok_or(anyhow::format_err!(
+                            "No JSON Schema type conversion for \"{}\"",
+                            c.model_name
+                        ))?;
+                json!({ "type": json_type })
+            }
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        };
+
+        // Default value, typed if possible ("0", "true", ...)
+        if let (Some(default), Value::Object(map)) = (&self.default, &mut content) {
+            let value = match object {
+                EnumCMOF::CMOFPrimitiveType(_) => {
+                    serde_json::from_str(default).unwrap_or(Value::String(default.clone()))
+                }
+                EnumCMOF::CMOFEnumeration(c) => match c.get_literal_by_designation(default)? {
+                    Some(literal) => Value::String(literal.litteral_designation.clone()),
+                    None => Value::String(default.clone()),
+                },
+                _ => Value::String(default.clone()),
+            };
+            map.insert(String::from("default"), value);
+        }
+
+        if self.upper > infinitable::Finite(1) {
+            let mut array = Map::new();
+            array.insert(String::from("type"), json!("array"));
+            array.insert(String::from("items"), content);
+            if self.lower > 0 {
+                array.insert(String::from("minItems"), json!(self.lower));
+            }
+            if let infinitable::Finite(upper) = self.upper {
+                array.insert(String::from("maxItems"), json!(upper));
+            }
+            Ok(Value::Object(array))
+        } else {
+            Ok(content)
+        }
+    }
+
+    /// Check if the property is in "required" list ("lower >= 1")
+    pub fn is_json_schema_required(&self) -> bool {
+        self.lower >= 1
+    }
+}
@@ -25,6 +25,9 @@ use crate::cmof_loader::*;
 use crate::custom_file_tools::*;
 use crate::custom_log_tools::*;
 
+use crate::output_writing::writing_entity::{
+    LibContext, MemberContext, PackageContext, ENTITY_FOLDER,
+};
 use crate::output_writing::*;
 
 use ::xmi_tools::XMIIdentity;
@@ -38,62 +41,64 @@ use ::xmi_tools::XMIIdentity;
 // ####################################################################################################
 
 impl LoadingTracker {
-    /// Make lib.rs from scratch and package
-    pub fn write_lib_file(&mut self) -> Result<(), anyhow::Error> {
-        // Get folder and file
-        let (_, mut writer) = self.get_project_lib_file();
-
-        // Part 1 : Head of lib.rs, using template
-        let _ = writeln!(
-            writer,
-            include_str!("template/lib_part_1_common.tmpl"),
-            folder_name = self.get_output_folder(),
-        );
-
-        // Part 2 : entities part of lib.rs
+    /// Make mod.rs of "entities" module and of its package modules, using "entity_mod.jinja" and "entity_package_mod.jinja"
+    pub fn write_lib_file(
+        &self,
+        sink: &dyn WrittingPath,
+        templates: &TemplateSet,
+    ) -> Result<(), anyhow::Error> {
+        let folder_name = self.get_input_folder_name();
+
+        // One mod.rs by package
+        let mut packages = Vec::new();
         for (label, package) in self.get_package_in_order() {
             // Logs
-            debug!("Generating \"lib.rs\" from \"{label}\" : START",);
+            debug!("Generating \"mod.rs\" of package \"{label}\" : START",);
 
-            // Writting for each entities, using template
+            // Context for each entities
+            let mut members = Vec::new();
             for (_, entity) in &package.get_json().owned_member {
-                match entity {
-                    EnumOwnedMember::Association(content) => {
-                        let t = content.get_xmi_label()?;
-                        trace!("Start : 'write_lib_file' for {}", t);
-                        content.wrt_lib_file_level(&mut writer)?;
-                        trace!("End : 'write_lib_file' for {}", t);
-                    }
-                    EnumOwnedMember::Class(content) => {
-                        let t = content.get_xmi_label()?;
-                        trace!("Start : 'write_lib_file' for {}", t);
-                        content.wrt_lib_file_level(&mut writer)?;
-                        trace!("End : 'write_lib_file' for {}", t);
-                    }
-                    EnumOwnedMember::DataType(content) => {
-                        let t = content.get_xmi_label()?;
-                        trace!("Start : 'write_lib_file' for {}", t);
-                        content.wrt_lib_file_level(&mut writer)?;
-                        trace!("End : 'write_lib_file' for {}", t);
-                    }
-                    EnumOwnedMember::Enumeration(content) => {
-                        let t = content.get_xmi_label()?;
-                        trace!("Start : 'write_lib_file' for {}", t);
-                        content.wrt_lib_file_level(&mut writer)?;
-                        trace!("End : 'write_lib_file' for {}", t);
-                    }
-                    EnumOwnedMember::PrimitiveType(content) => {
-                        let t = content.get_xmi_label()?;
-                        trace!("Start : 'write_lib_file' for {}", t);
-                        content.wrt_lib_file_level(&mut writer)?;
-                        trace!("End : 'write_lib_file' for {}", t);
-                    }
-                }
+                let member = match entity {
+                    EnumOwnedMember::Association(content) => content.get_lib_member()?,
+                    EnumOwnedMember::Class(content) => content.get_lib_member()?,
+                    EnumOwnedMember::DataType(content) => content.get_lib_member()?,
+                    EnumOwnedMember::Enumeration(content) => content.get_lib_member()?,
+                    EnumOwnedMember::PrimitiveType(content) => content.get_lib_member()?,
+                };
+                trace!("'write_lib_file' for {}", entity.get_xmi_label()?);
+                members.extend(member);
             }
 
+            // Writting, using template
+            let context = PackageContext {
+                folder_name: folder_name.clone(),
+                name: package.get_json().lowercase_name.clone(),
+                uri: package.get_json().uri.clone(),
+                members,
+            };
+            let (_, mut writer) = sink.get_package_file(&context.name);
+            let _ = write!(
+                writer,
+                "{}",
+                templates.render("entity_package_mod.jinja", &context)?
+            );
+            packages.push(context);
+
             // Logs
-            info!("Generating \"lib.rs\" from \"{label}\" : Finished");
+            info!("Generating \"mod.rs\" of package \"{label}\" : Finished");
         }
+
+        // Writting entities/mod.rs, using template
+        let (_, mut writer) = sink.get_module_file(ENTITY_FOLDER, "mod.rs");
+        let context = LibContext {
+            folder_name,
+            packages,
+        };
+        let _ = write!(
+            writer,
+            "{}",
+            templates.render("entity_mod.jinja", &context)?
+        );
         Ok(())
     }
 }
@@ -104,18 +109,34 @@ impl LoadingTracker {
 //
 // ####################################################################################################
 
+/// Member of __entities/${package}/mod.rs__, for "entity_package_mod.jinja"
+fn new_lib_member(
+    kind: &str,
+    package_lowercase_name: &str,
+    model_name: &str,
+    table_name: &str,
+) -> MemberContext {
+    MemberContext::new(
+        kind,
+        package_lowercase_name,
+        model_name,
+        table_name,
+        package_lowercase_name,
+    )
+}
+
 impl WritingLibFile for CMOFAssociation {
-    fn wrt_lib_file_level(&self, writer: &mut File) -> Result<(), anyhow::Error> {
+    fn get_lib_member(&self) -> Result<Option<MemberContext>, anyhow::Error> {
         // Only for "Many to Many"
         let association = self.get_association_relation()?;
         if matches!(association, Relation::ManyToManyRelation(_)) {
             if !association.is_self_referencing()? {
-                let _ = writeln!(
-                    writer,
-                    include_str!("template/lib_part_2_association.tmpl"),
-                    model_name = self.model_name,
-                    table_name = self.table_name,
-                );
+                return Ok(Some(new_lib_member(
+                    "Association",
+                    &self.package_lowercase_name,
+                    &self.model_name,
+                    &self.table_name,
+                )));
             } else {
                 warn!(
                     "Need association lib implement for \"{}\" because it's referencin itself",
@@ -123,54 +144,50 @@ impl WritingLibFile for CMOFAssociation {
                 )
             }
         };
-        Ok(())
+        Ok(None)
     }
 }
 
 impl WritingLibFile for CMOFClass {
-    fn wrt_lib_file_level(&self, writer: &mut File) -> Result<(), anyhow::Error> {
-        let _ = writeln!(
-            writer,
-            include_str!("template/lib_part_2_class.tmpl"),
-            model_name = self.model_name,
-            table_name = self.table_name,
-        );
-        Ok(())
+    fn get_lib_member(&self) -> Result<Option<MemberContext>, anyhow::Error> {
+        Ok(Some(new_lib_member(
+            "Class",
+            &self.package_lowercase_name,
+            &self.model_name,
+            &self.table_name,
+        )))
     }
 }
 
 impl WritingLibFile for CMOFDataType {
-    fn wrt_lib_file_level(&self, writer: &mut File) -> Result<(), anyhow::Error> {
-        let _ = writeln!(
-            writer,
-            include_str!("template/lib_part_2_datatype.tmpl"),
-            model_name = self.model_name,
-            table_name = self.table_name,
-        );
-        Ok(())
+    fn get_lib_member(&self) -> Result<Option<MemberContext>, anyhow::Error> {
+        Ok(Some(new_lib_member(
+            "DataType",
+            &self.package_lowercase_name,
+            &self.model_name,
+            &self.table_name,
+        )))
     }
 }
 
 impl WritingLibFile for CMOFEnumeration {
-    fn wrt_lib_file_level(&self, writer: &mut File) -> Result<(), anyhow::Error> {
-        let _ = writeln!(
-            writer,
-            include_str!("template/lib_part_2_enumeration.tmpl"),
-            model_name = self.model_name,
-            table_name = self.table_name,
-        );
-        Ok(())
+    fn get_lib_member(&self) -> Result<Option<MemberContext>, anyhow::Error> {
+        Ok(Some(new_lib_member(
+            "Enumeration",
+            &self.package_lowercase_name,
+            &self.model_name,
+            &self.table_name,
+        )))
     }
 }
 
 impl WritingLibFile for CMOFPrimitiveType {
-    fn wrt_lib_file_level(&self, writer: &mut File) -> Result<(), anyhow::Error> {
-        let _ = writeln!(
-            writer,
-            include_str!("template/lib_part_2_primitive_type.tmpl"),
-            model_name = self.model_name,
-            table_name = self.table_name,
-        );
-        Ok(())
+    fn get_lib_member(&self) -> Result<Option<MemberContext>, anyhow::Error> {
+        Ok(Some(new_lib_member(
+            "PrimitiveType",
+            &self.package_lowercase_name,
+            &self.model_name,
+            &self.table_name,
+        )))
     }
 }
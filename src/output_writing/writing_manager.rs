@@ -24,10 +24,18 @@ If not, see <https://www.gnu.org/licenses/>.
 use crate::cmof_loader::*;
 use crate::custom_file_tools::*;
 use crate::custom_log_tools::*;
+use crate::output_result_manager::{get_content_hash, read_folder_files, GenerationSources};
+use crate::output_writing::writing_entity::{MemberContext, ENTITY_FOLDER};
+use crate::output_writing::METAMODEL_EXTENSION_FOLDER;
 
 // Dependencies section
+use std::cell::RefCell;
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 
+/// Sub-folder of the output folder (crate root) for the source files
+pub const SOURCE_FOLDER: &str = "src";
+
 // ####################################################################################################
 //
 // ###################################### WrittingPath ################################################
@@ -36,6 +44,16 @@ use std::fmt::Debug;
 
 /// Trait providing full homogenous path to [`LoadingTracker`]
 pub trait WrittingPath {
+    /// Get ${file_name} file at the root of the output crate
+    ///
+    /// Example --> ${output_folder}/Cargo.toml
+    fn get_crate_file(&self, file_name: &str) -> (PathBuf, File);
+
+    /// Get ${file_name} file in the source folder of the output crate
+    ///
+    /// Example --> ${output_folder}/src/prelude.rs
+    fn get_source_file(&self, file_name: &str) -> (PathBuf, File);
+
     /// Get lib.rs file for the LoadingTracker
     ///
     /// Example --> ${output_folder}/src/lib.rs
@@ -43,25 +61,200 @@ pub trait WrittingPath {
 
     /// Get ${package}.rs file for a object of a package
     ///
-    /// Example for font object of dc package --> ${output_folder}/src/dc/font.rs
+    /// Example for font object of dc package --> ${output_folder}/src/entities/dc/font.rs
     fn get_object_file(&self, object: &EnumOwnedMember) -> (PathBuf, File);
+
+    /// Get mod.rs file of the module of a package, for its objects
+    ///
+    /// Example for dc package --> ${output_folder}/src/entities/dc/mod.rs
+    fn get_package_file(&self, package_name: &str) -> (PathBuf, File);
+
+    /// Get ${file_name} file in the dedicated sub-folder of a writing backend
+    ///
+    /// Example for XSD file of dc package --> ${output_folder}/xsd/dc.xsd
+    fn get_backend_file(&self, backend_folder: &str, file_name: &str) -> (PathBuf, File);
+
+    /// Get ${file_name} file in the module of a writing backend (sub-folder of the source folder)
+    ///
+    /// Example for mod.rs of domain model backend --> ${output_folder}/src/domain_model/mod.rs
+    fn get_module_file(&self, module_name: &str, file_name: &str) -> (PathBuf, File);
+
+    /// Get ${package}/${object}.rs file for a object of a package, in the module of a writing backend
+    ///
+    /// Example for font object of domain model backend --> ${output_folder}/src/domain_model/dc/font.rs
+    fn get_object_module_file(
+        &self,
+        module_name: &str,
+        object: &EnumOwnedMember,
+    ) -> (PathBuf, File);
+
+    /// Get mod.rs file of the module of a package, in the module of a writing backend
+    ///
+    /// Example for dc package of domain model backend --> ${output_folder}/src/domain_model/dc/mod.rs
+    fn get_package_module_file(&self, module_name: &str, package_name: &str) -> (PathBuf, File);
+
+    /// Record the label of a metamodel element written in a file, for the manifest of the export
+    fn add_file_source(&self, file: &Path, label: &str);
+
+    /// Check if the writing is a dry-run (input files, as lock files, must not be changed)
+    fn is_dry_run(&self) -> bool;
+
+    /// Root folder of the written files (root of the output crate)
+    fn get_output_folder(&self) -> PathBuf;
 }
 
-impl WrittingPath for LoadingTracker {
-    fn get_project_lib_file(&self) -> (PathBuf, File) {
+/// Files written by a run of the generators : output folder, dry-run flag, and labels of the elements written in each file
+///
+/// The model ([`LoadingTracker`]) is only read by the generators, all the state of the run is in the sink
+#[derive(Debug)]
+pub struct OutputSink {
+    /// Root folder of the written files
+    output_folder: PathBuf,
+    /// Dry-run : the output is only compared with the result, and generators don't change their input files (lock files)
+    dry_run: bool,
+    /// Labels of the elements written in each output file (path relative to output folder), for the manifest of the export
+    file_sources: RefCell<BTreeMap<PathBuf, BTreeSet<String>>>,
+}
+
+impl OutputSink {
+    /// Create new instance, writing in the output folder
+    pub fn new(output_folder: &Path, dry_run: bool) -> Self {
+        OutputSink {
+            output_folder: output_folder.to_path_buf(),
+            dry_run,
+            file_sources: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Hashes of the input files (metamodel files of the input folder and extension files) and sources of the written files, for the manifest of the export
+    pub fn get_generation_sources(
+        &self,
+        input_folder: &Path,
+    ) -> Result<GenerationSources, anyhow::Error> {
+        let mut inputs = BTreeMap::new();
+        let input_folder_name = input_folder.file_name().unwrap_or_default();
+        let input_folders = [
+            (PathBuf::from(input_folder_name), input_folder.to_path_buf()),
+            (
+                PathBuf::from(METAMODEL_EXTENSION_FOLDER),
+                PathBuf::from(METAMODEL_EXTENSION_FOLDER),
+            ),
+        ];
+        for (name, folder) in input_folders {
+            for (path, content) in read_folder_files(&folder)? {
+                inputs.insert(name.join(path), get_content_hash(&content));
+            }
+        }
+        Ok(GenerationSources {
+            inputs,
+            file_sources: self.file_sources.borrow().clone(),
+        })
+    }
+}
+
+impl WrittingPath for OutputSink {
+    fn get_crate_file(&self, file_name: &str) -> (PathBuf, File) {
         // Calculate path
-        let mut file_name = self.get_output_folder();
-        file_name.push("lib.rs");
+        let mut file_name_path = self.get_output_folder();
+        file_name_path.push(file_name);
         // Create file
-        (file_name.clone(), file_name.write_new_file().unwrap())
+        (
+            file_name_path.clone(),
+            file_name_path.write_new_file().unwrap(),
+        )
+    }
+
+    fn get_source_file(&self, file_name: &str) -> (PathBuf, File) {
+        // Calculate path
+        let mut folder_name = self.get_output_folder();
+        folder_name.push(SOURCE_FOLDER);
+        folder_name.create_folder().unwrap();
+        let mut file_name_path = folder_name;
+        file_name_path.push(file_name);
+        // Create file
+        (
+            file_name_path.clone(),
+            file_name_path.write_new_file().unwrap(),
+        )
+    }
+
+    fn get_project_lib_file(&self) -> (PathBuf, File) {
+        self.get_source_file("lib.rs")
     }
 
     fn get_object_file(&self, object: &EnumOwnedMember) -> (PathBuf, File) {
+        self.get_object_module_file(ENTITY_FOLDER, object)
+    }
+
+    fn get_package_file(&self, package_name: &str) -> (PathBuf, File) {
+        self.get_package_module_file(ENTITY_FOLDER, package_name)
+    }
+
+    fn get_backend_file(&self, backend_folder: &str, file_name: &str) -> (PathBuf, File) {
         // Calculate path
-        let mut file_name = self.get_output_folder();
-        file_name.push(object.get_table_name() + ".rs");
+        let mut folder_name = self.get_output_folder();
+        folder_name.push(backend_folder);
+        folder_name.create_folder().unwrap();
+        let mut file_name_path = folder_name;
+        file_name_path.push(file_name);
         // Create file
-        (file_name.clone(), file_name.write_new_file().unwrap())
+        (
+            file_name_path.clone(),
+            file_name_path.write_new_file().unwrap(),
+        )
+    }
+
+    fn get_module_file(&self, module_name: &str, file_name: &str) -> (PathBuf, File) {
+        // Calculate path
+        let mut folder_name = self.get_output_folder();
+        folder_name.push(SOURCE_FOLDER);
+        folder_name.push(module_name);
+        folder_name.create_folder().unwrap();
+        let mut file_name_path = folder_name;
+        file_name_path.push(file_name);
+        // Create file
+        (
+            file_name_path.clone(),
+            file_name_path.write_new_file().unwrap(),
+        )
+    }
+
+    fn get_object_module_file(
+        &self,
+        module_name: &str,
+        object: &EnumOwnedMember,
+    ) -> (PathBuf, File) {
+        let (package_name, object_name) = object.get_entity_module();
+        let mut module_folder = PathBuf::from(module_name);
+        module_folder.push(package_name);
+        let (path, file) =
+            self.get_module_file(&module_folder.display().to_string(), &(object_name + ".rs"));
+        self.add_file_source(&path, &object.get_technical_name());
+        (path, file)
+    }
+
+    fn get_package_module_file(&self, module_name: &str, package_name: &str) -> (PathBuf, File) {
+        let mut module_folder = PathBuf::from(module_name);
+        module_folder.push(package_name);
+        self.get_module_file(&module_folder.display().to_string(), "mod.rs")
+    }
+
+    fn add_file_source(&self, file: &Path, label: &str) {
+        let output_folder = self.get_output_folder();
+        let relative_path = file.strip_prefix(&output_folder).unwrap_or(file);
+        self.file_sources
+            .borrow_mut()
+            .entry(relative_path.to_path_buf())
+            .or_default()
+            .insert(label.to_string());
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    fn get_output_folder(&self) -> PathBuf {
+        self.output_folder.clone()
     }
 }
 
@@ -71,10 +264,10 @@ impl WrittingPath for LoadingTracker {
 //
 // ####################################################################################################
 
-/// Trait for writting __lib.rs__ file from sub-element of [`LoadingPackage`]
+/// Trait for writting __entities/mod.rs__ file from sub-element of [`LoadingPackage`]
 pub trait WritingLibFile: Debug {
-    /// Writting __lib.rs__ file from sub-element of [`LoadingPackage`]
-    fn wrt_lib_file_level(&self, wrt: &mut File) -> Result<(), anyhow::Error>;
+    /// Member of __entities/mod.rs__ file (context of "entity_mod.jinja"), None if the element isn't exported
+    fn get_lib_member(&self) -> Result<Option<MemberContext>, anyhow::Error>;
 }
 
 /// Trait for writting __${owned_member}.rs__ file from [`EnumOwnedMember`] element of [`LoadingPackage`]
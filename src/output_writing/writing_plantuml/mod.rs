@@ -0,0 +1,344 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_plantuml.md")]
+
+// Mod section
+mod writing_plantuml_for_association;
+mod writing_plantuml_for_class;
+mod writing_plantuml_for_datatype;
+mod writing_plantuml_for_enumeration;
+mod writing_plantuml_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use std::collections::BTreeSet;
+
+/// Sub-folder of the output folder used by the PlantUML writing
+pub const PLANTUML_FOLDER: &str = "plantuml";
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// PlantUML neighbourhood diagrams backend, for a list of classes (label and depth)
+pub struct PlantumlNeighbourhoodGenerator {
+    /// Label of each class, with the depth of its neighbourhood
+    classes: Vec<(String, usize)>,
+}
+
+impl PlantumlNeighbourhoodGenerator {
+    /// Instanciate the generator for a list of classes (example : `[("BPMN20-Process", 1)]`)
+    pub fn new(classes: &[(&str, usize)]) -> Self {
+        PlantumlNeighbourhoodGenerator {
+            classes: classes
+                .iter()
+                .map(|(label, depth)| (label.to_string(), *depth))
+                .collect(),
+        }
+    }
+}
+
+impl Generator for PlantumlNeighbourhoodGenerator {
+    fn get_name(&self) -> &str {
+        "plantuml_neighbourhood"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        for (class_label, depth) in &self.classes {
+            model.write_plantuml_neighbourhood(sink, class_label, *depth)?;
+        }
+        Ok(())
+    }
+}
+
+/// Shared content during the writing of a PlantUML diagram
+pub struct PlantumlContext<'a> {
+    /// Package of the diagram (no package for neighbourhood diagram, all names are qualified)
+    pub package_id: Option<String>,
+    /// PlantUML namespace of all packages (key : package id)
+    pub packages: &'a BTreeMap<String, String>,
+}
+
+impl PlantumlContext<'_> {
+    /// Name of an element, qualified by the namespace if not in the package of the diagram
+    pub fn get_qualified_name(
+        &self,
+        package_id: String,
+        model_name: &str,
+    ) -> Result<String, anyhow::Error> {
+        if self.package_id.as_ref() == Some(&package_id) {
+            return Ok(String::from(model_name));
+        }
+        let namespace = self.packages.get(&package_id).ok_or(anyhow::format_err!(
+            "No PlantUML namespace for \"{}\"",
+            package_id
+        ))?;
+        Ok(format!("{}.{}", namespace, model_name))
+    }
+}
+
+/// PlantUML notes of constraints (name, language and body), linked to the element
+pub fn get_plantuml_constraint(
+    owned_rule: &BTreeMap<String, EnumOwnedRule>,
+    name: &str,
+) -> Result<String, anyhow::Error> {
+    let mut result = String::new();
+    for rule in owned_rule.values() {
+        let EnumOwnedRule::Constraint(constraint) = rule;
+        let EnumSpecification::OpaqueExpression(specification) = &constraint.specification;
+        result.push_str(&format!(
+            include_str!("template/plantuml_main_note.tmpl"),
+            note_name = format!("N_{}", constraint.xmi_id.label()?.replace('-', "_")),
+            constraint_name = constraint.get_name(),
+            language = specification.language,
+            body = specification.body.trim().replace('\n', "\n  "),
+            name = name,
+        ));
+    }
+    Ok(result)
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// PlantUML backend (one class diagram by package, in "plantuml" folder)
+pub struct PlantumlGenerator;
+
+impl Generator for PlantumlGenerator {
+    fn get_name(&self) -> &str {
+        "plantuml"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_plantuml(sink)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make PlantUML class diagram files (one by package), in "plantuml" sub-folder
+    pub fn write_plantuml(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let packages = self.get_plantuml_namespaces();
+
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating PlantUML for \"{label}\" : START");
+            let package = pckg.get_json();
+            let context = PlantumlContext {
+                package_id: Some(package.xmi_id.get_package_id()),
+                packages: &packages,
+            };
+
+            let mut content = String::new();
+            let mut links = String::new();
+            for entity in package.owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Association(content_association) => {
+                        let r = content_association.get_plantuml_content(&context);
+                        links.push_str(&catch_error_and_log(r, content_association)?);
+                    }
+                    EnumOwnedMember::Class(content_class) => {
+                        let r = content_class.get_plantuml_content(&context);
+                        content.push_str(&catch_error_and_log(r, content_class)?);
+                        let r = content_class.get_plantuml_generalization(&context);
+                        for (_, line) in catch_error_and_log(r, content_class)? {
+                            links.push_str(&line);
+                        }
+                    }
+                    EnumOwnedMember::DataType(content_datatype) => {
+                        let r = content_datatype.get_plantuml_content(&context);
+                        content.push_str(&catch_error_and_log(r, content_datatype)?);
+                    }
+                    EnumOwnedMember::Enumeration(content_enumeration) => {
+                        let r = content_enumeration.get_plantuml_content();
+                        content.push_str(&catch_error_and_log(r, content_enumeration)?);
+                    }
+                    EnumOwnedMember::PrimitiveType(_) => {
+                        // Primitive types are only used as attribute types
+                        continue;
+                    }
+                }
+            }
+
+            let (_, mut wrt) =
+                sink.get_backend_file(PLANTUML_FOLDER, &format!("{}.puml", package.lowercase_name));
+            let _ = write!(
+                wrt,
+                include_str!("template/plantuml_main_file.tmpl"),
+                diagram_name = package.lowercase_name,
+                label = label,
+                content = content,
+                links = links,
+            );
+            info!("Generating PlantUML for \"{label}\" : Finished");
+        }
+        Ok(())
+    }
+
+    /// Make PlantUML class diagram of the neighbourhood of a class, in "plantuml" sub-folder
+    ///
+    /// Neighbours are super classes, sub-classes and associated classes, up to "depth" steps from the class
+    pub fn write_plantuml_neighbourhood(
+        &self,
+        sink: &dyn WrittingPath,
+        class_label: &str,
+        depth: usize,
+    ) -> Result<(), anyhow::Error> {
+        debug!("Generating PlantUML neighbourhood for \"{class_label}\" : START");
+        let packages = self.get_plantuml_namespaces();
+        let context = PlantumlContext {
+            package_id: None,
+            packages: &packages,
+        };
+
+        // All classes and associations of the loaded packages
+        let mut classes: BTreeMap<String, Rc<CMOFClass>> = BTreeMap::new();
+        let mut associations: Vec<Rc<CMOFAssociation>> = Vec::new();
+        for pckg in self.get_package_in_order().values() {
+            for entity in pckg.get_json().owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Association(content) => {
+                        associations.push(content.clone());
+                    }
+                    EnumOwnedMember::Class(content) => {
+                        classes.insert(content.xmi_id.label()?, content.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let root = classes.get(class_label).ok_or(anyhow::format_err!(
+            "No class \"{}\" for PlantUML neighbourhood",
+            class_label
+        ))?;
+
+        // Direct neighbours of each class
+        let mut neighbours: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for (label, class) in &classes {
+            for super_label in class.get_super_class()?.keys() {
+                Self::add_plantuml_neighbour(&mut neighbours, label, super_label);
+            }
+        }
+        for association in &associations {
+            let (label_a, label_b) = association.get_plantuml_end_class()?;
+            Self::add_plantuml_neighbour(&mut neighbours, &label_a, &label_b);
+        }
+
+        // Breadth-first search, up to "depth"
+        let mut selected: BTreeSet<String> = BTreeSet::from([String::from(class_label)]);
+        let mut frontier: BTreeSet<String> = selected.clone();
+        for _ in 0..depth {
+            let mut next: BTreeSet<String> = BTreeSet::new();
+            for label in &frontier {
+                for neighbour in neighbours.get(label).into_iter().flatten() {
+                    if selected.insert(neighbour.clone()) {
+                        next.insert(neighbour.clone());
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        let mut content = String::new();
+        let mut links = String::new();
+        for label in &selected {
+            let class = classes.get(label).ok_or(anyhow::format_err!(
+                "No class \"{}\" for PlantUML neighbourhood",
+                label
+            ))?;
+            let r = class.get_plantuml_content(&context);
+            content.push_str(&catch_error_and_log(r, class.as_ref())?);
+            let r = class.get_plantuml_generalization(&context);
+            for (super_label, line) in catch_error_and_log(r, class.as_ref())? {
+                if selected.contains(&super_label) {
+                    links.push_str(&line);
+                }
+            }
+        }
+        for association in &associations {
+            let (label_a, label_b) = association.get_plantuml_end_class()?;
+            if selected.contains(&label_a) && selected.contains(&label_b) {
+                let r = association.get_plantuml_content(&context);
+                links.push_str(&catch_error_and_log(r, association.as_ref())?);
+            }
+        }
+
+        let diagram_name = format!("neighbourhood_{}_{}", root.table_name, depth);
+        let (_, mut wrt) =
+            sink.get_backend_file(PLANTUML_FOLDER, &format!("{}.puml", diagram_name));
+        let _ = write!(
+            wrt,
+            include_str!("template/plantuml_main_file.tmpl"),
+            diagram_name = diagram_name,
+            label = format!("{} (depth {})", class_label, depth),
+            content = content,
+            links = links,
+        );
+        info!("Generating PlantUML neighbourhood for \"{class_label}\" : Finished");
+        Ok(())
+    }
+
+    /// PlantUML namespace of all packages (key : package id)
+    fn get_plantuml_namespaces(&self) -> BTreeMap<String, String> {
+        self.get_package_in_order()
+            .values()
+            .map(|pckg| {
+                let package = pckg.get_json();
+                (
+                    package.xmi_id.get_package_id(),
+                    package.lowercase_name.clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// Save "a" and "b" as neighbours, in both directions
+    fn add_plantuml_neighbour(
+        neighbours: &mut BTreeMap<String, BTreeSet<String>>,
+        label_a: &str,
+        label_b: &str,
+    ) {
+        neighbours
+            .entry(String::from(label_a))
+            .or_default()
+            .insert(String::from(label_b));
+        neighbours
+            .entry(String::from(label_b))
+            .or_default()
+            .insert(String::from(label_a));
+    }
+}
@@ -0,0 +1,70 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_plantuml::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFAssociation {
+    /// PlantUML association, with role name and multiplicity at each end
+    ///
+    /// Composition diamond is on the side of the whole (the end opposite to the "isComposite" end)
+    pub fn get_plantuml_content(&self, context: &PlantumlContext) -> Result<String, anyhow::Error> {
+        let (end_a, end_b) = self.get_member_end()?;
+        let (class_a, class_b) = (end_a.get_class_type()?, end_b.get_class_type()?);
+        let link = if end_a.is_composite {
+            "--*"
+        } else if end_b.is_composite {
+            "*--"
+        } else {
+            "--"
+        };
+
+        Ok(format!(
+            "{} \"{} {}\" {} \"{} {}\" {} : {}\n",
+            context.get_qualified_name(class_a.xmi_id.get_package_id(), &class_a.model_name)?,
+            end_a.get_metamodel_name(),
+            end_a.get_multiplicity(),
+            link,
+            end_b.get_metamodel_name(),
+            end_b.get_multiplicity(),
+            context.get_qualified_name(class_b.xmi_id.get_package_id(), &class_b.model_name)?,
+            self.model_name,
+        ))
+    }
+
+    /// Labels of the classes at the ends of the association
+    pub fn get_plantuml_end_class(&self) -> Result<(String, String), anyhow::Error> {
+        let (end_a, end_b) = self.get_member_end()?;
+        Ok((
+            end_a.get_class_type()?.xmi_id.label()?,
+            end_b.get_class_type()?.xmi_id.label()?,
+        ))
+    }
+}
@@ -0,0 +1,79 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_plantuml::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// PlantUML class (or abstract class) with its attributes, and its constraints as notes
+    ///
+    /// Properties of an association are written as association (see "CMOFAssociation")
+    pub fn get_plantuml_content(&self, context: &PlantumlContext) -> Result<String, anyhow::Error> {
+        let name = context.get_qualified_name(self.xmi_id.get_package_id(), &self.model_name)?;
+        let mut attributes = String::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            if content.association.is_none() {
+                attributes.push_str(&content.get_plantuml_attribute(context)?);
+            }
+        }
+
+        let mut result = format!(
+            include_str!("template/plantuml_main_class.tmpl"),
+            label = self.xmi_id.label()?,
+            kind = if self.is_abstract {
+                "abstract class"
+            } else {
+                "class"
+            },
+            name = name,
+            stereotype = "",
+            attributes = attributes,
+        );
+        result.push_str(&get_plantuml_constraint(&self.owned_rule, &name)?);
+        Ok(result)
+    }
+
+    /// PlantUML generalizations to the super classes (key : label of the super class)
+    pub fn get_plantuml_generalization(
+        &self,
+        context: &PlantumlContext,
+    ) -> Result<Vec<(String, String)>, anyhow::Error> {
+        let name = context.get_qualified_name(self.xmi_id.get_package_id(), &self.model_name)?;
+        let mut result: Vec<(String, String)> = Vec::new();
+        for (label, class) in self.get_super_class()? {
+            let class = get_object_as_class(class)?;
+            let super_name =
+                context.get_qualified_name(class.xmi_id.get_package_id(), &class.model_name)?;
+            result.push((label, format!("{} <|-- {}\n", super_name, name)));
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,55 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_plantuml::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFDataType {
+    /// PlantUML class with "dataType" stereotype, and its constraints as notes
+    pub fn get_plantuml_content(&self, context: &PlantumlContext) -> Result<String, anyhow::Error> {
+        let name = context.get_qualified_name(self.xmi_id.get_package_id(), &self.model_name)?;
+        let mut attributes = String::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            attributes.push_str(&content.get_plantuml_attribute(context)?);
+        }
+
+        let mut result = format!(
+            include_str!("template/plantuml_main_class.tmpl"),
+            label = self.xmi_id.label()?,
+            kind = "class",
+            name = name,
+            stereotype = " <<dataType>>",
+            attributes = attributes,
+        );
+        result.push_str(&get_plantuml_constraint(&self.owned_rule, &name)?);
+        Ok(result)
+    }
+}
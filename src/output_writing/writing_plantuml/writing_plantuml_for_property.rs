@@ -0,0 +1,71 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_plantuml::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// PlantUML attribute, with type, multiplicity and default value
+    pub fn get_plantuml_attribute(
+        &self,
+        context: &PlantumlContext,
+    ) -> Result<String, anyhow::Error> {
+        let type_name = match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => {
+                context.get_qualified_name(c.xmi_id.get_package_id(), &c.model_name)?
+            }
+            EnumCMOF::CMOFDataType(c) => {
+                context.get_qualified_name(c.xmi_id.get_package_id(), &c.model_name)?
+            }
+            EnumCMOF::CMOFEnumeration(c) => {
+                context.get_qualified_name(c.xmi_id.get_package_id(), &c.model_name)?
+            }
+            EnumCMOF::CMOFPrimitiveType(c) => c.model_name.clone(),
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        };
+        let default = match &self.default {
+            Some(value) => format!(" = {}", value),
+            None => String::new(),
+        };
+
+        Ok(format!(
+            "  +{} : {} [{}]{}\n",
+            self.get_metamodel_name(),
+            type_name,
+            self.get_multiplicity(),
+            default
+        ))
+    }
+}
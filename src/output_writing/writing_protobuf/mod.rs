@@ -0,0 +1,315 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_protobuf.md")]
+
+// Mod section
+mod writing_protobuf_field_number;
+mod writing_protobuf_for_class;
+mod writing_protobuf_for_datatype;
+mod writing_protobuf_for_enumeration;
+mod writing_protobuf_for_property;
+pub use writing_protobuf_field_number::ProtobufFieldNumbers;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use std::collections::BTreeSet;
+
+/// Sub-folder of the output folder used by the Protobuf writing
+pub const PROTOBUF_FOLDER: &str = "protobuf";
+
+/// Lock file of the field numbers, kept between generations
+pub const PROTOBUF_LOCK_FILE: &str = "metamodel_file_extension/protobuf_field_number.lock.json";
+
+/// Type used in "oneof" for sub-class of other packages (prevent circular import)
+pub const PROTOBUF_ANY: &str = "google.protobuf.Any";
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[derive(Clone, Debug)]
+/// Field of a message, before numbering
+pub struct ProtobufField {
+    /// Key of the field in the lock file (xmi label)
+    pub key: String,
+    /// Type of the field, with "repeated" or "optional" if needed
+    pub declaration: String,
+    /// Name of the field
+    pub name: String,
+}
+
+/// Shared content during the writing of the ".proto" file of a package
+pub struct ProtobufContext<'a> {
+    /// Package of the ".proto" file
+    pub package_id: String,
+    /// Protobuf package name of all packages (key : package id)
+    pub packages: &'a BTreeMap<String, String>,
+    /// Conversion of the primitive types
+    pub protobuf_type_conversion: &'a ProtobufTypeConversion,
+    /// Field numbers, from the lock file
+    pub field_numbers: &'a mut ProtobufFieldNumbers,
+    /// Files to import
+    pub imports: BTreeSet<String>,
+}
+
+impl ProtobufContext<'_> {
+    /// Protobuf package name of a package
+    pub fn get_package_name(&self, package_id: &str) -> Result<String, anyhow::Error> {
+        Ok(self
+            .packages
+            .get(package_id)
+            .ok_or(anyhow::format_err!(
+                "No Protobuf package for \"{}\"",
+                package_id
+            ))?
+            .clone())
+    }
+
+    /// Name of a message or enum, qualified if in other package (saving needed import)
+    pub fn get_qualified_name(
+        &mut self,
+        package_id: String,
+        model_name: &str,
+    ) -> Result<String, anyhow::Error> {
+        if package_id == self.package_id {
+            return Ok(String::from(model_name));
+        }
+        let package_name = self.get_package_name(&package_id)?;
+        self.imports.insert(format!("{}.proto", package_name));
+        Ok(format!("{}.{}", package_name, model_name))
+    }
+
+    /// Type of a primitive type, using "protobuf_type_conversion.json" (saving needed import)
+    pub fn get_primitive_type(&mut self, model_name: &str) -> Result<String, anyhow::Error> {
+        let result = self
+            .protobuf_type_conversion
+            .get(model_name)
+            .ok_or(anyhow::format_err!(
+                "No Protobuf type conversion for \"{}\"",
+                model_name
+            ))?
+            .clone();
+        self.add_well_known_import(&result);
+        Ok(result)
+    }
+
+    /// Import of well-known types of Protobuf ("google.protobuf.*")
+    pub fn add_well_known_import(&mut self, type_name: &str) {
+        let file = match type_name {
+            "google.protobuf.Any" => "google/protobuf/any.proto",
+            "google.protobuf.Struct" | "google.protobuf.Value" | "google.protobuf.ListValue" => {
+                "google/protobuf/struct.proto"
+            }
+            "google.protobuf.Timestamp" => "google/protobuf/timestamp.proto",
+            "google.protobuf.Duration" => "google/protobuf/duration.proto",
+            _ => return,
+        };
+        self.imports.insert(String::from(file));
+    }
+
+    /// Format fields with their numbers from the lock file, returning used numbers
+    ///
+    /// "message" is the key in the lock file, "first" the first number usable
+    pub fn format_fields(
+        &mut self,
+        message: &str,
+        fields: &[ProtobufField],
+        first: u32,
+        indent: &str,
+    ) -> (String, BTreeSet<u32>) {
+        let mut result = String::new();
+        let mut used: BTreeSet<u32> = BTreeSet::new();
+        for field in fields {
+            let number = self.field_numbers.get_number(message, &field.key, first);
+            used.insert(number);
+            result.push_str(&format!(
+                "{indent}{} {} = {}; // {}\n",
+                field.declaration, field.name, number, field.key
+            ));
+        }
+        (result, used)
+    }
+
+    /// "reserved" line, for numbers of the lock file not used anymore
+    pub fn format_reserved(&self, message: &str, used: &BTreeSet<u32>, indent: &str) -> String {
+        let reserved = self.field_numbers.get_reserved(message, used);
+        if reserved.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "{indent}reserved {};\n",
+                reserved
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        }
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Protobuf backend (one ".proto" file by package, in "protobuf" folder)
+pub struct ProtobufGenerator;
+
+impl Generator for ProtobufGenerator {
+    fn get_name(&self) -> &str {
+        "protobuf"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_protobuf(sink)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make Protobuf files (one by package), in "protobuf" sub-folder
+    ///
+    /// Field numbers are read from, and saved in, the lock file
+    pub fn write_protobuf(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let enumeration_default_values = read_enumeration_default_values()?;
+        let protobuf_type_conversion = read_protobuf_type_conversion()?;
+        let lock_path = Path::new(PROTOBUF_LOCK_FILE);
+        let mut field_numbers = ProtobufFieldNumbers::load(lock_path)?;
+
+        // Protobuf package name of all packages, for cross-package types
+        let mut packages: BTreeMap<String, String> = BTreeMap::new();
+        for pckg in self.get_package_in_order().values() {
+            let package = pckg.get_json();
+            packages.insert(
+                package.xmi_id.get_package_id(),
+                package.lowercase_name.clone(),
+            );
+        }
+
+        let mut all_imports: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating Protobuf for \"{label}\" : START");
+            let package = pckg.get_json();
+            let mut context = ProtobufContext {
+                package_id: package.xmi_id.get_package_id(),
+                packages: &packages,
+                protobuf_type_conversion: &protobuf_type_conversion,
+                field_numbers: &mut field_numbers,
+                imports: BTreeSet::new(),
+            };
+
+            let mut content = String::new();
+            for entity in package.owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Association(_) => {
+                        // Associations are written as fields of their member ends
+                        continue;
+                    }
+                    EnumOwnedMember::Class(content_class) => {
+                        let r = content_class.get_protobuf_content(&mut context);
+                        content.push_str(&catch_error_and_log(r, content_class)?);
+                    }
+                    EnumOwnedMember::DataType(content_datatype) => {
+                        let r = content_datatype.get_protobuf_content(&mut context);
+                        content.push_str(&catch_error_and_log(r, content_datatype)?);
+                    }
+                    EnumOwnedMember::Enumeration(content_enumeration) => {
+                        let r = content_enumeration
+                            .get_protobuf_content(&mut context, &enumeration_default_values);
+                        content.push_str(&catch_error_and_log(r, content_enumeration)?);
+                    }
+                    EnumOwnedMember::PrimitiveType(_) => {
+                        // Primitive types are converted as scalar
+                        continue;
+                    }
+                }
+            }
+
+            let file_name = format!("{}.proto", package.lowercase_name);
+            let mut imports = context
+                .imports
+                .iter()
+                .map(|x| format!("import \"{}\";\n", x))
+                .collect::<String>();
+            if !imports.is_empty() {
+                imports.insert(0, '\n');
+            }
+            let (_, mut wrt) = sink.get_backend_file(PROTOBUF_FOLDER, &file_name);
+            let _ = write!(
+                wrt,
+                include_str!("template/protobuf_main_file.tmpl"),
+                label = label,
+                package_name = package.lowercase_name,
+                imports = imports,
+                content = content,
+            );
+            all_imports.insert(file_name, context.imports);
+            info!("Generating Protobuf for \"{label}\" : Finished");
+        }
+
+        // Protobuf forbid circular import
+        for file_name in all_imports.keys() {
+            check_protobuf_circular_import(file_name, &all_imports, &mut Vec::new())?;
+        }
+
+        if sink.is_dry_run() {
+            info!("Dry-run : Protobuf lock file {:?} not saved", lock_path);
+        } else {
+            field_numbers.save(lock_path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Error if a ".proto" file import itself, directly or not
+fn check_protobuf_circular_import(
+    file_name: &str,
+    all_imports: &BTreeMap<String, BTreeSet<String>>,
+    path: &mut Vec<String>,
+) -> Result<(), anyhow::Error> {
+    if path.iter().any(|x| x == file_name) {
+        return Err(anyhow::format_err!(
+            "Circular import of Protobuf files : {} -> {}",
+            path.join(" -> "),
+            file_name
+        ));
+    }
+    if let Some(imports) = all_imports.get(file_name) {
+        path.push(String::from(file_name));
+        for import in imports {
+            check_protobuf_circular_import(import, all_imports, path)?;
+        }
+        path.pop();
+    }
+    Ok(())
+}
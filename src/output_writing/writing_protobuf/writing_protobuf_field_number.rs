@@ -0,0 +1,199 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+
+// Package section
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+
+// Dependencies section
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+/// Field numbers of all messages and enums, persisted between generations (lock file)
+///
+/// Key : qualified name of the message (example : "bpmn_20.Process"), then key of the field (xmi label)
+pub struct ProtobufFieldNumbers {
+    /// Numbers by message, then by field
+    messages: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl ProtobufFieldNumbers {
+    /// Read the lock file (empty if the file doesn't exist)
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        if !path.exists() {
+            info!("No Protobuf lock file {:?}, all numbers are new", path);
+            return Ok(ProtobufFieldNumbers::default());
+        }
+        let content = path.get_file_content()?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Write the lock file (replacing the previous one)
+    pub fn save(&self, path: &Path) -> Result<(), anyhow::Error> {
+        if path.exists() {
+            path.delete_file()?;
+        }
+        let mut wrt = path.write_new_file()?;
+        let _ = writeln!(wrt, "{}", serde_json::to_string_pretty(self)?);
+        Ok(())
+    }
+
+    /// Number of a field : the saved one, or a new one (never used by the message, and >= "first")
+    pub fn get_number(&mut self, message: &str, key: &str, first: u32) -> u32 {
+        let numbers = self.messages.entry(String::from(message)).or_default();
+        if let Some(number) = numbers.get(key) {
+            return *number;
+        }
+        let number = numbers
+            .values()
+            .max()
+            .map_or(first, |max| (max + 1).max(first));
+        debug!("New Protobuf number for \"{message}\" : {key} = {number}");
+        numbers.insert(String::from(key), number);
+        number
+    }
+
+    /// Saved numbers of a message not used anymore (need "reserved")
+    pub fn get_reserved(&self, message: &str, used: &BTreeSet<u32>) -> Vec<u32> {
+        match self.messages.get(message) {
+            Some(numbers) => numbers
+                .values()
+                .filter(|number| !used.contains(number))
+                .cloned()
+                .collect::<BTreeSet<u32>>()
+                .into_iter()
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+    use crate::output_writing::writing_protobuf::ProtobufContext;
+    use crate::output_writing::ProtobufTypeConversion;
+
+    /// Numbers of a first generation of "bpmn_20.Process" : "a" = 1, "b" = 2, "c" = 3
+    fn get_first_generation() -> ProtobufFieldNumbers {
+        let mut field_numbers = ProtobufFieldNumbers::default();
+        for key in ["a", "b", "c"] {
+            field_numbers.get_number("bpmn_20.Process", key, 1);
+        }
+        field_numbers
+    }
+
+    #[test]
+    fn writing_protobuf_field_number_01_known_key() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let folder = Path::new(
+                "tests/writing_protobuf_field_number/writing_protobuf_field_number_01_known_key",
+            );
+            folder.create_folder()?;
+            let lock_path = folder.join("protobuf_field_number.lock.json");
+            get_first_generation().save(&lock_path)?;
+
+            // Next run : same numbers, whatever the order of the fields and the first number
+            let mut field_numbers = ProtobufFieldNumbers::load(&lock_path)?;
+            assert_eq!(field_numbers.get_number("bpmn_20.Process", "c", 1), 3);
+            assert_eq!(field_numbers.get_number("bpmn_20.Process", "a", 2), 1);
+            assert_eq!(field_numbers.get_number("bpmn_20.Process", "b", 1), 2);
+            // Numbers are by message
+            assert_eq!(field_numbers.get_number("bpmn_20.Task", "c", 1), 1);
+
+            folder.purge_folder()?;
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn writing_protobuf_field_number_02_removed_number_not_reused() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            // Next run without "b" and "c" : "d" and "e" get new numbers, not 2 or 3
+            let mut field_numbers = get_first_generation();
+            assert_eq!(field_numbers.get_number("bpmn_20.Process", "a", 1), 1);
+            assert_eq!(field_numbers.get_number("bpmn_20.Process", "d", 1), 4);
+            assert_eq!(field_numbers.get_number("bpmn_20.Process", "e", 1), 5);
+            // Never under the first usable number
+            assert_eq!(field_numbers.get_number("bpmn_20.Process", "f", 10), 10);
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+
+    #[test]
+    fn writing_protobuf_field_number_03_reserved() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            // Next run without "b" and "c"
+            let mut field_numbers = get_first_generation();
+            let used = BTreeSet::from([
+                field_numbers.get_number("bpmn_20.Process", "a", 1),
+                field_numbers.get_number("bpmn_20.Process", "d", 1),
+            ]);
+            assert_eq!(field_numbers.get_reserved("bpmn_20.Process", &used), [2, 3]);
+            assert!(field_numbers.get_reserved("bpmn_20.Task", &used).is_empty());
+
+            let packages = BTreeMap::new();
+            let protobuf_type_conversion = ProtobufTypeConversion::new();
+            let context = ProtobufContext {
+                package_id: String::from("BPMN20"),
+                packages: &packages,
+                protobuf_type_conversion: &protobuf_type_conversion,
+                field_numbers: &mut field_numbers,
+                imports: BTreeSet::new(),
+            };
+            assert_eq!(
+                context.format_reserved("bpmn_20.Process", &used, "  "),
+                "  reserved 2, 3;\n"
+            );
+            assert_eq!(context.format_reserved("bpmn_20.Task", &used, "  "), "");
+
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+}
@@ -0,0 +1,127 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_protobuf::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// Message of the class (composition of the super classes messages), and "Kind" message if needed
+    pub fn get_protobuf_content(
+        &self,
+        context: &mut ProtobufContext,
+    ) -> Result<String, anyhow::Error> {
+        let mut fields: Vec<ProtobufField> = Vec::new();
+        // For super class, as composition
+        for (label, class) in self.get_super_class()? {
+            let class = get_object_as_class(class)?;
+            fields.push(ProtobufField {
+                key: label,
+                declaration: context
+                    .get_qualified_name(class.xmi_id.get_package_id(), &class.model_name)?,
+                name: class.super_field_name.clone(),
+            });
+        }
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            fields.push(content.get_protobuf_field(context)?);
+        }
+
+        let message = format!(
+            "{}.{}",
+            context.get_package_name(&context.package_id)?,
+            self.model_name
+        );
+        let (mut content, used) = context.format_fields(&message, &fields, 1, "  ");
+        content.push_str(&context.format_reserved(&message, &used, "  "));
+
+        let mut result = format!(
+            include_str!("template/protobuf_main_message.tmpl"),
+            label = self.xmi_id.label()?,
+            model_name = self.model_name,
+            fields = content,
+        );
+        if !self.get_reverse_super_class()?.is_empty() {
+            result.push_str(&self.get_protobuf_kind(context)?);
+        }
+        Ok(result)
+    }
+
+    /// "Kind" message, with "oneof" of the class and all its concrete sub-class
+    ///
+    /// Sub-class of other packages use "google.protobuf.Any" (prevent circular import)
+    fn get_protobuf_kind(&self, context: &mut ProtobufContext) -> Result<String, anyhow::Error> {
+        let mut fields: Vec<ProtobufField> = Vec::new();
+        if !self.is_abstract {
+            fields.push(ProtobufField {
+                key: self.xmi_id.label()?,
+                declaration: self.model_name.clone(),
+                name: self.model_name.to_case(Case::Snake),
+            });
+        }
+        let mut need_any = false;
+        for (label, class) in self.get_all_descendant_class()? {
+            if class.is_abstract {
+                continue;
+            }
+            if class.xmi_id.get_package_id() == context.package_id {
+                fields.push(ProtobufField {
+                    key: label,
+                    declaration: class.model_name.clone(),
+                    name: class.model_name.to_case(Case::Snake),
+                });
+            } else {
+                need_any = true;
+            }
+        }
+        if need_any {
+            context.add_well_known_import(PROTOBUF_ANY);
+            fields.push(ProtobufField {
+                key: String::from("OTHER"),
+                declaration: String::from(PROTOBUF_ANY),
+                name: String::from("other"),
+            });
+        }
+
+        let message = format!(
+            "{}.{}Kind",
+            context.get_package_name(&context.package_id)?,
+            self.model_name
+        );
+        let (content, used) = context.format_fields(&message, &fields, 1, "    ");
+        Ok(format!(
+            include_str!("template/protobuf_main_kind.tmpl"),
+            label = self.xmi_id.label()?,
+            model_name = self.model_name,
+            fields = content,
+            reserved = context.format_reserved(&message, &used, "  "),
+        ))
+    }
+}
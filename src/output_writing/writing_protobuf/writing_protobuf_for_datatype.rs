@@ -0,0 +1,61 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_protobuf::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFDataType {
+    /// Message of the datatype
+    pub fn get_protobuf_content(
+        &self,
+        context: &mut ProtobufContext,
+    ) -> Result<String, anyhow::Error> {
+        let mut fields: Vec<ProtobufField> = Vec::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            fields.push(content.get_protobuf_field(context)?);
+        }
+
+        let message = format!(
+            "{}.{}",
+            context.get_package_name(&context.package_id)?,
+            self.model_name
+        );
+        let (mut content, used) = context.format_fields(&message, &fields, 1, "  ");
+        content.push_str(&context.format_reserved(&message, &used, "  "));
+
+        Ok(format!(
+            include_str!("template/protobuf_main_message.tmpl"),
+            label = self.xmi_id.label()?,
+            model_name = self.model_name,
+            fields = content,
+        ))
+    }
+}
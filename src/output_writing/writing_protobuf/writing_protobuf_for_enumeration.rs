@@ -0,0 +1,81 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_protobuf::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use std::collections::BTreeSet;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFEnumeration {
+    /// Enum of the enumeration, with the default literal at 0 (see "enumeration_default_value.json")
+    ///
+    /// Values are prefixed by the enum name, as Protobuf enum values share the package scope
+    pub fn get_protobuf_content(
+        &self,
+        context: &mut ProtobufContext,
+        enumeration_default_values: &EnumerationDefaultValues,
+    ) -> Result<String, anyhow::Error> {
+        let prefix = self.model_name.to_case(Case::UpperSnake);
+        let message = format!(
+            "{}.{}",
+            context.get_package_name(&context.package_id)?,
+            self.model_name
+        );
+
+        let mut default = String::new();
+        let mut literals = String::new();
+        let mut used: BTreeSet<u32> = BTreeSet::new();
+        for literal in self.get_all_literal()? {
+            let name = format!(
+                "{}_{}",
+                prefix,
+                literal.litteral_name.to_case(Case::UpperSnake)
+            );
+            let label = literal.xmi_id.label()?;
+            if literal.is_default(enumeration_default_values)? {
+                default = format!("  {} = 0; // {}\n", name, label);
+            } else {
+                let number = context.field_numbers.get_number(&message, &label, 1);
+                used.insert(number);
+                literals.push_str(&format!("  {} = {}; // {}\n", name, number, label));
+            }
+        }
+        literals.insert_str(0, &default);
+        literals.push_str(&context.format_reserved(&message, &used, "  "));
+
+        Ok(format!(
+            include_str!("template/protobuf_main_enumeration.tmpl"),
+            label = self.xmi_id.label()?,
+            model_name = self.model_name,
+            literals = literals,
+        ))
+    }
+}
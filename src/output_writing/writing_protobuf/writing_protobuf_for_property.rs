@@ -0,0 +1,98 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_protobuf::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Field of the property
+    ///
+    /// - Class with composition as message ("Kind" message if the class have sub-class)
+    /// - Class without composition as reference (string identifier)
+    /// - DataType as message, Enumeration as enum
+    /// - Primitive type using "protobuf_type_conversion.json"
+    /// - "repeated" if "upper > 1", "optional" for scalar and enum if "lower == 0"
+    pub fn get_protobuf_field(
+        &self,
+        context: &mut ProtobufContext,
+    ) -> Result<ProtobufField, anyhow::Error> {
+        let (type_name, is_message) = match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => {
+                if !self.is_composite {
+                    (String::from("string"), false)
+                } else if c.get_reverse_super_class()?.is_empty() {
+                    let name =
+                        context.get_qualified_name(c.xmi_id.get_package_id(), &c.model_name)?;
+                    (name, true)
+                } else {
+                    let name = context.get_qualified_name(
+                        c.xmi_id.get_package_id(),
+                        &format!("{}Kind", c.model_name),
+                    )?;
+                    (name, true)
+                }
+            }
+            EnumCMOF::CMOFDataType(c) => {
+                let name = context.get_qualified_name(c.xmi_id.get_package_id(), &c.model_name)?;
+                (name, true)
+            }
+            EnumCMOF::CMOFEnumeration(c) => {
+                let name = context.get_qualified_name(c.xmi_id.get_package_id(), &c.model_name)?;
+                (name, false)
+            }
+            EnumCMOF::CMOFPrimitiveType(c) => {
+                let name = context.get_primitive_type(&c.model_name)?;
+                let is_message = name.starts_with("google.protobuf.");
+                (name, is_message)
+            }
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        };
+
+        let declaration = if self.upper > infinitable::Finite(1) {
+            format!("repeated {}", type_name)
+        } else if self.lower == 0 && !is_message {
+            format!("optional {}", type_name)
+        } else {
+            type_name
+        };
+
+        Ok(ProtobufField {
+            key: self.xmi_id.label()?,
+            declaration,
+            name: self.get_metamodel_name().to_case(Case::Snake),
+        })
+    }
+}
@@ -0,0 +1,192 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_typescript.md")]
+
+// Mod section
+mod writing_typescript_for_class;
+mod writing_typescript_for_datatype;
+mod writing_typescript_for_enumeration;
+mod writing_typescript_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::*;
+
+// Dependencies section
+use std::collections::BTreeSet;
+
+/// Sub-folder of the output folder used by the TypeScript writing
+pub const TYPESCRIPT_FOLDER: &str = "typescript";
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Shared content during the writing of the ".d.ts" file of a package
+pub struct TypescriptContext<'a> {
+    /// Package of the ".d.ts" file
+    pub package_id: String,
+    /// TypeScript module name of all packages (key : package id)
+    pub packages: &'a BTreeMap<String, String>,
+    /// Conversion of the primitive types
+    pub typescript_type_conversion: &'a TypescriptTypeConversion,
+    /// Modules to import
+    pub imports: BTreeSet<String>,
+}
+
+impl TypescriptContext<'_> {
+    /// Name of an interface or type, qualified by the module if in other package (saving needed import)
+    pub fn get_qualified_name(
+        &mut self,
+        package_id: String,
+        model_name: &str,
+    ) -> Result<String, anyhow::Error> {
+        if package_id == self.package_id {
+            return Ok(String::from(model_name));
+        }
+        let module_name = self
+            .packages
+            .get(&package_id)
+            .ok_or(anyhow::format_err!(
+                "No TypeScript module for \"{}\"",
+                package_id
+            ))?
+            .clone();
+        self.imports.insert(module_name.clone());
+        Ok(format!("{}.{}", module_name, model_name))
+    }
+
+    /// Type of a primitive type, using "typescript_type_conversion.json"
+    pub fn get_primitive_type(&self, model_name: &str) -> Result<String, anyhow::Error> {
+        Ok(self
+            .typescript_type_conversion
+            .get(model_name)
+            .ok_or(anyhow::format_err!(
+                "No TypeScript type conversion for \"{}\"",
+                model_name
+            ))?
+            .clone())
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// TypeScript backend (one declaration file by package, in "typescript" folder)
+pub struct TypescriptGenerator;
+
+impl Generator for TypescriptGenerator {
+    fn get_name(&self) -> &str {
+        "typescript"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_typescript(sink)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make TypeScript type definition files (one module by package), in "typescript" sub-folder
+    pub fn write_typescript(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let enumeration_default_values = read_enumeration_default_values()?;
+        let typescript_type_conversion = read_typescript_type_conversion()?;
+
+        // Module name of all packages, for cross-package types
+        let mut packages: BTreeMap<String, String> = BTreeMap::new();
+        for pckg in self.get_package_in_order().values() {
+            let package = pckg.get_json();
+            packages.insert(
+                package.xmi_id.get_package_id(),
+                package.lowercase_name.clone(),
+            );
+        }
+
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating TypeScript for \"{label}\" : START");
+            let package = pckg.get_json();
+            let mut context = TypescriptContext {
+                package_id: package.xmi_id.get_package_id(),
+                packages: &packages,
+                typescript_type_conversion: &typescript_type_conversion,
+                imports: BTreeSet::new(),
+            };
+
+            let mut content = String::new();
+            for entity in package.owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Association(_) => {
+                        // Associations are written as fields of their member ends
+                        continue;
+                    }
+                    EnumOwnedMember::Class(content_class) => {
+                        let r = content_class.get_typescript_content(&mut context);
+                        content.push_str(&catch_error_and_log(r, content_class)?);
+                    }
+                    EnumOwnedMember::DataType(content_datatype) => {
+                        let r = content_datatype.get_typescript_content(&mut context);
+                        content.push_str(&catch_error_and_log(r, content_datatype)?);
+                    }
+                    EnumOwnedMember::Enumeration(content_enumeration) => {
+                        let r =
+                            content_enumeration.get_typescript_content(&enumeration_default_values);
+                        content.push_str(&catch_error_and_log(r, content_enumeration)?);
+                    }
+                    EnumOwnedMember::PrimitiveType(_) => {
+                        // Primitive types are converted as TypeScript types
+                        continue;
+                    }
+                }
+            }
+
+            // Type-only imports : cyclic imports between modules are allowed
+            let mut imports = context
+                .imports
+                .iter()
+                .map(|x| format!("import type * as {} from \"./{}\";\n", x, x))
+                .collect::<String>();
+            if !imports.is_empty() {
+                imports.insert(0, '\n');
+            }
+            let file_name = format!("{}.d.ts", package.lowercase_name);
+            let (_, mut wrt) = sink.get_backend_file(TYPESCRIPT_FOLDER, &file_name);
+            let _ = write!(
+                wrt,
+                include_str!("template/typescript_main_file.tmpl"),
+                label = label,
+                imports = imports,
+                content = content,
+            );
+            info!("Generating TypeScript for \"{label}\" : Finished");
+        }
+        Ok(())
+    }
+}
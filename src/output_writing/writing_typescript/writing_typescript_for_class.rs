@@ -0,0 +1,67 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_typescript::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// Interface of the class, extending the interfaces of the super classes
+    pub fn get_typescript_content(
+        &self,
+        context: &mut TypescriptContext,
+    ) -> Result<String, anyhow::Error> {
+        let mut super_names: Vec<String> = Vec::new();
+        for class in self.get_super_class()?.values() {
+            let class = get_object_as_class(class)?;
+            super_names.push(
+                context.get_qualified_name(class.xmi_id.get_package_id(), &class.model_name)?,
+            );
+        }
+        let extends = if super_names.is_empty() {
+            String::new()
+        } else {
+            format!(" extends {}", super_names.join(", "))
+        };
+
+        let mut fields = String::new();
+        for property in self.owned_attribute.values() {
+            let EnumOwnedAttribute::Property(content) = property;
+            fields.push_str(&content.get_typescript_field(context)?);
+        }
+
+        Ok(format!(
+            include_str!("template/typescript_main_interface.tmpl"),
+            label = self.xmi_id.label()?,
+            model_name = self.model_name,
+            extends = extends,
+            fields = fields,
+        ))
+    }
+}
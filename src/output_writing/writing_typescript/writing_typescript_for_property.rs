@@ -0,0 +1,75 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_typescript::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Field of the property ("?" if "lower == 0", array if "upper > 1")
+    ///
+    /// Class, DataType and Enumeration use their interface or type, PrimitiveType use "typescript_type_conversion.json"
+    pub fn get_typescript_field(
+        &self,
+        context: &mut TypescriptContext,
+    ) -> Result<String, anyhow::Error> {
+        let type_name = match self.get_type()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => {
+                context.get_qualified_name(c.xmi_id.get_package_id(), &c.model_name)?
+            }
+            EnumCMOF::CMOFDataType(c) => {
+                context.get_qualified_name(c.xmi_id.get_package_id(), &c.model_name)?
+            }
+            EnumCMOF::CMOFEnumeration(c) => {
+                context.get_qualified_name(c.xmi_id.get_package_id(), &c.model_name)?
+            }
+            EnumCMOF::CMOFPrimitiveType(c) => context.get_primitive_type(&c.model_name)?,
+            _ => {
+                return Err(anyhow::format_err!(
+                    "Unexpected type for \"{}\"",
+                    self.xmi_id.label()?
+                ));
+            }
+        };
+
+        let type_name = if self.upper > infinitable::Finite(1) {
+            format!("{}[]", type_name)
+        } else {
+            type_name
+        };
+        let optional = if self.lower == 0 { "?" } else { "" };
+
+        Ok(format!(
+            "  {}{}: {};\n",
+            self.get_metamodel_name(),
+            optional,
+            type_name
+        ))
+    }
+}
@@ -0,0 +1,370 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+#![doc = include_str!("../../../doc/writing_xsd.md")]
+
+// Mod section
+mod writing_xsd_for_class;
+mod writing_xsd_for_datatype;
+mod writing_xsd_for_enumeration;
+mod writing_xsd_for_property;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::custom_file_tools::*;
+use crate::custom_log_tools::*;
+use crate::output_writing::writing_bpmn_xml::BpmnXmlContext;
+use crate::output_writing::*;
+
+// Dependencies section
+use std::collections::BTreeSet;
+
+/// Sub-folder of the output folder used by the XSD writing
+pub const XSD_FOLDER: &str = "xsd";
+
+/// Tag of the package providing the target namespace
+pub const TAG_NS_URI: &str = "org.omg.xmi.nsURI";
+
+/// Tag of the package providing the namespace prefix
+pub const TAG_NS_PREFIX: &str = "org.omg.xmi.nsPrefix";
+
+/// Suffix of the nsURI tags, not used in XML files (".../MODEL-XMI" --> ".../MODEL")
+pub const TAG_NS_URI_XMI_SUFFIX: &str = "-XMI";
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[derive(Clone, Debug)]
+/// XML namespace of a package
+pub struct XsdNamespace {
+    /// Target namespace (nsURI tag, without "-XMI" suffix)
+    pub uri: String,
+    /// Prefix (nsPrefix tag)
+    pub prefix: String,
+    /// Name of the XSD file of the package
+    pub file_name: String,
+}
+
+impl XsdNamespace {
+    /// Namespace of a package, from its nsURI and nsPrefix tags
+    ///
+    /// Without tags : "${uri}/${package}" and "${lowercase_name}"
+    pub fn new(pckg: &LoadingPackage) -> Self {
+        if let Some(namespace) = XsdNamespace::from_tags(pckg) {
+            return namespace;
+        }
+        let package = pckg.get_json();
+        warn!(
+            "No \"{TAG_NS_URI}\" and \"{TAG_NS_PREFIX}\" tags for \"{}\", using package URI",
+            pckg.get_label()
+        );
+        XsdNamespace {
+            uri: format!("{}/{}", package.uri, package.xmi_id.get_package_id()),
+            prefix: package.lowercase_name.clone(),
+            file_name: format!("{}.xsd", package.lowercase_name),
+        }
+    }
+
+    /// Namespace of a package with nsURI and nsPrefix tags, as in XML files (URI without "-XMI" suffix)
+    pub fn from_tags(pckg: &LoadingPackage) -> Option<Self> {
+        let uri = pckg.get_package_tag(TAG_NS_URI)?;
+        let prefix = pckg.get_package_tag(TAG_NS_PREFIX)?;
+        Some(XsdNamespace {
+            uri: uri.trim_end_matches(TAG_NS_URI_XMI_SUFFIX).to_string(),
+            prefix,
+            file_name: format!("{}.xsd", pckg.get_json().lowercase_name),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+/// Content of a complexType : elements (in "xsd:sequence"), attributes, and text
+pub struct XsdContent {
+    /// "xsd:element" and "xsd:choice" lines
+    pub elements: String,
+    /// "xsd:attribute" lines
+    pub attributes: String,
+    /// A property is the text of the element
+    pub is_mixed: bool,
+}
+
+impl XsdContent {
+    /// Format the content of a complexType, with "xsd:sequence" only if needed
+    pub fn format(&self, indent: &str) -> String {
+        let mut result = String::new();
+        if !self.elements.is_empty() {
+            result.push_str(&format!(
+                "{indent}<xsd:sequence>\n{}{indent}</xsd:sequence>\n",
+                self.elements
+            ));
+        }
+        result.push_str(&self.attributes);
+        result
+    }
+}
+
+/// Shared content during the writing of the XSD file of a package
+pub struct XsdContext<'a> {
+    /// Package of the XSD file
+    pub package_id: String,
+    /// Namespace of all packages (key : package id)
+    pub namespaces: &'a BTreeMap<String, XsdNamespace>,
+    /// Conversion of the primitive types
+    pub xsd_type_conversion: &'a XsdTypeConversion,
+    /// Placement and names of the properties, shared with the BPMN XML writing
+    pub bpmn_xml: &'a BpmnXmlContext,
+    /// Other packages used by the XSD file, need "xsd:import"
+    pub imported: BTreeSet<String>,
+}
+
+impl XsdContext<'_> {
+    /// Qualified name of the type of an object ("${prefix}:t${metamodel_name}"), saving needed import
+    pub fn get_type_name(&mut self, object: &EnumCMOF) -> Result<String, anyhow::Error> {
+        let (xmi_id, name) = match object {
+            EnumCMOF::CMOFClass(c) => (&c.xmi_id, c.get_metamodel_name()),
+            EnumCMOF::CMOFDataType(c) => (&c.xmi_id, c.get_metamodel_name()),
+            EnumCMOF::CMOFEnumeration(c) => (&c.xmi_id, c.get_metamodel_name()),
+            EnumCMOF::CMOFPrimitiveType(c) => {
+                return Ok(self
+                    .xsd_type_conversion
+                    .get(&c.model_name)
+                    .ok_or(anyhow::format_err!(
+                        "No XSD type conversion for \"{}\"",
+                        c.model_name
+                    ))?
+                    .clone());
+            }
+            _ => {
+                return Err(anyhow::format_err!(
+                    "No XSD type for \"{}\"",
+                    object.label()?
+                ));
+            }
+        };
+        self.get_qualified_name(xmi_id.get_package_id(), &format!("t{name}"))
+    }
+
+    /// Qualified name of a type or an element of a package ("${prefix}:${name}"), saving needed import
+    pub fn get_qualified_name(
+        &mut self,
+        package_id: String,
+        name: &str,
+    ) -> Result<String, anyhow::Error> {
+        let namespace = self.namespaces.get(&package_id).ok_or(anyhow::format_err!(
+            "No XSD namespace for \"{}\"",
+            package_id
+        ))?;
+        let qualified_name = format!("{}:{}", namespace.prefix, name);
+        if package_id != self.package_id {
+            self.imported.insert(package_id);
+        }
+        Ok(qualified_name)
+    }
+
+    /// Package of a namespace prefix of the BPMN XML writing ("dc" --> "DC"), the package of the XSD file without prefix
+    pub fn get_prefix_package_id(&self, qualified_name: &str) -> Result<String, anyhow::Error> {
+        let Some((prefix, _)) = qualified_name.split_once(':') else {
+            return Ok(self.package_id.clone());
+        };
+        self.namespaces
+            .iter()
+            .find(|(_, namespace)| namespace.prefix == prefix)
+            .map(|(package_id, _)| package_id.clone())
+            .ok_or(anyhow::format_err!(
+                "No XSD namespace for prefix \"{}\"",
+                prefix
+            ))
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// XSD backend (one XML Schema by package, in "xsd" folder)
+pub struct XsdGenerator;
+
+impl Generator for XsdGenerator {
+    fn get_name(&self) -> &str {
+        "xsd"
+    }
+
+    fn generate(
+        &self,
+        model: &LoadingTracker,
+        sink: &dyn WrittingPath,
+    ) -> Result<(), anyhow::Error> {
+        model.write_xsd(sink)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+impl LoadingTracker {
+    /// Make XSD files (one by package), in "xsd" sub-folder
+    pub fn write_xsd(&self, sink: &dyn WrittingPath) -> Result<(), anyhow::Error> {
+        let xsd_type_conversion = read_xsd_type_conversion()?;
+        let bpmn_xml = self.get_bpmn_xml_context()?;
+
+        // Namespace of all packages, for cross-package types
+        let mut namespaces: BTreeMap<String, XsdNamespace> = BTreeMap::new();
+        for pckg in self.get_package_in_order().values() {
+            namespaces.insert(
+                pckg.get_json().xmi_id.get_package_id(),
+                XsdNamespace::new(pckg),
+            );
+        }
+
+        for (label, pckg) in self.get_package_in_order() {
+            debug!("Generating XSD for \"{label}\" : START");
+            let package = pckg.get_json();
+            let mut context = XsdContext {
+                package_id: package.xmi_id.get_package_id(),
+                namespaces: &namespaces,
+                xsd_type_conversion: &xsd_type_conversion,
+                bpmn_xml: &bpmn_xml,
+                imported: BTreeSet::new(),
+            };
+
+            let mut elements = String::new();
+            let mut types = String::new();
+            for entity in package.owned_member.values() {
+                match entity {
+                    EnumOwnedMember::Association(_) => {
+                        // Associations are written as attributes or elements of their member ends
+                        continue;
+                    }
+                    EnumOwnedMember::Class(content) => {
+                        let r = content.get_xsd_content(&mut context);
+                        types.push_str(&catch_error_and_log(r, content)?);
+                        elements.push_str(&content.get_xsd_element(&mut context)?);
+                    }
+                    EnumOwnedMember::DataType(content) => {
+                        let r = content.get_xsd_content(&mut context);
+                        types.push_str(&catch_error_and_log(r, content)?);
+                        elements.push_str(&content.get_xsd_element(&mut context)?);
+                    }
+                    EnumOwnedMember::Enumeration(content) => {
+                        let r = content.get_xsd_content();
+                        types.push_str(&catch_error_and_log(r, content)?);
+                    }
+                    EnumOwnedMember::PrimitiveType(_) => {
+                        // Primitive types are converted as XSD type
+                        continue;
+                    }
+                }
+            }
+
+            // Namespace declarations and imports, for self and used packages
+            let namespace = &namespaces[&context.package_id];
+            let mut xmlns = format!("\n    xmlns:{}=\"{}\"", namespace.prefix, namespace.uri);
+            let mut imports = String::new();
+            for package_id in &context.imported {
+                let imported = &namespaces[package_id];
+                xmlns.push_str(&format!(
+                    "\n    xmlns:{}=\"{}\"",
+                    imported.prefix, imported.uri
+                ));
+                imports.push_str(&format!(
+                    include_str!("template/xsd_sub_import.tmpl"),
+                    uri = imported.uri,
+                    file_name = imported.file_name,
+                ));
+            }
+
+            let (_, mut wrt) = sink.get_backend_file(XSD_FOLDER, &namespace.file_name);
+            let _ = writeln!(
+                wrt,
+                include_str!("template/xsd_main_schema.tmpl"),
+                label = label,
+                xmlns = xmlns,
+                target_namespace = namespace.uri,
+                imports = imports,
+                elements = elements,
+                types = types,
+            );
+            info!("Generating XSD for \"{label}\" : Finished");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+    use crate::output_result_manager::open_env;
+    use std::process::Command;
+
+    #[test]
+    fn writing_xsd_01_bpmn_xml_sample() {
+        fn test() -> Result<(), anyhow::Error> {
+            initialize_log_for_test();
+
+            let test_folder = "tests/writing_xsd/writing_xsd_01_bpmn_xml_sample/";
+            let file_env = open_env(
+                "metamodel_file",
+                &format!("{test_folder}output"),
+                &format!("{test_folder}result"),
+            )?;
+            let mut loading_env = open_loader(file_env)?;
+            loading_env.make_prepare("BPMNDI.json", "_0", "root")?;
+            let sink = OutputSink::new(&loading_env.get_output_folder(), false);
+            XsdGenerator.generate(&loading_env, &sink)?;
+
+            // "sample.bpmn" is written by "write_bpmn_xml" of the generated crate
+            let mut folder = loading_env.get_output_folder();
+            folder.push(XSD_FOLDER);
+            let schema = folder.join("bpmn_20.xsd").get_file_content()?;
+            assert!(
+                schema.contains("targetNamespace=\"http://www.omg.org/spec/BPMN/20100524/MODEL\"")
+            );
+            assert!(schema.contains("<xsd:attribute name=\"exporter\" type=\"xsd:string\"/>"));
+            // Validation of the sample only if "xmllint" is installed
+            match Command::new("xmllint")
+                .arg("--noout")
+                .arg("--schema")
+                .arg(folder.join("bpmn_20.xsd"))
+                .arg(format!("{test_folder}sample.bpmn"))
+                .output()
+            {
+                Ok(output) => assert!(
+                    output.status.success(),
+                    "{}",
+                    String::from_utf8_lossy(&output.stderr)
+                ),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                    warn!("\"xmllint\" not found : validation of the sample skipped");
+                }
+                Err(e) => return Err(e.into()),
+            }
+
+            Path::new(&format!("{test_folder}output")).purge_folder()?;
+            Path::new(&format!("{test_folder}result")).purge_folder()?;
+            Ok(())
+        }
+
+        let r = test();
+        assert!(r.is_ok());
+    }
+}
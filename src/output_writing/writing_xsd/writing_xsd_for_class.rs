@@ -0,0 +1,159 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_xsd::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::output_writing::writing_bpmn_xml::BpmnXmlPlacement;
+
+// Dependencies section
+use std::collections::BTreeSet;
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFClass {
+    /// complexType of the class, with the properties in the order of the BPMN XML writing
+    ///
+    /// XSD allow only one base type : the first super class is used in "xsd:extension", and
+    /// properties of the other super classes are copied in the complexType
+    pub fn get_xsd_content(&self, context: &mut XsdContext) -> Result<String, anyhow::Error> {
+        let super_class = self.get_super_class()?;
+        let is_abstract = if self.is_abstract {
+            " abstract=\"true\""
+        } else {
+            ""
+        };
+        let is_mixed = if self.is_xsd_mixed(context)? {
+            " mixed=\"true\""
+        } else {
+            ""
+        };
+
+        let Some((_, base)) = super_class.first_key_value() else {
+            let mut content = XsdContent::default();
+            for property in self.get_owned_attribute_in_order() {
+                property.add_xsd_content(context, "        ", &mut content)?;
+            }
+            return Ok(format!(
+                include_str!("template/xsd_main_class.tmpl"),
+                label = self.xmi_id.label()?,
+                metamodel_name = self.get_metamodel_name(),
+                is_abstract = is_abstract,
+                is_mixed = is_mixed,
+                content = content.format("        "),
+            ));
+        };
+
+        // Properties written after the base : other super classes (without the classes of the base), then own properties
+        let base = get_object_as_class(base)?;
+        let mut written_classes = BTreeSet::new();
+        base.add_xsd_written_properties(&mut written_classes, &mut Vec::new())?;
+        let mut properties = Vec::new();
+        for class in super_class.values().skip(1) {
+            get_object_as_class(class)?
+                .add_xsd_written_properties(&mut written_classes, &mut properties)?;
+        }
+        properties.extend(self.get_owned_attribute_in_order());
+
+        let mut content = XsdContent::default();
+        for property in properties {
+            property.add_xsd_content(context, "                ", &mut content)?;
+        }
+        Ok(format!(
+            include_str!("template/xsd_main_class_extension.tmpl"),
+            label = self.xmi_id.label()?,
+            metamodel_name = self.get_metamodel_name(),
+            is_abstract = is_abstract,
+            is_mixed = is_mixed,
+            base = context.get_type_name(&EnumCMOF::CMOFClass(base))?,
+            content = content.format("                "),
+        ))
+    }
+
+    /// Properties of the class in the order of the BPMN XML writing : super classes first, each class once
+    fn add_xsd_written_properties(
+        &self,
+        written_classes: &mut BTreeSet<String>,
+        properties: &mut Vec<Rc<CMOFProperty>>,
+    ) -> Result<(), anyhow::Error> {
+        if !written_classes.insert(self.xmi_id.label()?) {
+            return Ok(());
+        }
+        for class in self.get_super_class()?.values() {
+            get_object_as_class(class)?.add_xsd_written_properties(written_classes, properties)?;
+        }
+        properties.extend(self.get_owned_attribute_in_order());
+        Ok(())
+    }
+
+    /// Check if the complexType have text ("mixed")
+    ///
+    /// "mixed" must be the same for all the types of an "xsd:extension" : true if a class with the same root base class have a property written as text
+    fn is_xsd_mixed(&self, context: &XsdContext) -> Result<bool, anyhow::Error> {
+        let mut root: Option<Rc<CMOFClass>> = None;
+        loop {
+            let super_class = match &root {
+                Some(class) => class.get_super_class()?,
+                None => self.get_super_class()?,
+            };
+            match super_class.first_key_value() {
+                Some((_, base)) => root = Some(get_object_as_class(base)?),
+                None => break,
+            }
+        }
+        let descendant_class = match &root {
+            Some(class) => class.get_all_descendant_class()?,
+            None => self.get_all_descendant_class()?,
+        };
+        let mut properties: Vec<Rc<CMOFProperty>> = match &root {
+            Some(class) => class.get_owned_attribute_in_order(),
+            None => self.get_owned_attribute_in_order(),
+        };
+        for class in descendant_class.values() {
+            properties.extend(class.get_owned_attribute_in_order());
+        }
+        for property in properties {
+            if property.get_bpmn_xml_placement(context.bpmn_xml)? == BpmnXmlPlacement::Text {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Global element of the class (only for concrete class), named as in BPMN XML files ("task", "BPMNShape")
+    pub fn get_xsd_element(&self, context: &mut XsdContext) -> Result<String, anyhow::Error> {
+        if self.is_abstract {
+            return Ok(String::new());
+        }
+        Ok(format!(
+            include_str!("template/xsd_sub_element.tmpl"),
+            element_name = self.get_bpmn_xml_local_name(),
+            type_name = context.get_qualified_name(
+                self.xmi_id.get_package_id(),
+                &format!("t{}", self.get_metamodel_name())
+            )?,
+        ))
+    }
+}
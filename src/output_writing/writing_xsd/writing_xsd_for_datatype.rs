@@ -0,0 +1,66 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_xsd::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFDataType {
+    /// complexType of the datatype, with the properties in the order of the BPMN XML writing
+    pub fn get_xsd_content(&self, context: &mut XsdContext) -> Result<String, anyhow::Error> {
+        let mut content = XsdContent::default();
+        for property in self.get_owned_attribute_in_order() {
+            property.add_xsd_content(context, "        ", &mut content)?;
+        }
+        Ok(format!(
+            include_str!("template/xsd_main_class.tmpl"),
+            label = self.xmi_id.label()?,
+            metamodel_name = self.get_metamodel_name(),
+            is_abstract = "",
+            is_mixed = if content.is_mixed {
+                " mixed=\"true\""
+            } else {
+                ""
+            },
+            content = content.format("        "),
+        ))
+    }
+
+    /// Global element of the datatype, named as in BPMN XML files ("Bounds")
+    pub fn get_xsd_element(&self, context: &mut XsdContext) -> Result<String, anyhow::Error> {
+        Ok(format!(
+            include_str!("template/xsd_sub_element.tmpl"),
+            element_name = self.get_metamodel_name(),
+            type_name = context.get_qualified_name(
+                self.xmi_id.get_package_id(),
+                &format!("t{}", self.get_metamodel_name())
+            )?,
+        ))
+    }
+}
@@ -0,0 +1,172 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::output_writing::writing_xsd::*;
+
+// Package section
+use crate::cmof_loader::*;
+use crate::output_writing::writing_bpmn_xml::BpmnXmlPlacement;
+
+// Dependencies section
+
+// // ####################################################################################################
+// //
+// // ####################################################################################################
+
+impl CMOFProperty {
+    /// Add the property to a complexType content, at its place in BPMN XML files (see [`CMOFProperty::get_bpmn_xml_placement`])
+    ///
+    /// - Derived property : not written
+    /// - Attribute : optional ("xsd:ID" for "id", "xsd:IDREF" for a class without composition)
+    /// - Element : named as the property ("xsd:IDREF" for a class without composition), or reference to the global element of an other namespace ("dc:Bounds")
+    /// - Element named by class : "xsd:choice" of the global elements of the concrete classes
+    /// - Text : "mixed" complexType
+    ///
+    /// Values equal to the default value aren't written : only owned values keep "minOccurs" of the metamodel.
+    /// "indent" is the indentation of attributes, elements are in "xsd:sequence"
+    pub fn add_xsd_content(
+        &self,
+        context: &mut XsdContext,
+        indent: &str,
+        content: &mut XsdContent,
+    ) -> Result<(), anyhow::Error> {
+        if self.is_derived || self.is_derived_union {
+            return Ok(());
+        }
+        let is_many = self.upper > infinitable::Finite(1);
+        let object = self.get_type()?.upgrade()?;
+        let (type_name, is_owned) = match &object {
+            EnumCMOF::CMOFClass(_) if !self.is_composite => (String::from("xsd:IDREF"), false),
+            EnumCMOF::CMOFClass(_) | EnumCMOF::CMOFDataType(_) => {
+                (context.get_type_name(&object)?, true)
+            }
+            EnumCMOF::CMOFPrimitiveType(_) if self.get_metamodel_name() == "id" && !is_many => {
+                (String::from("xsd:ID"), false)
+            }
+            _ => (context.get_type_name(&object)?, false),
+        };
+        let min_occurs = if is_owned { self.lower } else { 0 };
+        let max_occurs = match self.upper {
+            infinitable::Finite(upper) => upper.to_string(),
+            _ => String::from("unbounded"),
+        };
+
+        match self.get_bpmn_xml_placement(context.bpmn_xml)? {
+            BpmnXmlPlacement::Attribute(name) => {
+                let type_name = match type_name.as_str() {
+                    "xsd:IDREF" if is_many => String::from("xsd:IDREFS"),
+                    _ => type_name,
+                };
+                let default = match (&self.default, &object) {
+                    (Some(default), EnumCMOF::CMOFEnumeration(c)) => {
+                        match c.get_literal_by_designation(default)? {
+                            Some(literal) => Some(literal.litteral_designation.clone()),
+                            None => Some(default.clone()),
+                        }
+                    }
+                    (default, _) => default.clone(),
+                };
+                let default = match default {
+                    Some(default) => format!(" default=\"{}\"", default),
+                    None => String::new(),
+                };
+                content.attributes.push_str(&format!(
+                    "{indent}<xsd:attribute name=\"{}\" type=\"{}\"{}/>\n",
+                    name, type_name, default
+                ));
+            }
+            BpmnXmlPlacement::Element(name) => {
+                let package_id = context.get_prefix_package_id(&name)?;
+                let local_name = name.rsplit(':').next().unwrap_or(&name);
+                let declaration = if package_id == context.package_id {
+                    format!("name=\"{}\" type=\"{}\"", local_name, type_name)
+                } else {
+                    format!(
+                        "ref=\"{}\"",
+                        context.get_qualified_name(package_id, local_name)?
+                    )
+                };
+                content.elements.push_str(&format!(
+                    "{indent}    <xsd:element {} minOccurs=\"{}\" maxOccurs=\"{}\"/>\n",
+                    declaration, min_occurs, max_occurs
+                ));
+            }
+            BpmnXmlPlacement::ClassElement => {
+                let EnumCMOF::CMOFClass(class) = &object else {
+                    return Err(anyhow::format_err!(
+                        "Elements named by class for \"{}\", without class type",
+                        self.xmi_id.label()?
+                    ));
+                };
+                let mut classes = vec![class.clone()];
+                classes.extend(class.get_all_descendant_class()?.into_values());
+                // As for the reading, class names which are other property names of the owner aren't used
+                let own_names = self.get_bpmn_xml_own_names();
+                let hierarchy_names: Vec<String> = self
+                    .get_xsd_hierarchy_names()?
+                    .into_iter()
+                    .filter(|name| !own_names.contains(name))
+                    .collect();
+                let mut choices = String::new();
+                for class in classes.iter().filter(|class| {
+                    !class.is_abstract
+                        && !hierarchy_names
+                            .iter()
+                            .any(|name| name.eq_ignore_ascii_case(&class.get_metamodel_name()))
+                }) {
+                    choices.push_str(&format!(
+                        "{indent}        <xsd:element ref=\"{}\"/>\n",
+                        context.get_qualified_name(
+                            class.xmi_id.get_package_id(),
+                            &class.get_bpmn_xml_local_name()
+                        )?
+                    ));
+                }
+                if !choices.is_empty() {
+                    content.elements.push_str(&format!(
+                        "{indent}    <xsd:choice minOccurs=\"{}\" maxOccurs=\"{}\">\n{}{indent}    </xsd:choice>\n",
+                        min_occurs, max_occurs, choices
+                    ));
+                }
+            }
+            BpmnXmlPlacement::Text => {
+                content.is_mixed = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Property names of the owner and of its hierarchy (see [`CMOFClass::get_bpmn_xml_hierarchy_names`])
+    fn get_xsd_hierarchy_names(&self) -> Result<Vec<String>, anyhow::Error> {
+        let mut result = Vec::new();
+        match self.parent.get_object()?.upgrade()? {
+            EnumCMOF::CMOFClass(c) => result.extend(c.get_bpmn_xml_hierarchy_names()?),
+            EnumCMOF::CMOFDataType(c) => {
+                for property in c.get_owned_attribute_in_order() {
+                    result.extend(property.get_bpmn_xml_own_names());
+                }
+            }
+            _ => {}
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,724 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Mod section
+use crate::reflective_api::*;
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Instance of a class of the loaded metamodel, with the values of its properties
+#[derive(Clone, Debug)]
+pub struct Instance {
+    /// Identifier in the extent
+    id: InstanceId,
+    /// Class of the instance
+    class: Rc<CMOFClass>,
+    /// Values of each property (key : property label)
+    slots: BTreeMap<String, Vec<ReflectiveValue>>,
+    /// Owner of the instance, with the label of the composite property
+    container: Option<(InstanceId, String)>,
+}
+
+impl Instance {
+    /// Identifier in the extent
+    pub fn get_id(&self) -> InstanceId {
+        self.id
+    }
+
+    /// Class of the instance
+    pub fn get_class(&self) -> &Rc<CMOFClass> {
+        &self.class
+    }
+
+    /// Owner of the instance (composite property with the instance as value)
+    pub fn get_container(&self) -> Option<InstanceId> {
+        self.container.as_ref().map(|(owner, _)| *owner)
+    }
+
+    /// Values of each property (key : property label), without the unset properties
+    pub fn get_slots(&self) -> &BTreeMap<String, Vec<ReflectiveValue>> {
+        &self.slots
+    }
+
+    /// Values of a property, using its label
+    fn get_slot(&self, label: &str) -> &[ReflectiveValue] {
+        self.slots.get(label).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Set of instances of the loaded metamodel, with containment and association ends maintained
+#[derive(Clone, Debug)]
+pub struct ReflectiveExtent {
+    /// Members of the loaded packages, keeping types and associations (weak references) after "close"
+    members: Vec<EnumOwnedMember>,
+    /// Classes of the loaded packages (key : class label)
+    classes: BTreeMap<String, Rc<CMOFClass>>,
+    /// All instances of the extent
+    instances: BTreeMap<InstanceId, Instance>,
+    /// Identifier of the next created instance
+    next_id: usize,
+}
+
+impl LoadingTracker {
+    /// Make an empty extent, using the classes of all loaded packages (call before "close")
+    pub fn get_reflective_extent(&self) -> Result<ReflectiveExtent, anyhow::Error> {
+        let mut members = Vec::new();
+        for pckg in self.get_package_in_order().values() {
+            members.extend(pckg.get_json().owned_member.values().cloned());
+        }
+        ReflectiveExtent::new(members)
+    }
+}
+
+impl ReflectiveExtent {
+    /// Make an empty extent, using the members of the loaded packages
+    pub fn new(members: Vec<EnumOwnedMember>) -> Result<Self, anyhow::Error> {
+        let mut classes = BTreeMap::new();
+        for entity in &members {
+            if let EnumOwnedMember::Class(content) = entity {
+                classes.insert(content.get_xmi_label()?, content.clone());
+            }
+        }
+        Ok(ReflectiveExtent {
+            members,
+            classes,
+            instances: BTreeMap::new(),
+            next_id: 0,
+        })
+    }
+
+    /// Members of the loaded packages (classes, datatypes, enumerations, associations, ...)
+    pub fn get_members(&self) -> &[EnumOwnedMember] {
+        &self.members
+    }
+
+    /// Class of the metamodel, using its label ("BPMN20-Process")
+    pub fn get_class(&self, label: &str) -> Result<Rc<CMOFClass>, anyhow::Error> {
+        self.classes
+            .get(label)
+            .cloned()
+            .ok_or(anyhow::format_err!("No class \"{}\" in the extent", label))
+    }
+
+    /// All instances of the extent
+    pub fn get_instances(&self) -> impl Iterator<Item = &Instance> {
+        self.instances.values()
+    }
+
+    /// Instance of the extent, using its identifier
+    pub fn get_instance(&self, id: InstanceId) -> Result<&Instance, anyhow::Error> {
+        self.instances
+            .get(&id)
+            .ok_or(anyhow::format_err!("No instance {} in the extent", id))
+    }
+
+    /// Mutable instance of the extent, using its identifier
+    fn get_instance_mut(&mut self, id: InstanceId) -> Result<&mut Instance, anyhow::Error> {
+        self.instances
+            .get_mut(&id)
+            .ok_or(anyhow::format_err!("No instance {} in the extent", id))
+    }
+
+    /// Instances owned by an instance (values of its composite properties)
+    pub fn get_contents(&self, id: InstanceId) -> Result<Vec<InstanceId>, anyhow::Error> {
+        Ok(self
+            .instances
+            .values()
+            .filter(|instance| instance.get_container() == Some(id))
+            .map(|instance| instance.id)
+            .collect())
+    }
+
+    /// Create an instance of a concrete class, with the default values of the metamodel
+    pub fn create_instance(&mut self, class: &Rc<CMOFClass>) -> Result<InstanceId, anyhow::Error> {
+        if class.is_abstract {
+            return Err(anyhow::format_err!(
+                "Class \"{}\" is abstract",
+                class.get_xmi_label()?
+            ));
+        }
+        let mut slots = BTreeMap::new();
+        for (label, property) in class.get_all_property()? {
+            if property.is_derived || property.is_derived_union {
+                continue;
+            }
+            if let Some(default) = &property.default {
+                let value = match property.get_type()?.upgrade()? {
+                    EnumCMOF::CMOFPrimitiveType(c) => {
+                        ReflectiveValue::from_primitive_text(&c, default)?
+                    }
+                    EnumCMOF::CMOFEnumeration(c) => self.check_literal(&c, default)?,
+                    _ => continue,
+                };
+                slots.insert(label, vec![value]);
+            }
+        }
+        let id = InstanceId(self.next_id);
+        self.next_id += 1;
+        self.instances.insert(
+            id,
+            Instance {
+                id,
+                class: class.clone(),
+                slots,
+                container: None,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Delete an instance, with its contents, and remove all values referencing it
+    pub fn delete_instance(&mut self, id: InstanceId) -> Result<(), anyhow::Error> {
+        for child in self.get_contents(id)? {
+            self.delete_instance(child)?;
+        }
+        let instance = self.get_instance(id)?.clone();
+        let value = ReflectiveValue::Instance(id);
+        if let Some((owner, label)) = &instance.container {
+            let property = self.get_property_by_label(*owner, label)?;
+            self.remove_value(*owner, &property, &value)?;
+        }
+        for (label, values) in &instance.slots {
+            let property = self.get_property_by_label(id, label)?;
+            for old_value in values {
+                self.remove_value(id, &property, old_value)?;
+            }
+        }
+        // Values without opposite end
+        for other in self.instances.values_mut() {
+            for values in other.slots.values_mut() {
+                values.retain(|other_value| *other_value != value);
+            }
+        }
+        self.instances.remove(&id);
+        Ok(())
+    }
+
+    /// Property of the class of an instance (or of its super classes), using its metamodel name
+    pub fn get_property(
+        &self,
+        id: InstanceId,
+        name: &str,
+    ) -> Result<Rc<CMOFProperty>, anyhow::Error> {
+        let class = self.get_instance(id)?.get_class();
+        class.get_property_by_name(name)?.ok_or(anyhow::format_err!(
+            "No property \"{}\" in \"{}\"",
+            name,
+            class.get_xmi_label()?
+        ))
+    }
+
+    /// Property of the class of an instance (or of its super classes), using its label
+    fn get_property_by_label(
+        &self,
+        id: InstanceId,
+        label: &str,
+    ) -> Result<Rc<CMOFProperty>, anyhow::Error> {
+        let class = self.get_instance(id)?.get_class();
+        class
+            .get_all_property()?
+            .remove(label)
+            .ok_or(anyhow::format_err!(
+                "No property \"{}\" in \"{}\"",
+                label,
+                class.get_xmi_label()?
+            ))
+    }
+
+    /// Values of a property of an instance (empty if unset)
+    pub fn get(&self, id: InstanceId, name: &str) -> Result<Vec<ReflectiveValue>, anyhow::Error> {
+        let property = self.get_property(id, name)?;
+        Ok(self
+            .get_instance(id)?
+            .get_slot(&property.xmi_id.label()?)
+            .to_vec())
+    }
+
+    /// Replace the values of a property of an instance, checking type and multiplicity
+    pub fn set(
+        &mut self,
+        id: InstanceId,
+        name: &str,
+        values: Vec<ReflectiveValue>,
+    ) -> Result<(), anyhow::Error> {
+        let property = self.get_changeable_property(id, name)?;
+        check_multiplicity(&property, values.len())?;
+        let values = values
+            .iter()
+            .map(|value| self.check_value(&property, value))
+            .collect::<Result<Vec<ReflectiveValue>, anyhow::Error>>()?;
+        let old_values = self
+            .get_instance(id)?
+            .get_slot(&property.xmi_id.label()?)
+            .to_vec();
+        for old_value in &old_values {
+            self.remove_value(id, &property, old_value)?;
+        }
+        for value in values {
+            self.insert_value(id, &property, value)?;
+        }
+        Ok(())
+    }
+
+    /// Add a value to a property of an instance, checking type and multiplicity
+    pub fn add(
+        &mut self,
+        id: InstanceId,
+        name: &str,
+        value: ReflectiveValue,
+    ) -> Result<(), anyhow::Error> {
+        let property = self.get_changeable_property(id, name)?;
+        let value = self.check_value(&property, &value)?;
+        self.insert_value(id, &property, value)
+    }
+
+    /// Remove a value from a property of an instance, checking multiplicity
+    pub fn remove(
+        &mut self,
+        id: InstanceId,
+        name: &str,
+        value: &ReflectiveValue,
+    ) -> Result<(), anyhow::Error> {
+        let property = self.get_changeable_property(id, name)?;
+        let values = self.get_instance(id)?.get_slot(&property.xmi_id.label()?);
+        if values.contains(value) {
+            check_multiplicity(&property, values.len() - 1)?;
+        }
+        self.remove_value(id, &property, value)
+    }
+
+    /// Property which can be changed (not derived)
+    fn get_changeable_property(
+        &self,
+        id: InstanceId,
+        name: &str,
+    ) -> Result<Rc<CMOFProperty>, anyhow::Error> {
+        let property = self.get_property(id, name)?;
+        if property.is_derived || property.is_derived_union || property.is_read_only {
+            return Err(anyhow::format_err!(
+                "Property \"{}\" is derived or read-only",
+                property.xmi_id.label()?
+            ));
+        }
+        Ok(property)
+    }
+
+    /// Check a value against the type of a property (enumeration literals are normalized)
+    fn check_value(
+        &self,
+        property: &CMOFProperty,
+        value: &ReflectiveValue,
+    ) -> Result<ReflectiveValue, anyhow::Error> {
+        let error = || {
+            anyhow::format_err!(
+                "Value {} doesn't match the type of \"{}\"",
+                value,
+                property.xmi_id.label().unwrap_or_default()
+            )
+        };
+        match (property.get_type()?.upgrade()?, value) {
+            (EnumCMOF::CMOFClass(c), ReflectiveValue::Instance(id)) => {
+                if self.get_instance(*id)?.get_class().is_kind_of(&c)? {
+                    Ok(value.clone())
+                } else {
+                    Err(error())
+                }
+            }
+            (EnumCMOF::CMOFPrimitiveType(c), _) if value.is_primitive_value(&c) => {
+                Ok(value.clone())
+            }
+            (EnumCMOF::CMOFEnumeration(c), ReflectiveValue::Literal(designation)) => {
+                self.check_literal(&c, designation)
+            }
+            (EnumCMOF::CMOFDataType(c), ReflectiveValue::Data(fields)) => {
+                let mut result = BTreeMap::new();
+                for property in c.get_owned_attribute_in_order() {
+                    let name = property.get_metamodel_name();
+                    let field = fields.get(&name).map(Vec::as_slice).unwrap_or_default();
+                    check_multiplicity(&property, field.len())?;
+                    let field = field
+                        .iter()
+                        .map(|field_value| self.check_value(&property, field_value))
+                        .collect::<Result<Vec<ReflectiveValue>, anyhow::Error>>()?;
+                    result.insert(name, field);
+                }
+                if let Some(name) = fields.keys().find(|name| !result.contains_key(*name)) {
+                    return Err(anyhow::format_err!(
+                        "No property \"{}\" in \"{}\"",
+                        name,
+                        c.xmi_id.label()?
+                    ));
+                }
+                Ok(ReflectiveValue::Data(result))
+            }
+            _ => Err(error()),
+        }
+    }
+
+    /// Literal of an enumeration, using its designation (ignoring case)
+    fn check_literal(
+        &self,
+        enumeration: &CMOFEnumeration,
+        designation: &str,
+    ) -> Result<ReflectiveValue, anyhow::Error> {
+        match enumeration.get_literal_by_designation(designation)? {
+            Some(literal) => Ok(ReflectiveValue::Literal(
+                literal.litteral_designation.clone(),
+            )),
+            None => Err(anyhow::format_err!(
+                "No literal \"{}\" in \"{}\"",
+                designation,
+                enumeration.xmi_id.label()?
+            )),
+        }
+    }
+
+    /// Opposite end of a property, if the opposite end is a property of the class of the value
+    fn get_maintained_opposite(
+        &self,
+        property: &CMOFProperty,
+        value: InstanceId,
+    ) -> Result<Option<Rc<CMOFProperty>>, anyhow::Error> {
+        let opposite = match property.get_opposite()? {
+            Some(opposite) => opposite,
+            None => return Ok(None),
+        };
+        if opposite.is_derived || opposite.is_derived_union {
+            return Ok(None);
+        }
+        let class = self.get_instance(value)?.get_class();
+        if class
+            .get_all_property()?
+            .contains_key(&opposite.xmi_id.label()?)
+        {
+            Ok(Some(opposite))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Add a checked value, updating the container and the opposite end
+    fn insert_value(
+        &mut self,
+        id: InstanceId,
+        property: &Rc<CMOFProperty>,
+        value: ReflectiveValue,
+    ) -> Result<(), anyhow::Error> {
+        let label = property.xmi_id.label()?;
+        let values = self.get_instance(id)?.get_slot(&label);
+        let child = value.get_instance();
+        if child.is_some() && values.contains(&value) {
+            // Already linked (opposite end of the call)
+            return Ok(());
+        }
+        check_multiplicity_upper(property, values.len() + 1)?;
+
+        // An instance have only one container, and can't contain its container
+        if let (true, Some(child)) = (property.is_composite, child) {
+            let mut current = Some(id);
+            while let Some(owner) = current {
+                if owner == child {
+                    return Err(anyhow::format_err!(
+                        "Instance {} can't contain its container {}",
+                        id,
+                        child
+                    ));
+                }
+                current = self.get_instance(owner)?.get_container();
+            }
+            if let Some((old_owner, old_label)) = self.get_instance(child)?.container.clone() {
+                let old_property = self.get_property_by_label(old_owner, &old_label)?;
+                self.remove_value(old_owner, &old_property, &value)?;
+            }
+        }
+
+        self.get_instance_mut(id)?
+            .slots
+            .entry(label.clone())
+            .or_default()
+            .push(value);
+
+        if let Some(child) = child {
+            if property.is_composite {
+                self.get_instance_mut(child)?.container = Some((id, label));
+            }
+            if let Some(opposite) = self.get_maintained_opposite(property, child)? {
+                let opposite_label = opposite.xmi_id.label()?;
+                let old_values = self.get_instance(child)?.get_slot(&opposite_label).to_vec();
+                let owner = ReflectiveValue::Instance(id);
+                // Single opposite end : replace the previous value
+                if opposite.upper == infinitable::Finite(1) {
+                    for old_value in old_values.iter().filter(|v| **v != owner) {
+                        self.remove_value(child, &opposite, old_value)?;
+                    }
+                }
+                self.insert_value(child, &opposite, owner)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove a value, updating the container and the opposite end
+    fn remove_value(
+        &mut self,
+        id: InstanceId,
+        property: &Rc<CMOFProperty>,
+        value: &ReflectiveValue,
+    ) -> Result<(), anyhow::Error> {
+        let label = property.xmi_id.label()?;
+        let instance = self.get_instance_mut(id)?;
+        let values = match instance.slots.get_mut(&label) {
+            Some(values) => values,
+            None => return Ok(()),
+        };
+        match values.iter().position(|v| v == value) {
+            Some(position) => {
+                values.remove(position);
+            }
+            None => return Ok(()),
+        }
+        if values.is_empty() {
+            instance.slots.remove(&label);
+        }
+
+        if let Some(child) = value.get_instance() {
+            if property.is_composite {
+                let child_instance = self.get_instance_mut(child)?;
+                if child_instance.container == Some((id, label)) {
+                    child_instance.container = None;
+                }
+            }
+            if let Some(opposite) = self.get_maintained_opposite(property, child)? {
+                self.remove_value(child, &opposite, &ReflectiveValue::Instance(id))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check the number of values of a property, with lower and upper bounds
+fn check_multiplicity(property: &CMOFProperty, len: usize) -> Result<(), anyhow::Error> {
+    if (len as i32) < property.lower {
+        return Err(anyhow::format_err!(
+            "Property \"{}\" needs at least {} values ({} found)",
+            property.xmi_id.label()?,
+            property.lower,
+            len
+        ));
+    }
+    check_multiplicity_upper(property, len)
+}
+
+/// Check the number of values of a property, with upper bound
+fn check_multiplicity_upper(property: &CMOFProperty, len: usize) -> Result<(), anyhow::Error> {
+    if property.upper < infinitable::Finite(len as i32) {
+        return Err(anyhow::format_err!(
+            "Property \"{}\" accepts at most {} values ({} found)",
+            property.xmi_id.label()?,
+            property.get_multiplicity(),
+            len
+        ));
+    }
+    Ok(())
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_log_tools::tests::initialize_log_for_test;
+    use crate::output_result_manager::open_env;
+
+    /// Extent of the BPMN metamodel, loaded from "metamodel_file/"
+    fn open_extent(test_name: &str) -> ReflectiveExtent {
+        let main_output_folder = format!("tests/reflective_api/{}/output", test_name);
+        let result_folder = format!("tests/reflective_api/{}/result", test_name);
+        let file_env = open_env("metamodel_file/", &main_output_folder, &result_folder).unwrap();
+        let mut loading_env = open_loader(file_env).unwrap();
+        loading_env
+            .make_prepare("BPMNDI.json", "_0", "root")
+            .unwrap();
+        loading_env.get_reflective_extent().unwrap()
+    }
+
+    /// Make an instance of a class, using its label
+    fn create(extent: &mut ReflectiveExtent, label: &str) -> InstanceId {
+        let class = extent.get_class(label).unwrap();
+        extent.create_instance(&class).unwrap()
+    }
+
+    #[test]
+    fn reflective_api_01_create_instance() {
+        initialize_log_for_test();
+        let mut extent = open_extent("reflective_api_01_create_instance");
+
+        // Abstract and unknown classes
+        let class = extent.get_class("BPMN20-FlowElement").unwrap();
+        assert!(extent.create_instance(&class).is_err());
+        assert!(extent.get_class("BPMN20-Unknown").is_err());
+
+        // Default values of the metamodel
+        let event = create(&mut extent, "BPMN20-StartEvent");
+        assert_eq!(
+            extent.get(event, "isInterrupting").unwrap(),
+            vec![ReflectiveValue::Boolean(true)]
+        );
+        let task = create(&mut extent, "BPMN20-Task");
+        assert_eq!(
+            extent.get(task, "startQuantity").unwrap(),
+            vec![ReflectiveValue::Integer(1)]
+        );
+        let gateway = create(&mut extent, "BPMN20-ExclusiveGateway");
+        assert_eq!(
+            extent.get(gateway, "gatewayDirection").unwrap(),
+            vec![ReflectiveValue::Literal(String::from("Unspecified"))]
+        );
+        let process = create(&mut extent, "BPMN20-Process");
+        assert!(extent.get(process, "flowElements").unwrap().is_empty());
+    }
+
+    #[test]
+    fn reflective_api_02_set_with_checks() {
+        initialize_log_for_test();
+        let mut extent = open_extent("reflective_api_02_set_with_checks");
+        let task = create(&mut extent, "BPMN20-Task");
+        let name = ReflectiveValue::String(String::from("Check order"));
+
+        // Inherited property (FlowElement-name)
+        extent.set(task, "name", vec![name.clone()]).unwrap();
+        assert_eq!(extent.get(task, "name").unwrap(), vec![name.clone()]);
+
+        // Type, multiplicity, unknown and derived property
+        assert!(extent
+            .set(task, "name", vec![ReflectiveValue::Boolean(true)])
+            .is_err());
+        assert!(extent
+            .set(task, "name", vec![name.clone(), name.clone()])
+            .is_err());
+        assert!(extent.set(task, "unknown", vec![name.clone()]).is_err());
+        assert!(extent.get(task, "unknown").is_err());
+
+        // Enumeration literal, compared ignoring case
+        let gateway = create(&mut extent, "BPMN20-ExclusiveGateway");
+        let direction = ReflectiveValue::Literal(String::from("diverging"));
+        extent
+            .set(gateway, "gatewayDirection", vec![direction])
+            .unwrap();
+        assert_eq!(
+            extent.get(gateway, "gatewayDirection").unwrap(),
+            vec![ReflectiveValue::Literal(String::from("Diverging"))]
+        );
+        let direction = ReflectiveValue::Literal(String::from("Sideways"));
+        assert!(extent
+            .set(gateway, "gatewayDirection", vec![direction])
+            .is_err());
+
+        // Instance of an unexpected class
+        let flow = create(&mut extent, "BPMN20-SequenceFlow");
+        let value = ReflectiveValue::Instance(gateway);
+        assert!(extent.set(flow, "sourceRef", vec![value]).is_ok());
+        let process = create(&mut extent, "BPMN20-Process");
+        let value = ReflectiveValue::Instance(process);
+        assert!(extent.set(flow, "sourceRef", vec![value]).is_err());
+
+        // Derived property
+        let lane = ReflectiveValue::Instance(create(&mut extent, "BPMN20-Lane"));
+        assert!(extent.add(task, "lanes", lane).is_err());
+    }
+
+    #[test]
+    fn reflective_api_03_containment() {
+        initialize_log_for_test();
+        let mut extent = open_extent("reflective_api_03_containment");
+        let process_1 = create(&mut extent, "BPMN20-Process");
+        let process_2 = create(&mut extent, "BPMN20-Process");
+        let task = create(&mut extent, "BPMN20-Task");
+        let value = ReflectiveValue::Instance(task);
+
+        extent
+            .add(process_1, "flowElements", value.clone())
+            .unwrap();
+        assert_eq!(
+            extent.get_instance(task).unwrap().get_container(),
+            Some(process_1)
+        );
+        assert_eq!(extent.get_contents(process_1).unwrap(), vec![task]);
+
+        // Only one container
+        extent
+            .add(process_2, "flowElements", value.clone())
+            .unwrap();
+        assert_eq!(
+            extent.get_instance(task).unwrap().get_container(),
+            Some(process_2)
+        );
+        assert!(extent.get(process_1, "flowElements").unwrap().is_empty());
+
+        // Deletion with contents
+        extent.delete_instance(process_2).unwrap();
+        assert!(extent.get_instance(task).is_err());
+        assert_eq!(extent.get_instances().count(), 1);
+    }
+
+    #[test]
+    fn reflective_api_04_opposite_end() {
+        initialize_log_for_test();
+        let mut extent = open_extent("reflective_api_04_opposite_end");
+        let task_1 = create(&mut extent, "BPMN20-Task");
+        let task_2 = create(&mut extent, "BPMN20-Task");
+        let flow = create(&mut extent, "BPMN20-SequenceFlow");
+        let flow_value = ReflectiveValue::Instance(flow);
+
+        extent
+            .set(flow, "sourceRef", vec![ReflectiveValue::Instance(task_1)])
+            .unwrap();
+        assert_eq!(
+            extent.get(task_1, "outgoing").unwrap(),
+            vec![flow_value.clone()]
+        );
+
+        // Single end replaced, from the other side
+        extent.add(task_2, "outgoing", flow_value.clone()).unwrap();
+        assert!(extent.get(task_1, "outgoing").unwrap().is_empty());
+        assert_eq!(
+            extent.get(flow, "sourceRef").unwrap(),
+            vec![ReflectiveValue::Instance(task_2)]
+        );
+
+        // Deletion removes the opposite values
+        extent.delete_instance(flow).unwrap();
+        assert!(extent.get(task_2, "outgoing").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+/*
+Copyright 2023-2024 CHATROUX MARC
+
+This file is part of Imbriqua Structure, a interpreter of BPMN model files (in UML notation) for
+Imbriqua Engine project
+
+Imbriqua Structure is free software: you can redistribute it and/or modify it under the terms of
+the GNU General Public License as published by the Free Software Foundation, either
+version 3 of the License, or (at your option) any later version.
+
+Imbriqua Structure is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY;
+without even the implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR
+PURPOSE.See the GNU General Public License for more details.
+
+You should have received a copy of the GNU General Public License along with Imbriqua Structure.
+If not, see <https://www.gnu.org/licenses/>.
+*/
+
+#![warn(dead_code)]
+#![warn(missing_docs)]
+
+// Package section
+use crate::cmof_loader::*;
+
+// Dependencies section
+use std::fmt;
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Identifier of an instance in its extent
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InstanceId(pub usize);
+
+impl fmt::Display for InstanceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+// ####################################################################################################
+//
+// ####################################################################################################
+
+/// Value of a property, checked against the type of the property
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReflectiveValue {
+    /// Value of "Boolean" primitive type
+    Boolean(bool),
+    /// Value of "Integer" and "UnlimitedNatural" primitive types (-1 for "*")
+    Integer(i64),
+    /// Value of "Real" primitive type
+    Real(f64),
+    /// Value of "String" primitive type, and of other primitive types
+    String(String),
+    /// Literal of an enumeration, using its designation ("Diverging")
+    Literal(String),
+    /// Value of a datatype, with the values of its properties (key : property name)
+    Data(BTreeMap<String, Vec<ReflectiveValue>>),
+    /// Instance of a class, in the same extent
+    Instance(InstanceId),
+}
+
+impl fmt::Display for ReflectiveValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReflectiveValue::Boolean(value) => write!(f, "{}", value),
+            ReflectiveValue::Integer(value) => write!(f, "{}", value),
+            ReflectiveValue::Real(value) => write!(f, "{}", value),
+            ReflectiveValue::String(value) => write!(f, "{:?}", value),
+            ReflectiveValue::Literal(value) => write!(f, "{}", value),
+            ReflectiveValue::Data(values) => write!(f, "{:?}", values),
+            ReflectiveValue::Instance(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+impl ReflectiveValue {
+    /// Parse a value of a primitive type, from its metamodel text ("true", "1", ...)
+    pub fn from_primitive_text(
+        primitive: &CMOFPrimitiveType,
+        text: &str,
+    ) -> Result<Self, anyhow::Error> {
+        let error = || {
+            anyhow::format_err!(
+                "\"{}\" isn't a value of \"{}\"",
+                text,
+                primitive.xmi_id.label().unwrap_or_default()
+            )
+        };
+        Ok(match primitive.model_name.as_str() {
+            "Boolean" => ReflectiveValue::Boolean(text.parse().map_err(|_| error())?),
+            "Integer" => ReflectiveValue::Integer(text.parse().map_err(|_| error())?),
+            "UnlimitedNatural" if text == "*" => ReflectiveValue::Integer(-1),
+            "UnlimitedNatural" => ReflectiveValue::Integer(text.parse().map_err(|_| error())?),
+            "Real" => ReflectiveValue::Real(text.parse().map_err(|_| error())?),
+            _ => ReflectiveValue::String(text.to_string()),
+        })
+    }
+
+    /// Check if the value matches a primitive type
+    pub fn is_primitive_value(&self, primitive: &CMOFPrimitiveType) -> bool {
+        match primitive.model_name.as_str() {
+            "Boolean" => matches!(self, ReflectiveValue::Boolean(_)),
+            "Integer" | "UnlimitedNatural" => matches!(self, ReflectiveValue::Integer(_)),
+            "Real" => matches!(self, ReflectiveValue::Real(_)),
+            _ => matches!(self, ReflectiveValue::String(_)),
+        }
+    }
+
+    /// Instance of the value, if the value is an instance
+    pub fn get_instance(&self) -> Option<InstanceId> {
+        match self {
+            ReflectiveValue::Instance(id) => Some(*id),
+            _ => None,
+        }
+    }
+}
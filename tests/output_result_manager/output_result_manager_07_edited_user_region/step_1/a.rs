@@ -0,0 +1,4 @@
+use a;
+// <user-code name="behavior">
+impl B for A {}
+// </user-code>
@@ -0,0 +1,6 @@
+use a;
+// <user-code name="behavior">
+impl B for A {
+    fn b() {}
+}
+// </user-code>
@@ -0,0 +1,6 @@
+use b;
+// <user-code name="impl">
+impl B {
+    fn hand_written() {}
+}
+// </user-code>
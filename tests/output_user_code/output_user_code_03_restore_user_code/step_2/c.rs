@@ -0,0 +1 @@
+// <user-code name="not_exported">
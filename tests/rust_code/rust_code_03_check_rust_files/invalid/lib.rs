@@ -0,0 +1,2 @@
+pub struct A {
+    pub id: i64,
@@ -0,0 +1,2 @@
+//! Module
+pub mod sub;
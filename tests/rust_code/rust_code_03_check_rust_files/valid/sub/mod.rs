@@ -0,0 +1,3 @@
+pub struct A {
+    pub id: i64,
+}
@@ -0,0 +1,46 @@
+//! Reading and writing of "sample.bpmn" (Camunda Modeler file) with the generated BPMN XML code
+
+use entities::bpmn_xml::{read_bpmn_xml, write_bpmn_xml};
+use entities::domain_model::*;
+
+const SAMPLE: &str = include_str!("sample.bpmn");
+
+#[test]
+fn read_sample() {
+    let definitions = read_bpmn_xml::<Definitions>(SAMPLE).unwrap();
+    assert_eq!(definitions.exporter, "Camunda Modeler");
+    assert_eq!(definitions.diagrams.len(), 1);
+
+    let [RootElementKind::Process(process)] = &definitions.root_elements[..] else {
+        panic!("One process expected");
+    };
+    let flow_elements = &process.super_flow_elements_container.flow_elements;
+    assert_eq!(flow_elements.len(), 9);
+    assert_eq!(process.artifacts.len(), 2);
+
+    // Concrete class from "xsi:type", and reference from IDREF
+    let flow = flow_elements
+        .iter()
+        .find_map(|element| match element {
+            FlowElementKind::SequenceFlow(flow)
+                if flow.super_flow_element.super_base_element.id == "Flow_3" =>
+            {
+                Some(flow)
+            }
+            _ => None,
+        })
+        .unwrap();
+    assert!(matches!(
+        flow.condition_expression,
+        Some(ExpressionKind::FormalExpression(_))
+    ));
+    assert_eq!(flow.target_ref.id, "EndEvent_1");
+}
+
+#[test]
+fn round_trip_sample() {
+    let definitions = read_bpmn_xml::<Definitions>(SAMPLE).unwrap();
+    let written = write_bpmn_xml(&definitions);
+    assert!(written.contains("xsi:type=\"bpmn:tFormalExpression\""));
+    assert_eq!(read_bpmn_xml::<Definitions>(&written).unwrap(), definitions);
+}